@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: MIT
+
+use futures_util::stream::StreamExt;
+
+fn main() {
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_io()
+        .build()
+        .unwrap();
+    let iface_name =
+        std::env::args().nth(1).expect("Usage: cable_test <iface>");
+    rt.block_on(cable_test(&iface_name));
+}
+
+async fn cable_test(iface_name: &str) {
+    let (connection, mut handle, mut messages) =
+        ethtool::new_monitor_connection().unwrap();
+    tokio::spawn(connection);
+
+    let mut notifications = handle
+        .cable_test()
+        .start(iface_name)
+        .execute(&mut messages)
+        .await
+        .unwrap();
+
+    while let Some(msg) = notifications.next().await {
+        println!("{:?}", msg.unwrap());
+    }
+}
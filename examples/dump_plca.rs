@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: MIT
+
+use futures_util::stream::StreamExt;
+
+fn main() {
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_io()
+        .build()
+        .unwrap();
+    let iface_name = std::env::args().nth(1);
+    rt.block_on(get_plca(iface_name.as_deref()));
+}
+
+async fn get_plca(iface_name: Option<&str>) {
+    let (connection, mut handle, _) = ethtool::new_connection().unwrap();
+    tokio::spawn(connection);
+
+    let mut cfg_handle =
+        handle.plca().get_cfg(iface_name).execute().await.unwrap();
+
+    let mut msgs = Vec::new();
+    while let Some(Ok(msg)) = cfg_handle.next().await {
+        msgs.push(msg);
+    }
+
+    let mut status_handle = handle
+        .plca()
+        .get_status(iface_name)
+        .execute()
+        .await
+        .unwrap();
+
+    while let Some(Ok(msg)) = status_handle.next().await {
+        msgs.push(msg);
+    }
+    assert!(!msgs.is_empty());
+    for msg in msgs {
+        println!("{msg:?}");
+    }
+}
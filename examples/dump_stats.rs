@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT
+
+use ethtool::EthtoolStatsGroupId;
+use futures_util::stream::StreamExt;
+
+fn main() {
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_io()
+        .build()
+        .unwrap();
+    let iface_name = std::env::args().nth(1);
+    rt.block_on(get_stats(iface_name.as_deref()));
+}
+
+async fn get_stats(iface_name: Option<&str>) {
+    let (connection, mut handle, _) = ethtool::new_connection().unwrap();
+    tokio::spawn(connection);
+
+    let mut stats_handle = handle
+        .stats()
+        .get(
+            iface_name,
+            &[
+                EthtoolStatsGroupId::EthPhy,
+                EthtoolStatsGroupId::EthMac,
+                EthtoolStatsGroupId::EthCtrl,
+                EthtoolStatsGroupId::Rmon,
+            ],
+        )
+        .execute()
+        .await
+        .unwrap();
+
+    let mut msgs = Vec::new();
+    while let Some(Ok(msg)) = stats_handle.next().await {
+        msgs.push(msg);
+    }
+    assert!(!msgs.is_empty());
+    for msg in msgs {
+        println!("{msg:?}");
+    }
+}
@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: MIT
+
+use futures_util::stream::StreamExt;
+
+fn main() {
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_io()
+        .build()
+        .unwrap();
+    let iface_name = std::env::args().nth(1);
+    rt.block_on(get_tunnel_info(iface_name.as_deref()));
+}
+
+async fn get_tunnel_info(iface_name: Option<&str>) {
+    let (connection, mut handle, _) = ethtool::new_connection().unwrap();
+    tokio::spawn(connection);
+
+    let mut tunnel_info_handle = handle
+        .tunnel_info()
+        .get(iface_name)
+        .execute()
+        .await
+        .unwrap();
+
+    let mut msgs = Vec::new();
+    while let Some(Ok(msg)) = tunnel_info_handle.next().await {
+        msgs.push(msg);
+    }
+    assert!(!msgs.is_empty());
+    for msg in msgs {
+        println!("{msg:?}");
+    }
+}
@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: MIT
+
+use futures_util::stream::StreamExt;
+
+fn main() {
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_io()
+        .build()
+        .unwrap();
+    let mut args = std::env::args().skip(1);
+    let usage = "Usage: flash_module_firmware <iface> <file>";
+    let iface_name = args.next().expect(usage);
+    let file_name = args.next().expect(usage);
+    rt.block_on(flash_module_firmware(&iface_name, &file_name));
+}
+
+async fn flash_module_firmware(iface_name: &str, file_name: &str) {
+    let (connection, mut handle, mut messages) =
+        ethtool::new_connection().unwrap();
+    tokio::spawn(connection);
+
+    let mut notifications = handle
+        .module()
+        .flash_firmware(iface_name, file_name)
+        .execute(&mut messages)
+        .await
+        .unwrap();
+
+    while let Some(msg) = notifications.next().await {
+        println!("{:?}", msg.unwrap());
+    }
+}
@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: MIT
+
+use futures_util::stream::StreamExt;
+
+fn main() {
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_io()
+        .build()
+        .unwrap();
+    rt.block_on(monitor());
+}
+
+async fn monitor() {
+    let (connection, _, messages) = ethtool::new_monitor_connection().unwrap();
+    tokio::spawn(connection);
+
+    let mut notifications = ethtool::ethtool_monitor(messages);
+    while let Some(msg) = notifications.next().await {
+        match msg {
+            Ok(msg) => println!("{:?}", msg.payload),
+            Err(e) => eprintln!("{e}"),
+        }
+    }
+}
@@ -2,10 +2,15 @@
 
 use log::warn;
 use netlink_packet_core::{
-    parse_string, parse_u32, DecodeError, ErrorContext, NlasIterator,
+    emit_u32, parse_string, parse_u32, DecodeError, Emitable, ErrorContext,
+    Nla, NlasIterator, NLA_F_NESTED,
 };
 
+const ETHTOOL_A_BITSET_NOMASK: u16 = 1;
+const ETHTOOL_A_BITSET_SIZE: u16 = 2;
 const ETHTOOL_A_BITSET_BITS: u16 = 3;
+const ETHTOOL_A_BITSET_VALUE: u16 = 4;
+const ETHTOOL_A_BITSET_MASK: u16 = 5;
 
 const ETHTOOL_A_BITSET_BITS_BIT: u16 = 1;
 
@@ -20,35 +25,143 @@ pub(crate) struct EthtoolBitSet {
     pub(crate) value: bool,
 }
 
+impl EthtoolBitSet {
+    pub(crate) fn new(bit: &EthtoolBit, value: bool) -> Self {
+        Self {
+            index: bit.index,
+            name: bit.name.clone().unwrap_or_default(),
+            value,
+        }
+    }
+}
+
+/// Bit of a bitset, identified by index, and by name when known.
+/// Notifications only identify bits by index, their names can be resolved
+/// by `EthtoolStringSetHandle::resolve_names()`. When sending, the name is
+/// used if set, otherwise the index.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct EthtoolBit {
+    pub index: u32,
+    pub name: Option<String>,
+}
+
+impl From<&str> for EthtoolBit {
+    fn from(name: &str) -> Self {
+        Self {
+            index: 0,
+            name: Some(name.to_string()),
+        }
+    }
+}
+
+impl From<EthtoolBitSet> for EthtoolBit {
+    fn from(bit: EthtoolBitSet) -> Self {
+        Self {
+            index: bit.index,
+            name: if bit.name.is_empty() {
+                None
+            } else {
+                Some(bit.name)
+            },
+        }
+    }
+}
+
 pub(crate) fn parse_bitset_bits_nlas(
     raw: &[u8],
 ) -> Result<Vec<EthtoolBitSet>, DecodeError> {
     let error_msg = "failed to parse mode bit sets";
+    let mut no_mask = false;
+    let mut bits = None;
+    let mut size = 0;
+    let mut value = None;
+    let mut mask = None;
     for nla in NlasIterator::new(raw) {
         let nla = &nla.context(error_msg)?;
-        if nla.kind() == ETHTOOL_A_BITSET_BITS {
-            return parse_bitset_bits_nla(nla.value());
+        match nla.kind() {
+            ETHTOOL_A_BITSET_NOMASK => no_mask = true,
+            ETHTOOL_A_BITSET_SIZE => {
+                size = parse_u32(nla.value())
+                    .context("Invalid ETHTOOL_A_BITSET_SIZE value")?
+            }
+            ETHTOOL_A_BITSET_BITS => {
+                bits = Some(parse_bitset_bits_nla(nla.value())?)
+            }
+            ETHTOOL_A_BITSET_VALUE => value = Some(nla.value().to_vec()),
+            ETHTOOL_A_BITSET_MASK => mask = Some(nla.value().to_vec()),
+            _ => (),
         }
     }
-    Err("No ETHTOOL_A_BITSET_BITS NLA found".into())
+    let mut bits = match (bits, value) {
+        (Some(bits), _) => bits,
+        (None, Some(value)) => {
+            parse_compact_bitset(size, &value, mask.as_deref())
+        }
+        (None, None) => {
+            return Err("No ETHTOOL_A_BITSET_BITS NLA found".into());
+        }
+    };
+    // Bitset without mask is a list of set bits, no value flag is included.
+    if no_mask {
+        bits.iter_mut().for_each(|b| b.value = true);
+    }
+    Ok(bits)
+}
+
+// Notifications use the compact form: bit values (and mask) as u32 arrays in
+// host byte order, without bit names.
+fn parse_compact_bitset(
+    size: u32,
+    value: &[u8],
+    mask: Option<&[u8]>,
+) -> Vec<EthtoolBitSet> {
+    let is_set = |words: &[u8], index: u32| {
+        let i = (index / 32) as usize * 4;
+        words.get(i..i + 4).is_some_and(|w| {
+            u32::from_ne_bytes([w[0], w[1], w[2], w[3]]) & (1 << (index % 32))
+                != 0
+        })
+    };
+    // The size is only trusted within the bits present in value.
+    let size = size.min(u32::try_from(value.len() * 8).unwrap_or(u32::MAX));
+    (0..size)
+        .filter(|i| match mask {
+            Some(mask) => is_set(mask, *i),
+            None => is_set(value, *i),
+        })
+        .map(|i| EthtoolBitSet {
+            index: i,
+            name: String::new(),
+            value: is_set(value, i),
+        })
+        .collect()
+}
+
+// Set bits of the bitset. Bits of compact bitsets, as sent in notifications,
+// have no name.
+pub(crate) fn parse_bitset_set_bits_nlas(
+    raw: &[u8],
+) -> Result<Vec<EthtoolBit>, DecodeError> {
+    Ok(parse_bitset_bits_nlas(raw)?
+        .into_iter()
+        .filter(|b| b.value)
+        .map(EthtoolBit::from)
+        .collect())
 }
 
 pub(crate) fn parse_bitset_bits_string_nlas(
     raw: &[u8],
 ) -> Result<Vec<String>, DecodeError> {
-    let error_msg = "failed to parse mode bit sets";
-    for nla in NlasIterator::new(raw) {
-        let nla = &nla.context(error_msg)?;
-        if nla.kind() == ETHTOOL_A_BITSET_BITS {
-            let bits = parse_bitset_bits_nla(nla.value())?;
-
-            return Ok(bits
-                .into_iter()
-                .filter_map(|b| if b.value { Some(b.name) } else { None })
-                .collect::<Vec<String>>());
-        }
-    }
-    Err("No ETHTOOL_A_BITSET_BITS NLA found".into())
+    parse_bitset_set_bits_nlas(raw)?
+        .into_iter()
+        .map(|b| {
+            b.name.ok_or_else(|| {
+                DecodeError::from(format!(
+                    "Compact bitset holds no bit names: {raw:?}"
+                ))
+            })
+        })
+        .collect()
 }
 
 fn parse_bitset_bits_nla(
@@ -105,3 +218,129 @@ fn parse_bitset_bits_nla(
     }
     Ok(bit_sets)
 }
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum EthtoolBitSetBitAttr {
+    Index(u32),
+    Name(String),
+    Value,
+}
+
+impl Nla for EthtoolBitSetBitAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Index(_) => 4,
+            Self::Name(s) => s.len() + 1,
+            Self::Value => 0,
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Index(_) => ETHTOOL_A_BITSET_BIT_INDEX,
+            Self::Name(_) => ETHTOOL_A_BITSET_BIT_NAME,
+            Self::Value => ETHTOOL_A_BITSET_BIT_VALUE,
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Index(d) => emit_u32(buffer, *d).unwrap(),
+            Self::Name(s) => {
+                buffer[..s.len()].copy_from_slice(s.as_bytes());
+                buffer[s.len()] = 0;
+            }
+            Self::Value => (),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct EthtoolBitSetBit(Vec<EthtoolBitSetBitAttr>);
+
+impl Nla for EthtoolBitSetBit {
+    fn value_len(&self) -> usize {
+        self.0.as_slice().buffer_len()
+    }
+
+    fn kind(&self) -> u16 {
+        ETHTOOL_A_BITSET_BITS_BIT | NLA_F_NESTED
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        self.0.as_slice().emit(buffer)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum EthtoolBitSetAttr {
+    NoMask,
+    Bits(Vec<EthtoolBitSetBit>),
+}
+
+impl Nla for EthtoolBitSetAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::NoMask => 0,
+            Self::Bits(bits) => bits.as_slice().buffer_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::NoMask => ETHTOOL_A_BITSET_NOMASK,
+            Self::Bits(_) => ETHTOOL_A_BITSET_BITS | NLA_F_NESTED,
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::NoMask => (),
+            Self::Bits(bits) => bits.as_slice().emit(buffer),
+        }
+    }
+}
+
+// Build verbose bitset attributes. Bits are referred by name when it is not
+// empty, otherwise by index.
+// When `no_mask` is true, the bitset is a list: every listed bit is set and
+// all others are cleared. Otherwise only listed bits are changed, to the
+// state of their `value`.
+fn bitset_to_nlas(
+    bits: &[EthtoolBitSet],
+    no_mask: bool,
+) -> Vec<EthtoolBitSetAttr> {
+    let mut nlas = Vec::new();
+    if no_mask {
+        nlas.push(EthtoolBitSetAttr::NoMask);
+    }
+    nlas.push(EthtoolBitSetAttr::Bits(
+        bits.iter()
+            .map(|bit| {
+                let mut bit_nlas = Vec::new();
+                if bit.name.is_empty() {
+                    bit_nlas.push(EthtoolBitSetBitAttr::Index(bit.index));
+                } else {
+                    bit_nlas.push(EthtoolBitSetBitAttr::Name(bit.name.clone()));
+                }
+                if !no_mask && bit.value {
+                    bit_nlas.push(EthtoolBitSetBitAttr::Value);
+                }
+                EthtoolBitSetBit(bit_nlas)
+            })
+            .collect(),
+    ));
+    nlas
+}
+
+pub(crate) fn bitset_nlas_len(bits: &[EthtoolBitSet], no_mask: bool) -> usize {
+    bitset_to_nlas(bits, no_mask).as_slice().buffer_len()
+}
+
+pub(crate) fn emit_bitset_nlas(
+    bits: &[EthtoolBitSet],
+    no_mask: bool,
+    buffer: &mut [u8],
+) {
+    bitset_to_nlas(bits, no_mask).as_slice().emit(buffer)
+}
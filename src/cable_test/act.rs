@@ -0,0 +1,186 @@
+// SPDX-License-Identifier: MIT
+
+use futures_channel::mpsc::UnboundedReceiver;
+use futures_util::Stream;
+use genetlink::message::RawGenlMessage;
+use netlink_packet_core::NetlinkMessage;
+use netlink_packet_generic::GenlMessage;
+use netlink_sys::SocketAddr;
+
+use crate::{
+    ethtool_act_ntf, EthtoolAttr, EthtoolCablePair, EthtoolCableTestAttr,
+    EthtoolCableTestStatus, EthtoolCableTestTdrAttr, EthtoolCableTestTdrCfg,
+    EthtoolCmd, EthtoolError, EthtoolHandle, EthtoolHeader, EthtoolMessage,
+};
+
+pub struct EthtoolCableTestRequest {
+    handle: EthtoolHandle,
+    iface_name: String,
+}
+
+impl EthtoolCableTestRequest {
+    pub(crate) fn new(handle: EthtoolHandle, iface_name: &str) -> Self {
+        EthtoolCableTestRequest {
+            handle,
+            iface_name: iface_name.to_string(),
+        }
+    }
+
+    /// Start the cable test and return the `CableTestNtf` notifications of
+    /// this interface, ending with the one of `Completed` status.
+    /// The `messages` should be the receiver returned by
+    /// `new_monitor_connection()`, dedicated to this test: messages queued
+    /// before the test and other notifications are dropped.
+    pub async fn execute(
+        self,
+        messages: &mut UnboundedReceiver<(
+            NetlinkMessage<RawGenlMessage>,
+            SocketAddr,
+        )>,
+    ) -> Result<
+        impl Stream<Item = Result<GenlMessage<EthtoolMessage>, EthtoolError>> + '_,
+        EthtoolError,
+    > {
+        let EthtoolCableTestRequest { handle, iface_name } = self;
+        let message = EthtoolMessage::new_cable_test_act(&iface_name);
+        cable_test_execute(
+            handle,
+            message,
+            iface_name,
+            EthtoolCmd::CableTestNtf,
+            messages,
+        )
+        .await
+    }
+}
+
+pub struct EthtoolCableTestTdrRequest {
+    handle: EthtoolHandle,
+    iface_name: String,
+    cfg: Vec<EthtoolCableTestTdrCfg>,
+}
+
+impl EthtoolCableTestTdrRequest {
+    pub(crate) fn new(handle: EthtoolHandle, iface_name: &str) -> Self {
+        EthtoolCableTestTdrRequest {
+            handle,
+            iface_name: iface_name.to_string(),
+            cfg: Vec::new(),
+        }
+    }
+
+    /// First distance to sample, in centimeters
+    pub fn first(mut self, cm: u32) -> Self {
+        self.cfg.push(EthtoolCableTestTdrCfg::First(cm));
+        self
+    }
+
+    /// Last distance to sample, in centimeters
+    pub fn last(mut self, cm: u32) -> Self {
+        self.cfg.push(EthtoolCableTestTdrCfg::Last(cm));
+        self
+    }
+
+    /// Distance between samples, in centimeters
+    pub fn step(mut self, cm: u32) -> Self {
+        self.cfg.push(EthtoolCableTestTdrCfg::Step(cm));
+        self
+    }
+
+    /// Only test this pair instead of all pairs
+    pub fn pair(mut self, pair: EthtoolCablePair) -> Self {
+        self.cfg.push(EthtoolCableTestTdrCfg::Pair(pair));
+        self
+    }
+
+    /// Start the TDR cable test and return the `CableTestTdrNtf`
+    /// notifications of this interface, ending with the one of `Completed`
+    /// status.
+    /// The `messages` should be the receiver returned by
+    /// `new_monitor_connection()`, dedicated to this test: messages queued
+    /// before the test and other notifications are dropped.
+    pub async fn execute(
+        self,
+        messages: &mut UnboundedReceiver<(
+            NetlinkMessage<RawGenlMessage>,
+            SocketAddr,
+        )>,
+    ) -> Result<
+        impl Stream<Item = Result<GenlMessage<EthtoolMessage>, EthtoolError>> + '_,
+        EthtoolError,
+    > {
+        let EthtoolCableTestTdrRequest {
+            handle,
+            iface_name,
+            cfg,
+        } = self;
+        let mut message = EthtoolMessage::new_cable_test_tdr_act(&iface_name);
+        if !cfg.is_empty() {
+            message.nlas.push(EthtoolAttr::CableTestTdr(
+                EthtoolCableTestTdrAttr::Cfg(cfg),
+            ));
+        }
+        cable_test_execute(
+            handle,
+            message,
+            iface_name,
+            EthtoolCmd::CableTestTdrNtf,
+            messages,
+        )
+        .await
+    }
+}
+
+async fn cable_test_execute(
+    mut handle: EthtoolHandle,
+    message: EthtoolMessage,
+    iface_name: String,
+    ntf_cmd: EthtoolCmd,
+    messages: &mut UnboundedReceiver<(
+        NetlinkMessage<RawGenlMessage>,
+        SocketAddr,
+    )>,
+) -> Result<
+    impl Stream<Item = Result<GenlMessage<EthtoolMessage>, EthtoolError>> + '_,
+    EthtoolError,
+> {
+    // Notifications are multicast, hence the ones of other interfaces are
+    // skipped.
+    ethtool_act_ntf(
+        &mut handle,
+        message,
+        ntf_cmd,
+        messages,
+        move |msg| is_iface_ntf(msg, &iface_name),
+        is_completed,
+    )
+    .await
+}
+
+fn is_iface_ntf(message: &EthtoolMessage, iface_name: &str) -> bool {
+    message.nlas.iter().any(|nla| {
+        let hdrs = match nla {
+            EthtoolAttr::CableTest(EthtoolCableTestAttr::Header(hdrs))
+            | EthtoolAttr::CableTestTdr(EthtoolCableTestTdrAttr::Header(
+                hdrs,
+            )) => hdrs,
+            _ => return false,
+        };
+        hdrs.iter().any(
+            |hdr| matches!(hdr, EthtoolHeader::DevName(n) if n == iface_name),
+        )
+    })
+}
+
+fn is_completed(message: &EthtoolMessage) -> bool {
+    message.nlas.iter().any(|nla| {
+        matches!(
+            nla,
+            EthtoolAttr::CableTest(EthtoolCableTestAttr::Status(
+                EthtoolCableTestStatus::Completed
+            )) | EthtoolAttr::CableTestTdr(EthtoolCableTestTdrAttr::Status(
+                EthtoolCableTestStatus::Completed
+            ))
+        )
+    })
+}
@@ -0,0 +1,684 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_i16, emit_u32, parse_i16, parse_u32, parse_u8, DecodeError,
+    DefaultNla, Emitable, ErrorContext, Nla, NlaBuffer, NlasIterator,
+    Parseable, NLA_F_NESTED,
+};
+
+use crate::{EthtoolAttr, EthtoolHeader};
+
+const ETHTOOL_A_CABLE_TEST_HEADER: u16 = 1;
+
+const ETHTOOL_A_CABLE_TEST_NTF_HEADER: u16 = 1;
+const ETHTOOL_A_CABLE_TEST_NTF_STATUS: u16 = 2;
+const ETHTOOL_A_CABLE_TEST_NTF_NEST: u16 = 3;
+
+const ETHTOOL_A_CABLE_TEST_TDR_HEADER: u16 = 1;
+const ETHTOOL_A_CABLE_TEST_TDR_CFG: u16 = 2;
+
+const ETHTOOL_A_CABLE_TEST_TDR_NTF_HEADER: u16 = 1;
+const ETHTOOL_A_CABLE_TEST_TDR_NTF_STATUS: u16 = 2;
+const ETHTOOL_A_CABLE_TEST_TDR_NTF_NEST: u16 = 3;
+
+const ETHTOOL_A_CABLE_TEST_NTF_STATUS_STARTED: u8 = 1;
+const ETHTOOL_A_CABLE_TEST_NTF_STATUS_COMPLETED: u8 = 2;
+
+const ETHTOOL_A_CABLE_NEST_RESULT: u16 = 1;
+const ETHTOOL_A_CABLE_NEST_FAULT_LENGTH: u16 = 2;
+
+const ETHTOOL_A_CABLE_RESULT_PAIR: u16 = 1;
+const ETHTOOL_A_CABLE_RESULT_CODE: u16 = 2;
+
+const ETHTOOL_A_CABLE_FAULT_LENGTH_PAIR: u16 = 1;
+const ETHTOOL_A_CABLE_FAULT_LENGTH_CM: u16 = 2;
+
+const ETHTOOL_A_CABLE_RESULT_CODE_UNSPEC: u8 = 0;
+const ETHTOOL_A_CABLE_RESULT_CODE_OK: u8 = 1;
+const ETHTOOL_A_CABLE_RESULT_CODE_OPEN: u8 = 2;
+const ETHTOOL_A_CABLE_RESULT_CODE_SAME_SHORT: u8 = 3;
+const ETHTOOL_A_CABLE_RESULT_CODE_CROSS_SHORT: u8 = 4;
+
+const ETHTOOL_A_CABLE_PAIR_A: u8 = 0;
+const ETHTOOL_A_CABLE_PAIR_B: u8 = 1;
+const ETHTOOL_A_CABLE_PAIR_C: u8 = 2;
+const ETHTOOL_A_CABLE_PAIR_D: u8 = 3;
+
+const ETHTOOL_A_CABLE_TEST_TDR_CFG_FIRST: u16 = 1;
+const ETHTOOL_A_CABLE_TEST_TDR_CFG_LAST: u16 = 2;
+const ETHTOOL_A_CABLE_TEST_TDR_CFG_STEP: u16 = 3;
+const ETHTOOL_A_CABLE_TEST_TDR_CFG_PAIR: u16 = 4;
+
+const ETHTOOL_A_CABLE_TDR_NEST_STEP: u16 = 1;
+const ETHTOOL_A_CABLE_TDR_NEST_AMPLITUDE: u16 = 2;
+const ETHTOOL_A_CABLE_TDR_NEST_PULSE: u16 = 3;
+
+const ETHTOOL_A_CABLE_STEP_FIRST_DISTANCE: u16 = 1;
+const ETHTOOL_A_CABLE_STEP_LAST_DISTANCE: u16 = 2;
+const ETHTOOL_A_CABLE_STEP_STEP_DISTANCE: u16 = 3;
+
+const ETHTOOL_A_CABLE_AMPLITUDE_PAIR: u16 = 1;
+const ETHTOOL_A_CABLE_AMPLITUDE_MV: u16 = 2;
+
+const ETHTOOL_A_CABLE_PULSE_MV: u16 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EthtoolCablePair {
+    A,
+    B,
+    C,
+    D,
+    Other(u8),
+}
+
+impl From<u8> for EthtoolCablePair {
+    fn from(d: u8) -> Self {
+        match d {
+            ETHTOOL_A_CABLE_PAIR_A => Self::A,
+            ETHTOOL_A_CABLE_PAIR_B => Self::B,
+            ETHTOOL_A_CABLE_PAIR_C => Self::C,
+            ETHTOOL_A_CABLE_PAIR_D => Self::D,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<EthtoolCablePair> for u8 {
+    fn from(v: EthtoolCablePair) -> u8 {
+        match v {
+            EthtoolCablePair::A => ETHTOOL_A_CABLE_PAIR_A,
+            EthtoolCablePair::B => ETHTOOL_A_CABLE_PAIR_B,
+            EthtoolCablePair::C => ETHTOOL_A_CABLE_PAIR_C,
+            EthtoolCablePair::D => ETHTOOL_A_CABLE_PAIR_D,
+            EthtoolCablePair::Other(d) => d,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EthtoolCableResultCode {
+    Unspec,
+    Ok,
+    Open,
+    /// Short within the pair
+    SameShort,
+    /// Short to another pair
+    CrossShort,
+    Other(u8),
+}
+
+impl From<u8> for EthtoolCableResultCode {
+    fn from(d: u8) -> Self {
+        match d {
+            ETHTOOL_A_CABLE_RESULT_CODE_UNSPEC => Self::Unspec,
+            ETHTOOL_A_CABLE_RESULT_CODE_OK => Self::Ok,
+            ETHTOOL_A_CABLE_RESULT_CODE_OPEN => Self::Open,
+            ETHTOOL_A_CABLE_RESULT_CODE_SAME_SHORT => Self::SameShort,
+            ETHTOOL_A_CABLE_RESULT_CODE_CROSS_SHORT => Self::CrossShort,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<EthtoolCableResultCode> for u8 {
+    fn from(v: EthtoolCableResultCode) -> u8 {
+        match v {
+            EthtoolCableResultCode::Unspec => {
+                ETHTOOL_A_CABLE_RESULT_CODE_UNSPEC
+            }
+            EthtoolCableResultCode::Ok => ETHTOOL_A_CABLE_RESULT_CODE_OK,
+            EthtoolCableResultCode::Open => ETHTOOL_A_CABLE_RESULT_CODE_OPEN,
+            EthtoolCableResultCode::SameShort => {
+                ETHTOOL_A_CABLE_RESULT_CODE_SAME_SHORT
+            }
+            EthtoolCableResultCode::CrossShort => {
+                ETHTOOL_A_CABLE_RESULT_CODE_CROSS_SHORT
+            }
+            EthtoolCableResultCode::Other(d) => d,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EthtoolCableTestStatus {
+    Started,
+    Completed,
+    Other(u8),
+}
+
+impl From<u8> for EthtoolCableTestStatus {
+    fn from(d: u8) -> Self {
+        match d {
+            ETHTOOL_A_CABLE_TEST_NTF_STATUS_STARTED => Self::Started,
+            ETHTOOL_A_CABLE_TEST_NTF_STATUS_COMPLETED => Self::Completed,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<EthtoolCableTestStatus> for u8 {
+    fn from(v: EthtoolCableTestStatus) -> u8 {
+        match v {
+            EthtoolCableTestStatus::Started => {
+                ETHTOOL_A_CABLE_TEST_NTF_STATUS_STARTED
+            }
+            EthtoolCableTestStatus::Completed => {
+                ETHTOOL_A_CABLE_TEST_NTF_STATUS_COMPLETED
+            }
+            EthtoolCableTestStatus::Other(d) => d,
+        }
+    }
+}
+
+// Nested attribute holding a pair number and a value, shared by the cable
+// test results, fault lengths and TDR amplitudes.
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum EthtoolCablePairAttr {
+    Pair(u8),
+    U8(u16, u8),
+    U32(u16, u32),
+    I16(u16, i16),
+}
+
+impl Nla for EthtoolCablePairAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Pair(_) | Self::U8(..) => 1,
+            Self::U32(..) => 4,
+            Self::I16(..) => 2,
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            // All of ETHTOOL_A_CABLE_{RESULT,FAULT_LENGTH,AMPLITUDE}_PAIR
+            // share the same value.
+            Self::Pair(_) => ETHTOOL_A_CABLE_RESULT_PAIR,
+            Self::U8(kind, _) | Self::U32(kind, _) | Self::I16(kind, _) => {
+                *kind
+            }
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Pair(d) | Self::U8(_, d) => buffer[0] = *d,
+            Self::U32(_, d) => emit_u32(buffer, *d).unwrap(),
+            Self::I16(_, d) => emit_i16(buffer, *d).unwrap(),
+        }
+    }
+}
+
+/// Result of `ETHTOOL_MSG_CABLE_TEST_ACT`, reported per pair.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum EthtoolCableTestNest {
+    Result(EthtoolCablePair, EthtoolCableResultCode),
+    /// Distance to the fault in centimeters
+    FaultLength(EthtoolCablePair, u32),
+    Other(DefaultNla),
+}
+
+impl EthtoolCableTestNest {
+    fn pair_attrs(&self) -> Vec<EthtoolCablePairAttr> {
+        match self {
+            Self::Result(pair, code) => vec![
+                EthtoolCablePairAttr::Pair((*pair).into()),
+                EthtoolCablePairAttr::U8(
+                    ETHTOOL_A_CABLE_RESULT_CODE,
+                    (*code).into(),
+                ),
+            ],
+            Self::FaultLength(pair, cm) => vec![
+                EthtoolCablePairAttr::Pair((*pair).into()),
+                EthtoolCablePairAttr::U32(ETHTOOL_A_CABLE_FAULT_LENGTH_CM, *cm),
+            ],
+            Self::Other(_) => Vec::new(),
+        }
+    }
+}
+
+impl Nla for EthtoolCableTestNest {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Other(attr) => attr.value_len(),
+            _ => self.pair_attrs().as_slice().buffer_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Result(..) => ETHTOOL_A_CABLE_NEST_RESULT | NLA_F_NESTED,
+            Self::FaultLength(..) => {
+                ETHTOOL_A_CABLE_NEST_FAULT_LENGTH | NLA_F_NESTED
+            }
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Other(attr) => attr.emit_value(buffer),
+            _ => self.pair_attrs().as_slice().emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for EthtoolCableTestNest
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            ETHTOOL_A_CABLE_NEST_RESULT => {
+                let error_msg = "Invalid ETHTOOL_A_CABLE_NEST_RESULT";
+                let mut pair = EthtoolCablePair::Other(u8::MAX);
+                let mut code = EthtoolCableResultCode::Unspec;
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    match nla.kind() {
+                        ETHTOOL_A_CABLE_RESULT_PAIR => {
+                            pair =
+                                parse_u8(nla.value()).context(error_msg)?.into()
+                        }
+                        ETHTOOL_A_CABLE_RESULT_CODE => {
+                            code =
+                                parse_u8(nla.value()).context(error_msg)?.into()
+                        }
+                        _ => (),
+                    }
+                }
+                Self::Result(pair, code)
+            }
+            ETHTOOL_A_CABLE_NEST_FAULT_LENGTH => {
+                let error_msg = "Invalid ETHTOOL_A_CABLE_NEST_FAULT_LENGTH";
+                let mut pair = EthtoolCablePair::Other(u8::MAX);
+                let mut cm = 0;
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    match nla.kind() {
+                        ETHTOOL_A_CABLE_FAULT_LENGTH_PAIR => {
+                            pair =
+                                parse_u8(nla.value()).context(error_msg)?.into()
+                        }
+                        ETHTOOL_A_CABLE_FAULT_LENGTH_CM => {
+                            cm = parse_u32(nla.value()).context(error_msg)?
+                        }
+                        _ => (),
+                    }
+                }
+                Self::FaultLength(pair, cm)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum EthtoolCableTestAttr {
+    Header(Vec<EthtoolHeader>),
+    /// Only in notifications
+    Status(EthtoolCableTestStatus),
+    /// Only in notifications
+    Nest(Vec<EthtoolCableTestNest>),
+    Other(DefaultNla),
+}
+
+impl Nla for EthtoolCableTestAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Header(hdrs) => hdrs.as_slice().buffer_len(),
+            Self::Status(_) => 1,
+            Self::Nest(nlas) => nlas.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Header(_) => ETHTOOL_A_CABLE_TEST_HEADER | NLA_F_NESTED,
+            Self::Status(_) => ETHTOOL_A_CABLE_TEST_NTF_STATUS,
+            Self::Nest(_) => ETHTOOL_A_CABLE_TEST_NTF_NEST | NLA_F_NESTED,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Header(ref nlas) => nlas.as_slice().emit(buffer),
+            Self::Status(d) => buffer[0] = (*d).into(),
+            Self::Nest(ref nlas) => nlas.as_slice().emit(buffer),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for EthtoolCableTestAttr
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            ETHTOOL_A_CABLE_TEST_NTF_HEADER => {
+                Self::Header(parse_header_nlas(payload)?)
+            }
+            ETHTOOL_A_CABLE_TEST_NTF_STATUS => Self::Status(
+                parse_u8(payload)
+                    .context(format!(
+                        "Invalid ETHTOOL_A_CABLE_TEST_NTF_STATUS \
+                            {payload:?}"
+                    ))?
+                    .into(),
+            ),
+            ETHTOOL_A_CABLE_TEST_NTF_NEST => {
+                let mut nlas = Vec::new();
+                let error_msg = "Invalid ETHTOOL_A_CABLE_TEST_NTF_NEST";
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    nlas.push(
+                        EthtoolCableTestNest::parse(nla).context(error_msg)?,
+                    );
+                }
+                Self::Nest(nlas)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+pub(crate) fn parse_cable_test_nlas(
+    buffer: &[u8],
+) -> Result<Vec<EthtoolAttr>, DecodeError> {
+    let mut nlas = Vec::new();
+    for nla in NlasIterator::new(buffer) {
+        let error_msg = format!(
+            "Failed to parse ethtool cable test message attribute {:?}",
+            nla
+        );
+        let nla = &nla.context(error_msg.clone())?;
+        let parsed = EthtoolCableTestAttr::parse(nla).context(error_msg)?;
+        nlas.push(EthtoolAttr::CableTest(parsed));
+    }
+    Ok(nlas)
+}
+
+/// TDR configuration, distances are in centimeters.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum EthtoolCableTestTdrCfg {
+    First(u32),
+    Last(u32),
+    Step(u32),
+    Pair(EthtoolCablePair),
+}
+
+impl Nla for EthtoolCableTestTdrCfg {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Pair(_) => 1,
+            _ => 4,
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::First(_) => ETHTOOL_A_CABLE_TEST_TDR_CFG_FIRST,
+            Self::Last(_) => ETHTOOL_A_CABLE_TEST_TDR_CFG_LAST,
+            Self::Step(_) => ETHTOOL_A_CABLE_TEST_TDR_CFG_STEP,
+            Self::Pair(_) => ETHTOOL_A_CABLE_TEST_TDR_CFG_PAIR,
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::First(d) | Self::Last(d) | Self::Step(d) => {
+                emit_u32(buffer, *d).unwrap()
+            }
+            Self::Pair(pair) => buffer[0] = (*pair).into(),
+        }
+    }
+}
+
+/// Distances of the following amplitude samples, in centimeters.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct EthtoolCableTdrStep {
+    pub first: u32,
+    pub last: u32,
+    pub step: u32,
+}
+
+/// Result of `ETHTOOL_MSG_CABLE_TEST_TDR_ACT`, in the order sent by kernel.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum EthtoolCableTdrNest {
+    Step(EthtoolCableTdrStep),
+    /// Reflection amplitude in millivolts
+    Amplitude(EthtoolCablePair, i16),
+    /// Test pulse amplitude in millivolts
+    Pulse(i16),
+    Other(DefaultNla),
+}
+
+impl EthtoolCableTdrNest {
+    fn pair_attrs(&self) -> Vec<EthtoolCablePairAttr> {
+        match self {
+            Self::Step(step) => vec![
+                EthtoolCablePairAttr::U32(
+                    ETHTOOL_A_CABLE_STEP_FIRST_DISTANCE,
+                    step.first,
+                ),
+                EthtoolCablePairAttr::U32(
+                    ETHTOOL_A_CABLE_STEP_LAST_DISTANCE,
+                    step.last,
+                ),
+                EthtoolCablePairAttr::U32(
+                    ETHTOOL_A_CABLE_STEP_STEP_DISTANCE,
+                    step.step,
+                ),
+            ],
+            Self::Amplitude(pair, mv) => vec![
+                EthtoolCablePairAttr::Pair((*pair).into()),
+                EthtoolCablePairAttr::I16(ETHTOOL_A_CABLE_AMPLITUDE_MV, *mv),
+            ],
+            Self::Pulse(mv) => {
+                vec![EthtoolCablePairAttr::I16(ETHTOOL_A_CABLE_PULSE_MV, *mv)]
+            }
+            Self::Other(_) => Vec::new(),
+        }
+    }
+}
+
+impl Nla for EthtoolCableTdrNest {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Other(attr) => attr.value_len(),
+            _ => self.pair_attrs().as_slice().buffer_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Step(_) => ETHTOOL_A_CABLE_TDR_NEST_STEP | NLA_F_NESTED,
+            Self::Amplitude(..) => {
+                ETHTOOL_A_CABLE_TDR_NEST_AMPLITUDE | NLA_F_NESTED
+            }
+            Self::Pulse(_) => ETHTOOL_A_CABLE_TDR_NEST_PULSE | NLA_F_NESTED,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Other(attr) => attr.emit_value(buffer),
+            _ => self.pair_attrs().as_slice().emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for EthtoolCableTdrNest
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            ETHTOOL_A_CABLE_TDR_NEST_STEP => {
+                let error_msg = "Invalid ETHTOOL_A_CABLE_TDR_NEST_STEP";
+                let mut step = EthtoolCableTdrStep::default();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    let value = parse_u32(nla.value()).context(error_msg)?;
+                    match nla.kind() {
+                        ETHTOOL_A_CABLE_STEP_FIRST_DISTANCE => {
+                            step.first = value
+                        }
+                        ETHTOOL_A_CABLE_STEP_LAST_DISTANCE => step.last = value,
+                        ETHTOOL_A_CABLE_STEP_STEP_DISTANCE => step.step = value,
+                        _ => (),
+                    }
+                }
+                Self::Step(step)
+            }
+            ETHTOOL_A_CABLE_TDR_NEST_AMPLITUDE => {
+                let error_msg = "Invalid ETHTOOL_A_CABLE_TDR_NEST_AMPLITUDE";
+                let mut pair = EthtoolCablePair::Other(u8::MAX);
+                let mut mv = 0;
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    match nla.kind() {
+                        ETHTOOL_A_CABLE_AMPLITUDE_PAIR => {
+                            pair =
+                                parse_u8(nla.value()).context(error_msg)?.into()
+                        }
+                        ETHTOOL_A_CABLE_AMPLITUDE_MV => {
+                            mv = parse_i16(nla.value()).context(error_msg)?
+                        }
+                        _ => (),
+                    }
+                }
+                Self::Amplitude(pair, mv)
+            }
+            ETHTOOL_A_CABLE_TDR_NEST_PULSE => {
+                let error_msg = "Invalid ETHTOOL_A_CABLE_TDR_NEST_PULSE";
+                let mut mv = 0;
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    if nla.kind() == ETHTOOL_A_CABLE_PULSE_MV {
+                        mv = parse_i16(nla.value()).context(error_msg)?;
+                    }
+                }
+                Self::Pulse(mv)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum EthtoolCableTestTdrAttr {
+    Header(Vec<EthtoolHeader>),
+    /// Only in request, shares the attribute kind of `Status`
+    Cfg(Vec<EthtoolCableTestTdrCfg>),
+    /// Only in notifications
+    Status(EthtoolCableTestStatus),
+    /// Only in notifications
+    Nest(Vec<EthtoolCableTdrNest>),
+    Other(DefaultNla),
+}
+
+impl Nla for EthtoolCableTestTdrAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Header(hdrs) => hdrs.as_slice().buffer_len(),
+            Self::Cfg(nlas) => nlas.as_slice().buffer_len(),
+            Self::Status(_) => 1,
+            Self::Nest(nlas) => nlas.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Header(_) => ETHTOOL_A_CABLE_TEST_TDR_HEADER | NLA_F_NESTED,
+            Self::Cfg(_) => ETHTOOL_A_CABLE_TEST_TDR_CFG | NLA_F_NESTED,
+            Self::Status(_) => ETHTOOL_A_CABLE_TEST_TDR_NTF_STATUS,
+            Self::Nest(_) => ETHTOOL_A_CABLE_TEST_TDR_NTF_NEST | NLA_F_NESTED,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Header(ref nlas) => nlas.as_slice().emit(buffer),
+            Self::Cfg(ref nlas) => nlas.as_slice().emit(buffer),
+            Self::Status(d) => buffer[0] = (*d).into(),
+            Self::Nest(ref nlas) => nlas.as_slice().emit(buffer),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for EthtoolCableTestTdrAttr
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            ETHTOOL_A_CABLE_TEST_TDR_NTF_HEADER => {
+                Self::Header(parse_header_nlas(payload)?)
+            }
+            ETHTOOL_A_CABLE_TEST_TDR_NTF_STATUS => Self::Status(
+                parse_u8(payload)
+                    .context(format!(
+                        "Invalid ETHTOOL_A_CABLE_TEST_TDR_NTF_STATUS \
+                            {payload:?}"
+                    ))?
+                    .into(),
+            ),
+            ETHTOOL_A_CABLE_TEST_TDR_NTF_NEST => {
+                let mut nlas = Vec::new();
+                let error_msg = "Invalid ETHTOOL_A_CABLE_TEST_TDR_NTF_NEST";
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    nlas.push(
+                        EthtoolCableTdrNest::parse(nla).context(error_msg)?,
+                    );
+                }
+                Self::Nest(nlas)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+pub(crate) fn parse_cable_test_tdr_nlas(
+    buffer: &[u8],
+) -> Result<Vec<EthtoolAttr>, DecodeError> {
+    let mut nlas = Vec::new();
+    for nla in NlasIterator::new(buffer) {
+        let error_msg = format!(
+            "Failed to parse ethtool cable test TDR message attribute {:?}",
+            nla
+        );
+        let nla = &nla.context(error_msg.clone())?;
+        let parsed = EthtoolCableTestTdrAttr::parse(nla).context(error_msg)?;
+        nlas.push(EthtoolAttr::CableTestTdr(parsed));
+    }
+    Ok(nlas)
+}
+
+fn parse_header_nlas(
+    payload: &[u8],
+) -> Result<Vec<EthtoolHeader>, DecodeError> {
+    let mut nlas = Vec::new();
+    let error_msg = "failed to parse cable test header attributes";
+    for nla in NlasIterator::new(payload) {
+        let nla = &nla.context(error_msg)?;
+        nlas.push(EthtoolHeader::parse(nla).context(error_msg)?);
+    }
+    Ok(nlas)
+}
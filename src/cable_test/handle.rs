@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    EthtoolCableTestRequest, EthtoolCableTestTdrRequest, EthtoolHandle,
+};
+
+pub struct EthtoolCableTestHandle(EthtoolHandle);
+
+impl EthtoolCableTestHandle {
+    pub fn new(handle: EthtoolHandle) -> Self {
+        EthtoolCableTestHandle(handle)
+    }
+
+    /// Run the PHY cable test on a interface (used by
+    /// `ethtool --cable-test eth1`)
+    pub fn start(&mut self, iface_name: &str) -> EthtoolCableTestRequest {
+        EthtoolCableTestRequest::new(self.0.clone(), iface_name)
+    }
+
+    /// Run the PHY cable test collecting TDR data on a interface (used by
+    /// `ethtool --cable-test-tdr eth1 first 100 last 1000 step 100`)
+    pub fn start_tdr(
+        &mut self,
+        iface_name: &str,
+    ) -> EthtoolCableTestTdrRequest {
+        EthtoolCableTestTdrRequest::new(self.0.clone(), iface_name)
+    }
+}
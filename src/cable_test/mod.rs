@@ -0,0 +1,15 @@
+// SPDX-License-Identifier: MIT
+
+mod act;
+mod attr;
+mod handle;
+
+pub(crate) use attr::{parse_cable_test_nlas, parse_cable_test_tdr_nlas};
+
+pub use act::{EthtoolCableTestRequest, EthtoolCableTestTdrRequest};
+pub use attr::{
+    EthtoolCablePair, EthtoolCableResultCode, EthtoolCableTdrNest,
+    EthtoolCableTdrStep, EthtoolCableTestAttr, EthtoolCableTestNest,
+    EthtoolCableTestStatus, EthtoolCableTestTdrAttr, EthtoolCableTestTdrCfg,
+};
+pub use handle::EthtoolCableTestHandle;
@@ -1,6 +1,8 @@
 // SPDX-License-Identifier: MIT
 
-use crate::{EthtoolCoalesceGetRequest, EthtoolHandle};
+use crate::{
+    EthtoolCoalesceGetRequest, EthtoolCoalesceSetRequest, EthtoolHandle,
+};
 
 pub struct EthtoolCoalesceHandle(EthtoolHandle);
 
@@ -17,4 +19,10 @@ impl EthtoolCoalesceHandle {
     ) -> EthtoolCoalesceGetRequest {
         EthtoolCoalesceGetRequest::new(self.0.clone(), iface_name)
     }
+
+    /// Set the ethtool coalesces of a interface (equivalent to `ethtool -C
+    /// eth1`)
+    pub fn set(&mut self, iface_name: &str) -> EthtoolCoalesceSetRequest {
+        EthtoolCoalesceSetRequest::new(self.0.clone(), iface_name)
+    }
 }
@@ -0,0 +1,331 @@
+// SPDX-License-Identifier: MIT
+
+use futures_util::StreamExt;
+use netlink_packet_core::{NetlinkMessage, NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    try_ethtool, EthtoolAttr, EthtoolCoalesceAttr, EthtoolError, EthtoolHandle,
+    EthtoolMessage,
+};
+
+pub struct EthtoolCoalesceSetRequest {
+    handle: EthtoolHandle,
+    message: EthtoolMessage,
+    rx_usecs: Option<u32>,
+    rx_max_frames: Option<u32>,
+    rx_usecs_irq: Option<u32>,
+    rx_max_frames_irq: Option<u32>,
+    tx_usecs: Option<u32>,
+    tx_max_frames: Option<u32>,
+    tx_usecs_irq: Option<u32>,
+    tx_max_frames_irq: Option<u32>,
+    stats_block_usecs: Option<u32>,
+    use_adaptive_rx: Option<bool>,
+    use_adaptive_tx: Option<bool>,
+    pkt_rate_low: Option<u32>,
+    rx_usecs_low: Option<u32>,
+    rx_max_frames_low: Option<u32>,
+    tx_usecs_low: Option<u32>,
+    tx_max_frames_low: Option<u32>,
+    pkt_rate_high: Option<u32>,
+    rx_usecs_high: Option<u32>,
+    rx_max_frames_high: Option<u32>,
+    tx_usecs_high: Option<u32>,
+    tx_max_frames_high: Option<u32>,
+    rate_sample_interval: Option<u32>,
+}
+
+impl EthtoolCoalesceSetRequest {
+    pub(crate) fn new(handle: EthtoolHandle, iface_name: &str) -> Self {
+        EthtoolCoalesceSetRequest {
+            handle,
+            message: EthtoolMessage::new_coalesce_set(iface_name),
+            rx_usecs: None,
+            rx_max_frames: None,
+            rx_usecs_irq: None,
+            rx_max_frames_irq: None,
+            tx_usecs: None,
+            tx_max_frames: None,
+            tx_usecs_irq: None,
+            tx_max_frames_irq: None,
+            stats_block_usecs: None,
+            use_adaptive_rx: None,
+            use_adaptive_tx: None,
+            pkt_rate_low: None,
+            rx_usecs_low: None,
+            rx_max_frames_low: None,
+            tx_usecs_low: None,
+            tx_max_frames_low: None,
+            pkt_rate_high: None,
+            rx_usecs_high: None,
+            rx_max_frames_high: None,
+            tx_usecs_high: None,
+            tx_max_frames_high: None,
+            rate_sample_interval: None,
+        }
+    }
+
+    pub fn rx_usecs(mut self, value: u32) -> Self {
+        self.rx_usecs = Some(value);
+        self
+    }
+
+    pub fn rx_max_frames(mut self, value: u32) -> Self {
+        self.rx_max_frames = Some(value);
+        self
+    }
+
+    pub fn rx_usecs_irq(mut self, value: u32) -> Self {
+        self.rx_usecs_irq = Some(value);
+        self
+    }
+
+    pub fn rx_max_frames_irq(mut self, value: u32) -> Self {
+        self.rx_max_frames_irq = Some(value);
+        self
+    }
+
+    pub fn tx_usecs(mut self, value: u32) -> Self {
+        self.tx_usecs = Some(value);
+        self
+    }
+
+    pub fn tx_max_frames(mut self, value: u32) -> Self {
+        self.tx_max_frames = Some(value);
+        self
+    }
+
+    pub fn tx_usecs_irq(mut self, value: u32) -> Self {
+        self.tx_usecs_irq = Some(value);
+        self
+    }
+
+    pub fn tx_max_frames_irq(mut self, value: u32) -> Self {
+        self.tx_max_frames_irq = Some(value);
+        self
+    }
+
+    pub fn stats_block_usecs(mut self, value: u32) -> Self {
+        self.stats_block_usecs = Some(value);
+        self
+    }
+
+    pub fn use_adaptive_rx(mut self, enabled: bool) -> Self {
+        self.use_adaptive_rx = Some(enabled);
+        self
+    }
+
+    pub fn use_adaptive_tx(mut self, enabled: bool) -> Self {
+        self.use_adaptive_tx = Some(enabled);
+        self
+    }
+
+    pub fn pkt_rate_low(mut self, value: u32) -> Self {
+        self.pkt_rate_low = Some(value);
+        self
+    }
+
+    pub fn rx_usecs_low(mut self, value: u32) -> Self {
+        self.rx_usecs_low = Some(value);
+        self
+    }
+
+    pub fn rx_max_frames_low(mut self, value: u32) -> Self {
+        self.rx_max_frames_low = Some(value);
+        self
+    }
+
+    pub fn tx_usecs_low(mut self, value: u32) -> Self {
+        self.tx_usecs_low = Some(value);
+        self
+    }
+
+    pub fn tx_max_frames_low(mut self, value: u32) -> Self {
+        self.tx_max_frames_low = Some(value);
+        self
+    }
+
+    pub fn pkt_rate_high(mut self, value: u32) -> Self {
+        self.pkt_rate_high = Some(value);
+        self
+    }
+
+    pub fn rx_usecs_high(mut self, value: u32) -> Self {
+        self.rx_usecs_high = Some(value);
+        self
+    }
+
+    pub fn rx_max_frames_high(mut self, value: u32) -> Self {
+        self.rx_max_frames_high = Some(value);
+        self
+    }
+
+    pub fn tx_usecs_high(mut self, value: u32) -> Self {
+        self.tx_usecs_high = Some(value);
+        self
+    }
+
+    pub fn tx_max_frames_high(mut self, value: u32) -> Self {
+        self.tx_max_frames_high = Some(value);
+        self
+    }
+
+    pub fn rate_sample_interval(mut self, value: u32) -> Self {
+        self.rate_sample_interval = Some(value);
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), EthtoolError> {
+        let EthtoolCoalesceSetRequest {
+            mut handle,
+            mut message,
+            rx_usecs,
+            rx_max_frames,
+            rx_usecs_irq,
+            rx_max_frames_irq,
+            tx_usecs,
+            tx_max_frames,
+            tx_usecs_irq,
+            tx_max_frames_irq,
+            stats_block_usecs,
+            use_adaptive_rx,
+            use_adaptive_tx,
+            pkt_rate_low,
+            rx_usecs_low,
+            rx_max_frames_low,
+            tx_usecs_low,
+            tx_max_frames_low,
+            pkt_rate_high,
+            rx_usecs_high,
+            rx_max_frames_high,
+            tx_usecs_high,
+            tx_max_frames_high,
+            rate_sample_interval,
+        } = self;
+
+        if let Some(value) = rx_usecs {
+            message.nlas.push(EthtoolAttr::Coalesce(
+                EthtoolCoalesceAttr::RxUsecs(value),
+            ));
+        }
+        if let Some(value) = rx_max_frames {
+            message.nlas.push(EthtoolAttr::Coalesce(
+                EthtoolCoalesceAttr::RxMaxFrames(value),
+            ));
+        }
+        if let Some(value) = rx_usecs_irq {
+            message.nlas.push(EthtoolAttr::Coalesce(
+                EthtoolCoalesceAttr::RxUsecsIrq(value),
+            ));
+        }
+        if let Some(value) = rx_max_frames_irq {
+            message.nlas.push(EthtoolAttr::Coalesce(
+                EthtoolCoalesceAttr::RxMaxFramesIrq(value),
+            ));
+        }
+        if let Some(value) = tx_usecs {
+            message.nlas.push(EthtoolAttr::Coalesce(
+                EthtoolCoalesceAttr::TxUsecs(value),
+            ));
+        }
+        if let Some(value) = tx_max_frames {
+            message.nlas.push(EthtoolAttr::Coalesce(
+                EthtoolCoalesceAttr::TxMaxFrames(value),
+            ));
+        }
+        if let Some(value) = tx_usecs_irq {
+            message.nlas.push(EthtoolAttr::Coalesce(
+                EthtoolCoalesceAttr::TxUsecsIrq(value),
+            ));
+        }
+        if let Some(value) = tx_max_frames_irq {
+            message.nlas.push(EthtoolAttr::Coalesce(
+                EthtoolCoalesceAttr::TxMaxFramesIrq(value),
+            ));
+        }
+        if let Some(value) = stats_block_usecs {
+            message.nlas.push(EthtoolAttr::Coalesce(
+                EthtoolCoalesceAttr::StatsBlockUsecs(value),
+            ));
+        }
+        if let Some(enabled) = use_adaptive_rx {
+            message.nlas.push(EthtoolAttr::Coalesce(
+                EthtoolCoalesceAttr::UseAdaptiveRx(enabled),
+            ));
+        }
+        if let Some(enabled) = use_adaptive_tx {
+            message.nlas.push(EthtoolAttr::Coalesce(
+                EthtoolCoalesceAttr::UseAdaptiveTx(enabled),
+            ));
+        }
+        if let Some(value) = pkt_rate_low {
+            message.nlas.push(EthtoolAttr::Coalesce(
+                EthtoolCoalesceAttr::PktRateLow(value),
+            ));
+        }
+        if let Some(value) = rx_usecs_low {
+            message.nlas.push(EthtoolAttr::Coalesce(
+                EthtoolCoalesceAttr::RxUsecsLow(value),
+            ));
+        }
+        if let Some(value) = rx_max_frames_low {
+            message.nlas.push(EthtoolAttr::Coalesce(
+                EthtoolCoalesceAttr::RxMaxFramesLow(value),
+            ));
+        }
+        if let Some(value) = tx_usecs_low {
+            message.nlas.push(EthtoolAttr::Coalesce(
+                EthtoolCoalesceAttr::TxUsecsLow(value),
+            ));
+        }
+        if let Some(value) = tx_max_frames_low {
+            message.nlas.push(EthtoolAttr::Coalesce(
+                EthtoolCoalesceAttr::TxMaxFramesLow(value),
+            ));
+        }
+        if let Some(value) = pkt_rate_high {
+            message.nlas.push(EthtoolAttr::Coalesce(
+                EthtoolCoalesceAttr::PktRateHigh(value),
+            ));
+        }
+        if let Some(value) = rx_usecs_high {
+            message.nlas.push(EthtoolAttr::Coalesce(
+                EthtoolCoalesceAttr::RxUsecsHigh(value),
+            ));
+        }
+        if let Some(value) = rx_max_frames_high {
+            message.nlas.push(EthtoolAttr::Coalesce(
+                EthtoolCoalesceAttr::RxMaxFramesHigh(value),
+            ));
+        }
+        if let Some(value) = tx_usecs_high {
+            message.nlas.push(EthtoolAttr::Coalesce(
+                EthtoolCoalesceAttr::TxUsecsHigh(value),
+            ));
+        }
+        if let Some(value) = tx_max_frames_high {
+            message.nlas.push(EthtoolAttr::Coalesce(
+                EthtoolCoalesceAttr::TxMaxFramesHigh(value),
+            ));
+        }
+        if let Some(value) = rate_sample_interval {
+            message.nlas.push(EthtoolAttr::Coalesce(
+                EthtoolCoalesceAttr::RateSampleInterval(value),
+            ));
+        }
+
+        let mut nl_msg =
+            NetlinkMessage::from(GenlMessage::from_payload(message));
+
+        nl_msg.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        let mut response = handle.request(nl_msg).await?;
+
+        while let Some(message) = response.next().await {
+            try_ethtool!(message);
+        }
+
+        Ok(())
+    }
+}
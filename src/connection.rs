@@ -4,11 +4,22 @@ use std::io;
 
 use futures_channel::mpsc::UnboundedReceiver;
 use genetlink::message::RawGenlMessage;
-use netlink_packet_core::NetlinkMessage;
+use netlink_packet_core::{NetlinkMessage, NetlinkPayload, NLM_F_REQUEST};
+use netlink_packet_generic::{
+    ctrl::{
+        nlas::{GenlCtrlAttrs, McastGrpAttrs},
+        GenlCtrl, GenlCtrlCmd,
+    },
+    GenlFamily, GenlMessage,
+};
 use netlink_proto::Connection;
-use netlink_sys::{AsyncSocket, SocketAddr};
+use netlink_sys::{
+    protocols::NETLINK_GENERIC, AsyncSocket, Socket, SocketAddr,
+};
 
-use crate::EthtoolHandle;
+use crate::{EthtoolHandle, EthtoolMessage};
+
+const ETHTOOL_MCGRP_MONITOR_NAME: &str = "monitor";
 
 #[cfg(feature = "tokio_socket")]
 #[allow(clippy::type_complexity)]
@@ -32,3 +43,101 @@ where
     let (conn, handle, messages) = genetlink::new_connection_with_socket()?;
     Ok((conn, EthtoolHandle::new(handle), messages))
 }
+
+/// Create a connection joined to the ethtool `monitor` multicast group.
+/// Ethtool notifications, including cable test results, are delivered to
+/// the returned receiver, use `ethtool_monitor()` to decode them.
+#[cfg(feature = "tokio_socket")]
+#[allow(clippy::type_complexity)]
+pub fn new_monitor_connection() -> io::Result<(
+    Connection<RawGenlMessage>,
+    EthtoolHandle,
+    UnboundedReceiver<(NetlinkMessage<RawGenlMessage>, SocketAddr)>,
+)> {
+    new_monitor_connection_with_socket()
+}
+
+#[allow(clippy::type_complexity)]
+pub fn new_monitor_connection_with_socket<S>() -> io::Result<(
+    Connection<RawGenlMessage, S>,
+    EthtoolHandle,
+    UnboundedReceiver<(NetlinkMessage<RawGenlMessage>, SocketAddr)>,
+)>
+where
+    S: AsyncSocket,
+{
+    let group_id = resolve_monitor_group_id()?;
+    let (mut conn, handle, messages) = new_connection_with_socket::<S>()?;
+    let socket = conn.socket_mut().socket_mut();
+    // Kernel skips unbound sockets when delivering multicast messages.
+    socket.bind_auto()?;
+    socket.add_membership(group_id)?;
+    Ok((conn, handle, messages))
+}
+
+// The socket of the connection is not reachable once spawned, hence the
+// multicast group is resolved beforehand using a blocking socket.
+fn resolve_monitor_group_id() -> io::Result<u32> {
+    let mut socket = Socket::new(NETLINK_GENERIC)?;
+    socket.bind_auto()?;
+    socket.connect(&SocketAddr::new(0, 0))?;
+
+    let mut genl_msg = GenlMessage::from_payload(GenlCtrl {
+        cmd: GenlCtrlCmd::GetFamily,
+        nlas: vec![GenlCtrlAttrs::FamilyName(
+            EthtoolMessage::family_name().to_string(),
+        )],
+    });
+    genl_msg.finalize();
+    let mut nl_msg = NetlinkMessage::from(genl_msg);
+    nl_msg.header.flags = NLM_F_REQUEST;
+    nl_msg.finalize();
+
+    let mut buf = vec![0; nl_msg.buffer_len()];
+    nl_msg.serialize(&mut buf);
+    socket.send(&buf, 0)?;
+
+    let (buf, _) = socket.recv_from_full()?;
+    let reply = NetlinkMessage::<GenlMessage<GenlCtrl>>::deserialize(&buf)
+        .map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Failed to parse nlctrl reply: {e}"),
+            )
+        })?;
+
+    match reply.payload {
+        NetlinkPayload::InnerMessage(genl_msg) => genl_msg
+            .payload
+            .nlas
+            .into_iter()
+            .filter_map(|nla| match nla {
+                GenlCtrlAttrs::McastGroups(groups) => Some(groups),
+                _ => None,
+            })
+            .flatten()
+            .find_map(|group| {
+                if !group.iter().any(|attr| {
+                    matches!(attr, McastGrpAttrs::Name(name)
+                        if name == ETHTOOL_MCGRP_MONITOR_NAME)
+                }) {
+                    return None;
+                }
+                group.iter().find_map(|attr| match attr {
+                    McastGrpAttrs::Id(id) => Some(*id),
+                    _ => None,
+                })
+            })
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    "No ethtool monitor multicast group found",
+                )
+            }),
+        NetlinkPayload::Error(e) => Err(e.to_io()),
+        payload => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Unexpected nlctrl reply: {payload:?}"),
+        )),
+    }
+}
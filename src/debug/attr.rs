@@ -0,0 +1,264 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    DecodeError, DefaultNla, Emitable, ErrorContext, Nla, NlaBuffer,
+    NlasIterator, Parseable, NLA_F_NESTED,
+};
+
+use crate::{
+    bitset_util::{
+        bitset_nlas_len, emit_bitset_nlas, parse_bitset_bits_nlas,
+        EthtoolBitSet,
+    },
+    EthtoolAttr, EthtoolError, EthtoolHeader,
+};
+
+const ETHTOOL_A_DEBUG_HEADER: u16 = 1;
+const ETHTOOL_A_DEBUG_MSGMASK: u16 = 2;
+
+const NETIF_MSG_DRV_BIT: u32 = 0;
+const NETIF_MSG_PROBE_BIT: u32 = 1;
+const NETIF_MSG_LINK_BIT: u32 = 2;
+const NETIF_MSG_TIMER_BIT: u32 = 3;
+const NETIF_MSG_IFDOWN_BIT: u32 = 4;
+const NETIF_MSG_IFUP_BIT: u32 = 5;
+const NETIF_MSG_RX_ERR_BIT: u32 = 6;
+const NETIF_MSG_TX_ERR_BIT: u32 = 7;
+const NETIF_MSG_TX_QUEUED_BIT: u32 = 8;
+const NETIF_MSG_INTR_BIT: u32 = 9;
+const NETIF_MSG_TX_DONE_BIT: u32 = 10;
+const NETIF_MSG_RX_STATUS_BIT: u32 = 11;
+const NETIF_MSG_PKTDATA_BIT: u32 = 12;
+const NETIF_MSG_HW_BIT: u32 = 13;
+const NETIF_MSG_WOL_BIT: u32 = 14;
+
+pub(crate) const MSG_LEVEL_COUNT: u32 = 15;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EthtoolMsgLevel {
+    Drv,
+    Probe,
+    Link,
+    Timer,
+    IfDown,
+    IfUp,
+    RxErr,
+    TxErr,
+    TxQueued,
+    Intr,
+    TxDone,
+    RxStatus,
+    PktData,
+    Hw,
+    Wol,
+    /// Bit index of message level unknown to this crate
+    Other(u32),
+}
+
+impl std::fmt::Display for EthtoolMsgLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Drv => write!(f, "drv"),
+            Self::Probe => write!(f, "probe"),
+            Self::Link => write!(f, "link"),
+            Self::Timer => write!(f, "timer"),
+            Self::IfDown => write!(f, "ifdown"),
+            Self::IfUp => write!(f, "ifup"),
+            Self::RxErr => write!(f, "rx_err"),
+            Self::TxErr => write!(f, "tx_err"),
+            Self::TxQueued => write!(f, "tx_queued"),
+            Self::Intr => write!(f, "intr"),
+            Self::TxDone => write!(f, "tx_done"),
+            Self::RxStatus => write!(f, "rx_status"),
+            Self::PktData => write!(f, "pktdata"),
+            Self::Hw => write!(f, "hw"),
+            Self::Wol => write!(f, "wol"),
+            Self::Other(d) => write!(f, "{d}"),
+        }
+    }
+}
+
+// Accepts the names used by `Display`, which are the kernel `msglvl` names,
+// or a bit index.
+impl std::str::FromStr for EthtoolMsgLevel {
+    type Err = EthtoolError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "drv" => Self::Drv,
+            "probe" => Self::Probe,
+            "link" => Self::Link,
+            "timer" => Self::Timer,
+            "ifdown" => Self::IfDown,
+            "ifup" => Self::IfUp,
+            "rx_err" => Self::RxErr,
+            "tx_err" => Self::TxErr,
+            "tx_queued" => Self::TxQueued,
+            "intr" => Self::Intr,
+            "tx_done" => Self::TxDone,
+            "rx_status" => Self::RxStatus,
+            "pktdata" => Self::PktData,
+            "hw" => Self::Hw,
+            "wol" => Self::Wol,
+            _ => match s.parse::<u32>() {
+                Ok(d) => Self::from(d),
+                Err(_) => {
+                    return Err(EthtoolError::InvalidArgument(format!(
+                        "Unknown message level {s}"
+                    )))
+                }
+            },
+        })
+    }
+}
+
+impl From<u32> for EthtoolMsgLevel {
+    fn from(d: u32) -> Self {
+        match d {
+            NETIF_MSG_DRV_BIT => Self::Drv,
+            NETIF_MSG_PROBE_BIT => Self::Probe,
+            NETIF_MSG_LINK_BIT => Self::Link,
+            NETIF_MSG_TIMER_BIT => Self::Timer,
+            NETIF_MSG_IFDOWN_BIT => Self::IfDown,
+            NETIF_MSG_IFUP_BIT => Self::IfUp,
+            NETIF_MSG_RX_ERR_BIT => Self::RxErr,
+            NETIF_MSG_TX_ERR_BIT => Self::TxErr,
+            NETIF_MSG_TX_QUEUED_BIT => Self::TxQueued,
+            NETIF_MSG_INTR_BIT => Self::Intr,
+            NETIF_MSG_TX_DONE_BIT => Self::TxDone,
+            NETIF_MSG_RX_STATUS_BIT => Self::RxStatus,
+            NETIF_MSG_PKTDATA_BIT => Self::PktData,
+            NETIF_MSG_HW_BIT => Self::Hw,
+            NETIF_MSG_WOL_BIT => Self::Wol,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<EthtoolMsgLevel> for u32 {
+    fn from(v: EthtoolMsgLevel) -> u32 {
+        match v {
+            EthtoolMsgLevel::Drv => NETIF_MSG_DRV_BIT,
+            EthtoolMsgLevel::Probe => NETIF_MSG_PROBE_BIT,
+            EthtoolMsgLevel::Link => NETIF_MSG_LINK_BIT,
+            EthtoolMsgLevel::Timer => NETIF_MSG_TIMER_BIT,
+            EthtoolMsgLevel::IfDown => NETIF_MSG_IFDOWN_BIT,
+            EthtoolMsgLevel::IfUp => NETIF_MSG_IFUP_BIT,
+            EthtoolMsgLevel::RxErr => NETIF_MSG_RX_ERR_BIT,
+            EthtoolMsgLevel::TxErr => NETIF_MSG_TX_ERR_BIT,
+            EthtoolMsgLevel::TxQueued => NETIF_MSG_TX_QUEUED_BIT,
+            EthtoolMsgLevel::Intr => NETIF_MSG_INTR_BIT,
+            EthtoolMsgLevel::TxDone => NETIF_MSG_TX_DONE_BIT,
+            EthtoolMsgLevel::RxStatus => NETIF_MSG_RX_STATUS_BIT,
+            EthtoolMsgLevel::PktData => NETIF_MSG_PKTDATA_BIT,
+            EthtoolMsgLevel::Hw => NETIF_MSG_HW_BIT,
+            EthtoolMsgLevel::Wol => NETIF_MSG_WOL_BIT,
+            EthtoolMsgLevel::Other(d) => d,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum EthtoolDebugAttr {
+    Header(Vec<EthtoolHeader>),
+    /// Driver message levels and whether they are enabled. Kernel only
+    /// replies the enabled ones.
+    /// When setting, only listed levels are changed.
+    MsgMask(Vec<(EthtoolMsgLevel, bool)>),
+    Other(DefaultNla),
+}
+
+// Message levels are emitted with mask, so levels not listed are left
+// unchanged.
+fn msg_levels_to_bitset(
+    levels: &[(EthtoolMsgLevel, bool)],
+) -> Vec<EthtoolBitSet> {
+    levels
+        .iter()
+        .map(|(level, enabled)| EthtoolBitSet {
+            index: (*level).into(),
+            name: String::new(),
+            value: *enabled,
+        })
+        .collect()
+}
+
+impl Nla for EthtoolDebugAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Header(hdrs) => hdrs.as_slice().buffer_len(),
+            Self::MsgMask(levels) => {
+                bitset_nlas_len(&msg_levels_to_bitset(levels), false)
+            }
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Header(_) => ETHTOOL_A_DEBUG_HEADER | NLA_F_NESTED,
+            Self::MsgMask(_) => ETHTOOL_A_DEBUG_MSGMASK | NLA_F_NESTED,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Header(ref nlas) => nlas.as_slice().emit(buffer),
+            Self::MsgMask(levels) => {
+                emit_bitset_nlas(&msg_levels_to_bitset(levels), false, buffer)
+            }
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for EthtoolDebugAttr
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            ETHTOOL_A_DEBUG_HEADER => {
+                let mut nlas = Vec::new();
+                let error_msg = "failed to parse debug header attributes";
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    let parsed =
+                        EthtoolHeader::parse(nla).context(error_msg)?;
+                    nlas.push(parsed);
+                }
+                Self::Header(nlas)
+            }
+            ETHTOOL_A_DEBUG_MSGMASK => {
+                let bits = parse_bitset_bits_nlas(payload).context(format!(
+                    "Invalid ETHTOOL_A_DEBUG_MSGMASK {payload:?}"
+                ))?;
+                Self::MsgMask(
+                    bits.into_iter()
+                        .map(|b| (EthtoolMsgLevel::from(b.index), b.value))
+                        .collect(),
+                )
+            }
+            kind => {
+                Self::Other(DefaultNla::parse(buf).context(format!(
+                    "invalid ethtool debug NLA kind {kind}"
+                ))?)
+            }
+        })
+    }
+}
+
+pub(crate) fn parse_debug_nlas(
+    buffer: &[u8],
+) -> Result<Vec<EthtoolAttr>, DecodeError> {
+    let mut nlas = Vec::new();
+    for nla in NlasIterator::new(buffer) {
+        let error_msg =
+            format!("Failed to parse ethtool debug message attribute {nla:?}");
+        let nla = &nla.context(error_msg.clone())?;
+        let parsed = EthtoolDebugAttr::parse(nla).context(error_msg)?;
+        nlas.push(EthtoolAttr::Debug(parsed));
+    }
+    Ok(nlas)
+}
@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{EthtoolDebugGetRequest, EthtoolDebugSetRequest, EthtoolHandle};
+
+pub struct EthtoolDebugHandle(EthtoolHandle);
+
+impl EthtoolDebugHandle {
+    pub fn new(handle: EthtoolHandle) -> Self {
+        EthtoolDebugHandle(handle)
+    }
+
+    /// Retrieve the driver message levels of a interface (used by
+    /// `ethtool eth1`)
+    pub fn get(&mut self, iface_name: Option<&str>) -> EthtoolDebugGetRequest {
+        EthtoolDebugGetRequest::new(self.0.clone(), iface_name)
+    }
+
+    /// Set the driver message levels of a interface (equivalent to
+    /// `ethtool -s eth1 msglvl drv on`)
+    pub fn set(&mut self, iface_name: &str) -> EthtoolDebugSetRequest {
+        EthtoolDebugSetRequest::new(self.0.clone(), iface_name)
+    }
+}
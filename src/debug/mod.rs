@@ -0,0 +1,13 @@
+// SPDX-License-Identifier: MIT
+
+mod attr;
+mod get;
+mod handle;
+mod set;
+
+pub(crate) use attr::parse_debug_nlas;
+
+pub use attr::{EthtoolDebugAttr, EthtoolMsgLevel};
+pub use get::EthtoolDebugGetRequest;
+pub use handle::EthtoolDebugHandle;
+pub use set::EthtoolDebugSetRequest;
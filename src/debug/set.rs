@@ -0,0 +1,72 @@
+// SPDX-License-Identifier: MIT
+
+use futures_util::StreamExt;
+use netlink_packet_core::{NetlinkMessage, NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use super::attr::MSG_LEVEL_COUNT;
+use crate::{
+    try_ethtool, EthtoolAttr, EthtoolDebugAttr, EthtoolError, EthtoolHandle,
+    EthtoolMessage, EthtoolMsgLevel,
+};
+
+pub struct EthtoolDebugSetRequest {
+    handle: EthtoolHandle,
+    message: EthtoolMessage,
+    levels: Vec<(EthtoolMsgLevel, bool)>,
+}
+
+impl EthtoolDebugSetRequest {
+    pub(crate) fn new(handle: EthtoolHandle, iface_name: &str) -> Self {
+        EthtoolDebugSetRequest {
+            handle,
+            message: EthtoolMessage::new_debug_set(iface_name),
+            levels: Vec::new(),
+        }
+    }
+
+    /// Enable or disable single message level, other levels are left
+    /// unchanged. Levels can be named with `"link".parse()`, see
+    /// [EthtoolMsgLevel]'s `FromStr`.
+    pub fn level(mut self, level: EthtoolMsgLevel, enabled: bool) -> Self {
+        self.levels.retain(|(l, _)| *l != level);
+        self.levels.push((level, enabled));
+        self
+    }
+
+    /// Message levels to enable, levels not listed will be disabled.
+    pub fn levels(mut self, levels: &[EthtoolMsgLevel]) -> Self {
+        self.levels = (0..MSG_LEVEL_COUNT)
+            .map(EthtoolMsgLevel::from)
+            .map(|level| (level, levels.contains(&level)))
+            .collect();
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), EthtoolError> {
+        let EthtoolDebugSetRequest {
+            mut handle,
+            mut message,
+            levels,
+        } = self;
+
+        if !levels.is_empty() {
+            message
+                .nlas
+                .push(EthtoolAttr::Debug(EthtoolDebugAttr::MsgMask(levels)));
+        }
+
+        let mut nl_msg =
+            NetlinkMessage::from(GenlMessage::from_payload(message));
+
+        nl_msg.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        let mut response = handle.request(nl_msg).await?;
+
+        while let Some(message) = response.next().await {
+            try_ethtool!(message);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,151 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32, parse_u32, parse_u8, DecodeError, DefaultNla, Emitable,
+    ErrorContext, Nla, NlaBuffer, NlasIterator, Parseable, NLA_F_NESTED,
+};
+
+use crate::{
+    bitset_util::{
+        bitset_nlas_len, emit_bitset_nlas, parse_bitset_set_bits_nlas,
+        EthtoolBit, EthtoolBitSet,
+    },
+    EthtoolAttr, EthtoolHeader,
+};
+
+const ETHTOOL_A_EEE_HEADER: u16 = 1;
+const ETHTOOL_A_EEE_MODES_OURS: u16 = 2;
+const ETHTOOL_A_EEE_MODES_PEER: u16 = 3;
+const ETHTOOL_A_EEE_ACTIVE: u16 = 4;
+const ETHTOOL_A_EEE_ENABLED: u16 = 5;
+const ETHTOOL_A_EEE_TX_LPI_ENABLED: u16 = 6;
+const ETHTOOL_A_EEE_TX_LPI_TIMER: u16 = 7;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum EthtoolEeeAttr {
+    Header(Vec<EthtoolHeader>),
+    /// Link modes advertised with EEE, indexed by `LinkModes` string set
+    ModesOurs(Vec<EthtoolBit>),
+    /// Link modes advertised with EEE by link partner, indexed by
+    /// `LinkModes` string set
+    ModesPeer(Vec<EthtoolBit>),
+    Active(bool),
+    Enabled(bool),
+    TxLpiEnabled(bool),
+    /// Time in microseconds before entering Low Power Idle
+    TxLpiTimer(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for EthtoolEeeAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Header(hdrs) => hdrs.as_slice().buffer_len(),
+            Self::ModesOurs(modes) | Self::ModesPeer(modes) => {
+                bitset_nlas_len(&eee_modes_to_bitset(modes), true)
+            }
+            Self::Active(_) | Self::Enabled(_) | Self::TxLpiEnabled(_) => 1,
+            Self::TxLpiTimer(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Header(_) => ETHTOOL_A_EEE_HEADER | NLA_F_NESTED,
+            Self::ModesOurs(_) => ETHTOOL_A_EEE_MODES_OURS | NLA_F_NESTED,
+            Self::ModesPeer(_) => ETHTOOL_A_EEE_MODES_PEER | NLA_F_NESTED,
+            Self::Active(_) => ETHTOOL_A_EEE_ACTIVE,
+            Self::Enabled(_) => ETHTOOL_A_EEE_ENABLED,
+            Self::TxLpiEnabled(_) => ETHTOOL_A_EEE_TX_LPI_ENABLED,
+            Self::TxLpiTimer(_) => ETHTOOL_A_EEE_TX_LPI_TIMER,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Header(ref nlas) => nlas.as_slice().emit(buffer),
+            Self::ModesOurs(modes) | Self::ModesPeer(modes) => {
+                emit_bitset_nlas(&eee_modes_to_bitset(modes), true, buffer)
+            }
+            Self::Active(d) | Self::Enabled(d) | Self::TxLpiEnabled(d) => {
+                buffer[0] = *d as u8
+            }
+            Self::TxLpiTimer(d) => emit_u32(buffer, *d).unwrap(),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+// The EEE modes are sent as a list, so every mode not included is disabled.
+fn eee_modes_to_bitset(modes: &[EthtoolBit]) -> Vec<EthtoolBitSet> {
+    modes
+        .iter()
+        .map(|mode| EthtoolBitSet::new(mode, true))
+        .collect()
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for EthtoolEeeAttr
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            ETHTOOL_A_EEE_HEADER => {
+                let mut nlas = Vec::new();
+                let error_msg = "failed to parse eee header attributes";
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    let parsed =
+                        EthtoolHeader::parse(nla).context(error_msg)?;
+                    nlas.push(parsed);
+                }
+                Self::Header(nlas)
+            }
+            ETHTOOL_A_EEE_MODES_OURS => {
+                Self::ModesOurs(parse_bitset_set_bits_nlas(payload)?)
+            }
+            ETHTOOL_A_EEE_MODES_PEER => {
+                Self::ModesPeer(parse_bitset_set_bits_nlas(payload)?)
+            }
+            ETHTOOL_A_EEE_ACTIVE => Self::Active(
+                parse_u8(payload)
+                    .context("Invalid ETHTOOL_A_EEE_ACTIVE value")?
+                    > 0,
+            ),
+            ETHTOOL_A_EEE_ENABLED => Self::Enabled(
+                parse_u8(payload)
+                    .context("Invalid ETHTOOL_A_EEE_ENABLED value")?
+                    > 0,
+            ),
+            ETHTOOL_A_EEE_TX_LPI_ENABLED => Self::TxLpiEnabled(
+                parse_u8(payload)
+                    .context("Invalid ETHTOOL_A_EEE_TX_LPI_ENABLED value")?
+                    > 0,
+            ),
+            ETHTOOL_A_EEE_TX_LPI_TIMER => Self::TxLpiTimer(
+                parse_u32(payload)
+                    .context("Invalid ETHTOOL_A_EEE_TX_LPI_TIMER value")?,
+            ),
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("invalid ethtool eee NLA kind {kind}"))?,
+            ),
+        })
+    }
+}
+
+pub(crate) fn parse_eee_nlas(
+    buffer: &[u8],
+) -> Result<Vec<EthtoolAttr>, DecodeError> {
+    let mut nlas = Vec::new();
+    for nla in NlasIterator::new(buffer) {
+        let error_msg =
+            format!("Failed to parse ethtool eee message attribute {nla:?}");
+        let nla = &nla.context(error_msg.clone())?;
+        let parsed = EthtoolEeeAttr::parse(nla).context(error_msg)?;
+        nlas.push(EthtoolAttr::Eee(parsed));
+    }
+    Ok(nlas)
+}
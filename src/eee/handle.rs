@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{EthtoolEeeGetRequest, EthtoolEeeSetRequest, EthtoolHandle};
+
+pub struct EthtoolEeeHandle(EthtoolHandle);
+
+impl EthtoolEeeHandle {
+    pub fn new(handle: EthtoolHandle) -> Self {
+        EthtoolEeeHandle(handle)
+    }
+
+    /// Retrieve the Energy Efficient Ethernet settings of a interface (used
+    /// by `ethtool --show-eee eth1`)
+    pub fn get(&mut self, iface_name: Option<&str>) -> EthtoolEeeGetRequest {
+        EthtoolEeeGetRequest::new(self.0.clone(), iface_name)
+    }
+
+    /// Set the Energy Efficient Ethernet settings of a interface (used by
+    /// `ethtool --set-eee eth1 eee off`)
+    pub fn set(&mut self, iface_name: &str) -> EthtoolEeeSetRequest {
+        EthtoolEeeSetRequest::new(self.0.clone(), iface_name)
+    }
+}
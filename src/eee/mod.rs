@@ -0,0 +1,13 @@
+// SPDX-License-Identifier: MIT
+
+mod attr;
+mod get;
+mod handle;
+mod set;
+
+pub(crate) use attr::parse_eee_nlas;
+
+pub use attr::EthtoolEeeAttr;
+pub use get::EthtoolEeeGetRequest;
+pub use handle::EthtoolEeeHandle;
+pub use set::EthtoolEeeSetRequest;
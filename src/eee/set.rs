@@ -0,0 +1,101 @@
+// SPDX-License-Identifier: MIT
+
+use futures_util::StreamExt;
+use netlink_packet_core::{NetlinkMessage, NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    try_ethtool, EthtoolAttr, EthtoolBit, EthtoolEeeAttr, EthtoolError,
+    EthtoolHandle, EthtoolMessage,
+};
+
+pub struct EthtoolEeeSetRequest {
+    handle: EthtoolHandle,
+    message: EthtoolMessage,
+    enabled: Option<bool>,
+    tx_lpi_enabled: Option<bool>,
+    tx_lpi_timer: Option<u32>,
+    advertise: Option<Vec<EthtoolBit>>,
+}
+
+impl EthtoolEeeSetRequest {
+    pub(crate) fn new(handle: EthtoolHandle, iface_name: &str) -> Self {
+        EthtoolEeeSetRequest {
+            handle,
+            message: EthtoolMessage::new_eee_set(iface_name),
+            enabled: None,
+            tx_lpi_enabled: None,
+            tx_lpi_timer: None,
+            advertise: None,
+        }
+    }
+
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = Some(enabled);
+        self
+    }
+
+    pub fn tx_lpi_enabled(mut self, enabled: bool) -> Self {
+        self.tx_lpi_enabled = Some(enabled);
+        self
+    }
+
+    /// Time in microseconds before entering Low Power Idle
+    pub fn tx_lpi_timer(mut self, timer: u32) -> Self {
+        self.tx_lpi_timer = Some(timer);
+        self
+    }
+
+    /// Link modes to advertise with EEE, using kernel link mode names like
+    /// `1000baseT/Full`. Link modes not listed will not be advertised.
+    pub fn advertise(mut self, modes: &[&str]) -> Self {
+        self.advertise =
+            Some(modes.iter().map(|m| EthtoolBit::from(*m)).collect());
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), EthtoolError> {
+        let EthtoolEeeSetRequest {
+            mut handle,
+            mut message,
+            enabled,
+            tx_lpi_enabled,
+            tx_lpi_timer,
+            advertise,
+        } = self;
+
+        if let Some(modes) = advertise {
+            message
+                .nlas
+                .push(EthtoolAttr::Eee(EthtoolEeeAttr::ModesOurs(modes)));
+        }
+        if let Some(enabled) = enabled {
+            message
+                .nlas
+                .push(EthtoolAttr::Eee(EthtoolEeeAttr::Enabled(enabled)));
+        }
+        if let Some(enabled) = tx_lpi_enabled {
+            message
+                .nlas
+                .push(EthtoolAttr::Eee(EthtoolEeeAttr::TxLpiEnabled(enabled)));
+        }
+        if let Some(timer) = tx_lpi_timer {
+            message
+                .nlas
+                .push(EthtoolAttr::Eee(EthtoolEeeAttr::TxLpiTimer(timer)));
+        }
+
+        let mut nl_msg =
+            NetlinkMessage::from(GenlMessage::from_payload(message));
+
+        nl_msg.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        let mut response = handle.request(nl_msg).await?;
+
+        while let Some(message) = response.next().await {
+            try_ethtool!(message);
+        }
+
+        Ok(())
+    }
+}
@@ -1,12 +1,17 @@
 // SPDX-License-Identifier: MIT
 
-use log::warn;
 use netlink_packet_core::{
-    parse_string, parse_u32, DecodeError, DefaultNla, Emitable, ErrorContext,
-    Nla, NlaBuffer, NlasIterator, Parseable, NLA_F_NESTED,
+    DecodeError, DefaultNla, Emitable, ErrorContext, Nla, NlaBuffer,
+    NlasIterator, Parseable, NLA_F_NESTED,
 };
 
-use crate::{EthtoolAttr, EthtoolHeader};
+use crate::{
+    bitset_util::{
+        bitset_nlas_len, emit_bitset_nlas, parse_bitset_bits_nlas,
+        EthtoolBitSet,
+    },
+    EthtoolAttr, EthtoolHeader,
+};
 
 const ETHTOOL_A_FEATURES_HEADER: u16 = 1;
 const ETHTOOL_A_FEATURES_HW: u16 = 2; // User changable features
@@ -14,14 +19,6 @@ const ETHTOOL_A_FEATURES_WANTED: u16 = 3; // User requested fatures
 const ETHTOOL_A_FEATURES_ACTIVE: u16 = 4; // Active features
 const ETHTOOL_A_FEATURES_NOCHANGE: u16 = 5;
 
-const ETHTOOL_A_BITSET_BITS: u16 = 3;
-
-const ETHTOOL_A_BITSET_BITS_BIT: u16 = 1;
-
-const ETHTOOL_A_BITSET_BIT_INDEX: u16 = 1;
-const ETHTOOL_A_BITSET_BIT_NAME: u16 = 2;
-const ETHTOOL_A_BITSET_BIT_VALUE: u16 = 3;
-
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct EthtoolFeatureBit {
     pub index: u32,
@@ -29,22 +26,39 @@ pub struct EthtoolFeatureBit {
     pub value: bool,
 }
 
-impl EthtoolFeatureBit {
-    fn new(has_mask: bool) -> Self {
+impl From<&EthtoolFeatureBit> for EthtoolBitSet {
+    fn from(bit: &EthtoolFeatureBit) -> Self {
+        Self {
+            index: bit.index,
+            name: bit.name.clone(),
+            value: bit.value,
+        }
+    }
+}
+
+impl From<EthtoolBitSet> for EthtoolFeatureBit {
+    fn from(bit: EthtoolBitSet) -> Self {
         Self {
-            index: 0,
-            name: "".into(),
-            value: !has_mask,
+            index: bit.index,
+            name: bit.name,
+            value: bit.value,
         }
     }
 }
 
-fn feature_bits_len(_feature_bits: &[EthtoolFeatureBit]) -> usize {
-    todo!("Does not support changing ethtool feature yet")
+// Feature bits are emitted with mask, so only listed features are changed.
+fn feature_bits_to_bitset(
+    feature_bits: &[EthtoolFeatureBit],
+) -> Vec<EthtoolBitSet> {
+    feature_bits.iter().map(EthtoolBitSet::from).collect()
 }
 
-fn feature_bits_emit(_feature_bits: &[EthtoolFeatureBit], _buffer: &mut [u8]) {
-    todo!("Does not support changing ethtool feature yet")
+fn feature_bits_len(feature_bits: &[EthtoolFeatureBit]) -> usize {
+    bitset_nlas_len(&feature_bits_to_bitset(feature_bits), false)
+}
+
+fn feature_bits_emit(feature_bits: &[EthtoolFeatureBit], buffer: &mut [u8]) {
+    emit_bitset_nlas(&feature_bits_to_bitset(feature_bits), false, buffer)
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -114,28 +128,16 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
                 Self::Header(nlas)
             }
             ETHTOOL_A_FEATURES_HW => {
-                Self::Hw(parse_bitset_bits_string_nlas(
-                    payload,
-                    true, /* ETHTOOL_A_FEATURES_HW is using mask */
-                )?)
+                Self::Hw(parse_feature_bits_nlas(payload)?)
             }
             ETHTOOL_A_FEATURES_WANTED => {
-                Self::Wanted(parse_bitset_bits_string_nlas(
-                    payload,
-                    false, /* ETHTOOL_A_FEATURES_WANTED does not use mask */
-                )?)
+                Self::Wanted(parse_feature_bits_nlas(payload)?)
             }
             ETHTOOL_A_FEATURES_ACTIVE => {
-                Self::Active(parse_bitset_bits_string_nlas(
-                    payload,
-                    false, /* ETHTOOL_A_FEATURES_ACTIVE does not use mask */
-                )?)
+                Self::Active(parse_feature_bits_nlas(payload)?)
             }
             ETHTOOL_A_FEATURES_NOCHANGE => {
-                Self::NoChange(parse_bitset_bits_string_nlas(
-                    payload,
-                    false, /* ETHTOOL_A_FEATURES_NOCHANGE does not use mask */
-                )?)
+                Self::NoChange(parse_feature_bits_nlas(payload)?)
             }
             _ => Self::Other(
                 DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
@@ -144,80 +146,13 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
     }
 }
 
-fn parse_bitset_bits_string_nlas(
+fn parse_feature_bits_nlas(
     raw: &[u8],
-    has_mask: bool,
 ) -> Result<Vec<EthtoolFeatureBit>, DecodeError> {
-    let error_msg = "failed to parse feature bit sets";
-    for nla in NlasIterator::new(raw) {
-        let nla = &nla.context(error_msg)?;
-        if nla.kind() == ETHTOOL_A_BITSET_BITS {
-            return parse_bitset_bits_nla(nla.value(), has_mask);
-        }
-    }
-    Err("No ETHTOOL_A_BITSET_BITS NLA found".into())
-}
-
-fn parse_bitset_bits_nla(
-    raw: &[u8],
-    has_mask: bool,
-) -> Result<Vec<EthtoolFeatureBit>, DecodeError> {
-    let mut feature_bits = Vec::new();
-    let error_msg = "Failed to parse ETHTOOL_A_BITSET_BITS attributes";
-    for bit_nla in NlasIterator::new(raw) {
-        let bit_nla = &bit_nla.context(error_msg)?;
-        match bit_nla.kind() {
-            ETHTOOL_A_BITSET_BITS_BIT => {
-                let error_msg =
-                    "Failed to parse ETHTOOL_A_BITSET_BITS_BIT attributes";
-                let nlas = NlasIterator::new(bit_nla.value());
-                let mut cur_bit_info = EthtoolFeatureBit::new(has_mask);
-                for nla in nlas {
-                    let nla = &nla.context(error_msg)?;
-                    let payload = nla.value();
-                    match nla.kind() {
-                        ETHTOOL_A_BITSET_BIT_INDEX => {
-                            if cur_bit_info.index != 0
-                                && !&cur_bit_info.name.is_empty()
-                            {
-                                feature_bits.push(cur_bit_info);
-                                cur_bit_info = EthtoolFeatureBit::new(has_mask);
-                            }
-                            cur_bit_info.index = parse_u32(payload).context(
-                                "Invald ETHTOOL_A_BITSET_BIT_INDEX value",
-                            )?;
-                        }
-                        ETHTOOL_A_BITSET_BIT_NAME => {
-                            cur_bit_info.name = parse_string(payload).context(
-                                "Invald ETHTOOL_A_BITSET_BIT_NAME value",
-                            )?;
-                        }
-                        ETHTOOL_A_BITSET_BIT_VALUE => {
-                            cur_bit_info.value = true;
-                        }
-                        _ => {
-                            warn!(
-                                "Unknown ETHTOOL_A_BITSET_BITS_BIT {} {:?}",
-                                nla.kind(),
-                                nla.value(),
-                            );
-                        }
-                    }
-                }
-                if cur_bit_info.index != 0 && !&cur_bit_info.name.is_empty() {
-                    feature_bits.push(cur_bit_info);
-                }
-            }
-            _ => {
-                warn!(
-                    "Unknown ETHTOOL_A_BITSET_BITS kind {}, {:?}",
-                    bit_nla.kind(),
-                    bit_nla.value()
-                );
-            }
-        };
-    }
-    Ok(feature_bits)
+    Ok(parse_bitset_bits_nlas(raw)?
+        .into_iter()
+        .map(EthtoolFeatureBit::from)
+        .collect())
 }
 
 pub(crate) fn parse_feature_nlas(
@@ -1,6 +1,8 @@
 // SPDX-License-Identifier: MIT
 
-use crate::{EthtoolFeatureGetRequest, EthtoolHandle};
+use crate::{
+    EthtoolFeatureGetRequest, EthtoolFeatureSetRequest, EthtoolHandle,
+};
 
 pub struct EthtoolFeatureHandle(EthtoolHandle);
 
@@ -17,4 +19,10 @@ impl EthtoolFeatureHandle {
     ) -> EthtoolFeatureGetRequest {
         EthtoolFeatureGetRequest::new(self.0.clone(), iface_name)
     }
+
+    /// Change the ethtool features of a interface (equivalent to `ethtool -K
+    /// eth1`)
+    pub fn set(&mut self, iface_name: &str) -> EthtoolFeatureSetRequest {
+        EthtoolFeatureSetRequest::new(self.0.clone(), iface_name)
+    }
 }
@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: MIT
+
+use futures_util::StreamExt;
+use netlink_packet_core::{NetlinkMessage, NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    try_ethtool, EthtoolAttr, EthtoolCmd, EthtoolError, EthtoolFeatureAttr,
+    EthtoolFeatureBit, EthtoolHandle, EthtoolMessage,
+};
+
+pub struct EthtoolFeatureSetRequest {
+    handle: EthtoolHandle,
+    message: EthtoolMessage,
+    features: Vec<EthtoolFeatureBit>,
+}
+
+impl EthtoolFeatureSetRequest {
+    pub(crate) fn new(handle: EthtoolHandle, iface_name: &str) -> Self {
+        EthtoolFeatureSetRequest {
+            handle,
+            message: EthtoolMessage::new_feature_set(iface_name),
+            features: Vec::new(),
+        }
+    }
+
+    /// Request feature to be enabled or disabled, using the kernel feature
+    /// name like `rx-checksum` or `tx-tcp-segmentation`. Features not
+    /// requested are left unchanged.
+    pub fn feature(mut self, name: &str, enabled: bool) -> Self {
+        self.features.push(EthtoolFeatureBit {
+            index: 0,
+            name: name.to_string(),
+            value: enabled,
+        });
+        self
+    }
+
+    /// Return the requested features which kernel failed to apply, with
+    /// `value` holding the requested state.
+    pub async fn execute(self) -> Result<Vec<EthtoolFeatureBit>, EthtoolError> {
+        let EthtoolFeatureSetRequest {
+            mut handle,
+            mut message,
+            features,
+        } = self;
+
+        message
+            .nlas
+            .push(EthtoolAttr::Feature(EthtoolFeatureAttr::Wanted(features)));
+
+        let mut nl_msg =
+            NetlinkMessage::from(GenlMessage::from_payload(message));
+
+        nl_msg.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        let mut response = handle.request(nl_msg).await?;
+
+        let mut not_applied = Vec::new();
+        while let Some(message) = response.next().await {
+            let reply = try_ethtool!(message).payload;
+            if reply.cmd != EthtoolCmd::FeatureSetReply {
+                continue;
+            }
+            for nla in reply.nlas {
+                if let EthtoolAttr::Feature(EthtoolFeatureAttr::Wanted(bits)) =
+                    nla
+                {
+                    not_applied.extend(bits);
+                }
+            }
+        }
+
+        Ok(not_applied)
+    }
+}
@@ -1,13 +1,16 @@
 // SPDX-License-Identifier: MIT
 
 use netlink_packet_core::{
-    emit_u64, parse_u32, parse_u64, parse_u8, DecodeError, DefaultNla,
-    Emitable, ErrorContext, Nla, NlaBuffer, NlasIterator, Parseable,
-    NLA_F_NESTED,
+    emit_u32, emit_u64, parse_u32, parse_u64, parse_u8, DecodeError,
+    DefaultNla, Emitable, ErrorContext, Nla, NlaBuffer, NlasIterator,
+    Parseable, NLA_F_NESTED,
 };
 
 use crate::{
-    bitset_util::{parse_bitset_bits_nlas, EthtoolBitSet},
+    bitset_util::{
+        bitset_nlas_len, emit_bitset_nlas, parse_bitset_bits_nlas,
+        EthtoolBitSet,
+    },
     EthtoolAttr, EthtoolHeader,
 };
 
@@ -42,7 +45,9 @@ impl Nla for EthtoolFecAttr {
     fn value_len(&self) -> usize {
         match self {
             Self::Header(hdrs) => hdrs.as_slice().buffer_len(),
-            Self::Modes(_) => todo!("Does not support emitting EthtoolFecAttr"),
+            Self::Modes(modes) => {
+                bitset_nlas_len(&fec_modes_to_bitset(modes), true)
+            }
             Self::Auto(_) => 1,
             Self::Active(_) => 4,
             Self::Stats(s) => s.as_slice().buffer_len(),
@@ -53,10 +58,10 @@ impl Nla for EthtoolFecAttr {
     fn kind(&self) -> u16 {
         match self {
             Self::Header(_) => ETHTOOL_A_FEC_HEADER | NLA_F_NESTED,
-            Self::Modes(_) => ETHTOOL_A_FEC_MODES,
+            Self::Modes(_) => ETHTOOL_A_FEC_MODES | NLA_F_NESTED,
             Self::Auto(_) => ETHTOOL_A_FEC_AUTO,
             Self::Active(_) => ETHTOOL_A_FEC_ACTIVE,
-            Self::Stats(_) => ETHTOOL_A_FEC_STATS,
+            Self::Stats(_) => ETHTOOL_A_FEC_STATS | NLA_F_NESTED,
             Self::Other(attr) => attr.kind(),
         }
     }
@@ -64,12 +69,32 @@ impl Nla for EthtoolFecAttr {
     fn emit_value(&self, buffer: &mut [u8]) {
         match self {
             Self::Header(ref nlas) => nlas.as_slice().emit(buffer),
+            Self::Modes(modes) => {
+                emit_bitset_nlas(&fec_modes_to_bitset(modes), true, buffer)
+            }
+            Self::Auto(d) => buffer[0] = *d as u8,
+            Self::Active(mode) => {
+                emit_u32(buffer, u32::from(mode.clone())).unwrap()
+            }
+            Self::Stats(ref nlas) => nlas.as_slice().emit(buffer),
             Self::Other(ref attr) => attr.emit(buffer),
-            _ => todo!("Does not support changing ethtool fec yet"),
         }
     }
 }
 
+// The FEC modes are sent as a list of link mode bit indices, so every mode
+// not included is disabled.
+fn fec_modes_to_bitset(modes: &[EthtoolFecMode]) -> Vec<EthtoolBitSet> {
+    modes
+        .iter()
+        .map(|mode| EthtoolBitSet {
+            index: mode.clone().into(),
+            name: String::new(),
+            value: true,
+        })
+        .collect()
+}
+
 impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
     for EthtoolFecAttr
 {
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: MIT
 
-use crate::{EthtoolFecGetRequest, EthtoolHandle};
+use crate::{EthtoolFecGetRequest, EthtoolFecSetRequest, EthtoolHandle};
 
 pub struct EthtoolFecHandle(EthtoolHandle);
 
@@ -9,8 +9,15 @@ impl EthtoolFecHandle {
         EthtoolFecHandle(handle)
     }
 
-    /// Retrieve the ethtool timestamping capabilities of an interface
+    /// Retrieve the FEC settings of an interface (equivalent to `ethtool
+    /// --show-fec eth1`)
     pub fn get(&mut self, iface_name: Option<&str>) -> EthtoolFecGetRequest {
         EthtoolFecGetRequest::new(self.0.clone(), iface_name)
     }
+
+    /// Set the FEC settings of an interface (equivalent to `ethtool
+    /// --set-fec eth1`)
+    pub fn set(&mut self, iface_name: &str) -> EthtoolFecSetRequest {
+        EthtoolFecSetRequest::new(self.0.clone(), iface_name)
+    }
 }
@@ -3,8 +3,10 @@
 mod attr;
 mod get;
 mod handle;
+mod set;
 
 pub(crate) use self::attr::parse_fec_nlas;
 pub use self::attr::{EthtoolFecAttr, EthtoolFecMode, EthtoolFecStat};
 pub use self::get::EthtoolFecGetRequest;
 pub use self::handle::EthtoolFecHandle;
+pub use self::set::EthtoolFecSetRequest;
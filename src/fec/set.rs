@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: MIT
+
+use futures_util::StreamExt;
+use netlink_packet_core::{NetlinkMessage, NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    try_ethtool, EthtoolAttr, EthtoolError, EthtoolFecAttr, EthtoolFecMode,
+    EthtoolHandle, EthtoolMessage,
+};
+
+pub struct EthtoolFecSetRequest {
+    handle: EthtoolHandle,
+    message: EthtoolMessage,
+    modes: Option<Vec<EthtoolFecMode>>,
+    auto: Option<bool>,
+}
+
+impl EthtoolFecSetRequest {
+    pub(crate) fn new(handle: EthtoolHandle, iface_name: &str) -> Self {
+        EthtoolFecSetRequest {
+            handle,
+            message: EthtoolMessage::new_fec_set(iface_name),
+            modes: None,
+            auto: None,
+        }
+    }
+
+    /// FEC modes to configure, modes not listed will be disabled.
+    pub fn modes(mut self, modes: &[EthtoolFecMode]) -> Self {
+        self.modes = Some(modes.to_vec());
+        self
+    }
+
+    /// Request the driver to choose FEC mode based on SFP module parameters.
+    pub fn auto(mut self, enabled: bool) -> Self {
+        self.auto = Some(enabled);
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), EthtoolError> {
+        let EthtoolFecSetRequest {
+            mut handle,
+            mut message,
+            modes,
+            auto,
+        } = self;
+
+        if let Some(modes) = modes {
+            message
+                .nlas
+                .push(EthtoolAttr::Fec(EthtoolFecAttr::Modes(modes)));
+        }
+        if let Some(enabled) = auto {
+            message
+                .nlas
+                .push(EthtoolAttr::Fec(EthtoolFecAttr::Auto(enabled)));
+        }
+
+        let mut nl_msg =
+            NetlinkMessage::from(GenlMessage::from_payload(message));
+
+        nl_msg.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        let mut response = handle.request(nl_msg).await?;
+
+        while let Some(message) = response.next().await {
+            try_ethtool!(message);
+        }
+
+        Ok(())
+    }
+}
@@ -1,28 +1,40 @@
 // SPDX-License-Identifier: MIT
 
-use futures_util::{Stream, StreamExt};
-use genetlink::GenetlinkHandle;
+use futures_channel::mpsc::UnboundedReceiver;
+use futures_util::{future, Stream, StreamExt};
+use genetlink::{message::RawGenlMessage, GenetlinkHandle};
 use netlink_packet_core::DecodeError;
 use netlink_packet_core::{
-    NetlinkMessage, NLM_F_ACK, NLM_F_DUMP, NLM_F_REQUEST,
+    NetlinkMessage, NetlinkPayload, NLM_F_ACK, NLM_F_DUMP, NLM_F_REQUEST,
 };
 use netlink_packet_generic::GenlMessage;
+use netlink_sys::SocketAddr;
 
 use crate::{
-    try_ethtool, EthtoolChannelHandle, EthtoolCoalesceHandle, EthtoolError,
-    EthtoolFeatureHandle, EthtoolFecHandle, EthtoolLinkModeHandle,
-    EthtoolMessage, EthtoolModuleEEPROMHandle, EthtoolPauseHandle,
-    EthtoolRingHandle, EthtoolTsInfoHandle,
+    try_ethtool, EthtoolCableTestHandle, EthtoolChannelHandle, EthtoolCmd,
+    EthtoolCoalesceHandle, EthtoolDebugHandle, EthtoolEeeHandle, EthtoolError,
+    EthtoolFeatureHandle, EthtoolFecHandle, EthtoolLinkInfoHandle,
+    EthtoolLinkModeHandle, EthtoolLinkStateHandle, EthtoolMessage,
+    EthtoolMmHandle, EthtoolModuleEEPROMHandle, EthtoolModuleHandle,
+    EthtoolPauseHandle, EthtoolPhcVclocksHandle, EthtoolPlcaHandle,
+    EthtoolPrivFlagsHandle, EthtoolPseHandle, EthtoolRingHandle,
+    EthtoolRssHandle, EthtoolStatsHandle, EthtoolStringSetCache,
+    EthtoolStringSetHandle, EthtoolTsInfoHandle, EthtoolTunnelInfoHandle,
+    EthtoolWolHandle,
 };
 
 #[derive(Clone, Debug)]
 pub struct EthtoolHandle {
     pub handle: GenetlinkHandle,
+    pub(crate) strset_cache: Option<EthtoolStringSetCache>,
 }
 
 impl EthtoolHandle {
     pub(crate) fn new(handle: GenetlinkHandle) -> Self {
-        EthtoolHandle { handle }
+        EthtoolHandle {
+            handle,
+            strset_cache: None,
+        }
     }
 
     pub fn pause(&mut self) -> EthtoolPauseHandle {
@@ -37,10 +49,22 @@ impl EthtoolHandle {
         EthtoolLinkModeHandle::new(self.clone())
     }
 
+    pub fn link_info(&mut self) -> EthtoolLinkInfoHandle {
+        EthtoolLinkInfoHandle::new(self.clone())
+    }
+
+    pub fn link_state(&mut self) -> EthtoolLinkStateHandle {
+        EthtoolLinkStateHandle::new(self.clone())
+    }
+
     pub fn ring(&mut self) -> EthtoolRingHandle {
         EthtoolRingHandle::new(self.clone())
     }
 
+    pub fn rss(&mut self) -> EthtoolRssHandle {
+        EthtoolRssHandle::new(self.clone())
+    }
+
     pub fn coalesce(&mut self) -> EthtoolCoalesceHandle {
         EthtoolCoalesceHandle::new(self.clone())
     }
@@ -49,6 +73,14 @@ impl EthtoolHandle {
         EthtoolTsInfoHandle::new(self.clone())
     }
 
+    pub fn phc_vclocks(&mut self) -> EthtoolPhcVclocksHandle {
+        EthtoolPhcVclocksHandle::new(self.clone())
+    }
+
+    pub fn tunnel_info(&mut self) -> EthtoolTunnelInfoHandle {
+        EthtoolTunnelInfoHandle::new(self.clone())
+    }
+
     pub fn fec(&mut self) -> EthtoolFecHandle {
         EthtoolFecHandle::new(self.clone())
     }
@@ -57,6 +89,66 @@ impl EthtoolHandle {
         EthtoolChannelHandle::new(self.clone())
     }
 
+    pub fn wol(&mut self) -> EthtoolWolHandle {
+        EthtoolWolHandle::new(self.clone())
+    }
+
+    pub fn eee(&mut self) -> EthtoolEeeHandle {
+        EthtoolEeeHandle::new(self.clone())
+    }
+
+    pub fn debug(&mut self) -> EthtoolDebugHandle {
+        EthtoolDebugHandle::new(self.clone())
+    }
+
+    pub fn stats(&mut self) -> EthtoolStatsHandle {
+        EthtoolStatsHandle::new(self.clone())
+    }
+
+    pub fn privflags(&mut self) -> EthtoolPrivFlagsHandle {
+        EthtoolPrivFlagsHandle::new(self.clone())
+    }
+
+    pub fn strset(&mut self) -> EthtoolStringSetHandle {
+        EthtoolStringSetHandle::new(self.clone())
+    }
+
+    /// Cache the string sets retrieved by `EthtoolStringSetHandle::strings()`
+    /// and `EthtoolStringSetHandle::resolve_names()` in this handle, shared
+    /// with clones created afterwards.
+    pub fn enable_strset_cache(&mut self) {
+        if self.strset_cache.is_none() {
+            self.strset_cache = Some(EthtoolStringSetCache::default());
+        }
+    }
+
+    /// Drop the cached string sets, for example after driver reload.
+    pub fn clear_strset_cache(&mut self) {
+        if let Some(cache) = self.strset_cache.as_ref() {
+            cache.clear();
+        }
+    }
+
+    pub fn cable_test(&mut self) -> EthtoolCableTestHandle {
+        EthtoolCableTestHandle::new(self.clone())
+    }
+
+    pub fn module(&mut self) -> EthtoolModuleHandle {
+        EthtoolModuleHandle::new(self.clone())
+    }
+
+    pub fn pse(&mut self) -> EthtoolPseHandle {
+        EthtoolPseHandle::new(self.clone())
+    }
+
+    pub fn plca(&mut self) -> EthtoolPlcaHandle {
+        EthtoolPlcaHandle::new(self.clone())
+    }
+
+    pub fn mm(&mut self) -> EthtoolMmHandle {
+        EthtoolMmHandle::new(self.clone())
+    }
+
     pub fn eeprom(&mut self) -> EthtoolModuleEEPROMHandle {
         EthtoolModuleEEPROMHandle::new(self.clone())
     }
@@ -109,3 +201,71 @@ pub(crate) async fn ethtool_execute(
         .await?
         .map(move |msg| Ok(try_ethtool!(msg))))
 }
+
+/// Send the action request and return the `ntf_cmd` notifications matching
+/// `is_wanted`, ending with the one `is_last` returns true for.
+/// Messages queued in `messages` before the request are discarded, as they
+/// are not the outcome of it, so are notifications not wanted. Hence
+/// `messages` should be dedicated to this action.
+pub(crate) async fn ethtool_act_ntf<'a, W, L>(
+    handle: &mut EthtoolHandle,
+    ethtool_msg: EthtoolMessage,
+    ntf_cmd: EthtoolCmd,
+    messages: &'a mut UnboundedReceiver<(
+        NetlinkMessage<RawGenlMessage>,
+        SocketAddr,
+    )>,
+    is_wanted: W,
+    is_last: L,
+) -> Result<
+    impl Stream<Item = Result<GenlMessage<EthtoolMessage>, EthtoolError>> + 'a,
+    EthtoolError,
+>
+where
+    W: Fn(&EthtoolMessage) -> bool + 'a,
+    L: Fn(&EthtoolMessage) -> bool + 'a,
+{
+    while messages.try_recv().is_ok() {}
+
+    let mut nl_msg =
+        NetlinkMessage::from(GenlMessage::from_payload(ethtool_msg));
+
+    nl_msg.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+    let mut response = handle.request(nl_msg).await?;
+
+    while let Some(message) = response.next().await {
+        try_ethtool!(message);
+    }
+
+    let ntf_cmd = u8::from(ntf_cmd);
+    Ok(messages
+        .filter_map(move |(nl_msg, _)| {
+            future::ready(match nl_msg.payload {
+                NetlinkPayload::InnerMessage(raw)
+                    if raw.header.cmd == ntf_cmd =>
+                {
+                    match raw.parse_into_genlmsg::<EthtoolMessage>() {
+                        Ok(genl_msg) if is_wanted(&genl_msg.payload) => {
+                            Some(Ok(genl_msg))
+                        }
+                        Ok(_) => None,
+                        Err(e) => Some(Err(EthtoolError::Bug(format!(
+                            "BUG: decode error {e:?}"
+                        )))),
+                    }
+                }
+                _ => None,
+            })
+        })
+        .scan(false, move |finished, msg| {
+            if *finished {
+                return future::ready(None);
+            }
+            *finished = match &msg {
+                Ok(genl_msg) => is_last(&genl_msg.payload),
+                Err(_) => true,
+            };
+            future::ready(Some(msg))
+        }))
+}
@@ -1,25 +1,51 @@
 // SPDX-License-Identifier: MIT
 
 mod bitset_util;
+mod cable_test;
 mod channel;
 mod coalesce;
 mod connection;
+mod debug;
+mod eee;
 mod eeprom;
 mod error;
 mod feature;
 mod fec;
 mod handle;
 mod header;
+mod link_info;
 mod link_mode;
+mod link_state;
 mod macros;
 mod message;
+mod mm;
+mod module;
+mod module_fw_flash;
+mod monitor;
 mod pause;
+mod phc_vclocks;
+mod plca;
+mod privflags;
+mod pse;
 mod ring;
+mod rss;
+mod stats;
+mod strset;
 mod tsinfo;
+mod tunnel_info;
+mod wol;
 
 pub use self::fec::{
     EthtoolFecAttr, EthtoolFecGetRequest, EthtoolFecHandle, EthtoolFecMode,
-    EthtoolFecStat,
+    EthtoolFecSetRequest, EthtoolFecStat,
+};
+pub use bitset_util::EthtoolBit;
+pub use cable_test::{
+    EthtoolCablePair, EthtoolCableResultCode, EthtoolCableTdrNest,
+    EthtoolCableTdrStep, EthtoolCableTestAttr, EthtoolCableTestHandle,
+    EthtoolCableTestNest, EthtoolCableTestRequest, EthtoolCableTestStatus,
+    EthtoolCableTestTdrAttr, EthtoolCableTestTdrCfg,
+    EthtoolCableTestTdrRequest,
 };
 pub use channel::{
     EthtoolChannelAttr, EthtoolChannelGetRequest, EthtoolChannelHandle,
@@ -27,10 +53,21 @@ pub use channel::{
 };
 pub use coalesce::{
     EthtoolCoalesceAttr, EthtoolCoalesceGetRequest, EthtoolCoalesceHandle,
+    EthtoolCoalesceSetRequest,
 };
 #[cfg(feature = "tokio_socket")]
-pub use connection::new_connection;
-pub use connection::new_connection_with_socket;
+pub use connection::{new_connection, new_monitor_connection};
+pub use connection::{
+    new_connection_with_socket, new_monitor_connection_with_socket,
+};
+pub use debug::{
+    EthtoolDebugAttr, EthtoolDebugGetRequest, EthtoolDebugHandle,
+    EthtoolDebugSetRequest, EthtoolMsgLevel,
+};
+pub use eee::{
+    EthtoolEeeAttr, EthtoolEeeGetRequest, EthtoolEeeHandle,
+    EthtoolEeeSetRequest,
+};
 pub use eeprom::{
     EthtoolModuleEEPROMAttr, EthtoolModuleEEPROMGetRequest,
     EthtoolModuleEEPROMHandle,
@@ -38,22 +75,88 @@ pub use eeprom::{
 pub use error::EthtoolError;
 pub use feature::{
     EthtoolFeatureAttr, EthtoolFeatureBit, EthtoolFeatureGetRequest,
-    EthtoolFeatureHandle,
+    EthtoolFeatureHandle, EthtoolFeatureSetRequest,
 };
 pub use handle::EthtoolHandle;
 pub use header::EthtoolHeader;
+pub use link_info::{
+    EthtoolLinkInfoAttr, EthtoolLinkInfoGetRequest, EthtoolLinkInfoHandle,
+    EthtoolLinkInfoMdix, EthtoolLinkInfoPort, EthtoolLinkInfoSetRequest,
+};
 pub use link_mode::{
     EthtoolLinkModeAttr, EthtoolLinkModeDuplex, EthtoolLinkModeGetRequest,
-    EthtoolLinkModeHandle,
+    EthtoolLinkModeHandle, EthtoolLinkModeSetRequest,
+};
+pub use link_state::{
+    EthtoolLinkExtState, EthtoolLinkExtSubstate, EthtoolLinkStateAttr,
+    EthtoolLinkStateGetRequest, EthtoolLinkStateHandle,
 };
 pub use message::{EthtoolAttr, EthtoolCmd, EthtoolMessage};
+pub use mm::{
+    EthtoolMmAttr, EthtoolMmGetRequest, EthtoolMmHandle, EthtoolMmSetRequest,
+    EthtoolMmStatAttr, EthtoolMmVerifyStatus,
+};
+pub use module::{
+    EthtoolModuleAttr, EthtoolModuleGetRequest, EthtoolModuleHandle,
+    EthtoolModulePowerMode, EthtoolModulePowerModePolicy,
+    EthtoolModuleSetRequest,
+};
+pub use module_fw_flash::{
+    EthtoolModuleFwFlashAttr, EthtoolModuleFwFlashRequest,
+    EthtoolModuleFwFlashStatus,
+};
+pub use monitor::ethtool_monitor;
 pub use pause::{
     EthtoolPauseAttr, EthtoolPauseGetRequest, EthtoolPauseHandle,
-    EthtoolPauseStatAttr,
+    EthtoolPauseSetRequest, EthtoolPauseStatAttr,
+};
+pub use phc_vclocks::{
+    EthtoolPhcVclocksAttr, EthtoolPhcVclocksGetRequest, EthtoolPhcVclocksHandle,
+};
+pub use plca::{
+    EthtoolPlcaAttr, EthtoolPlcaGetRequest, EthtoolPlcaHandle,
+    EthtoolPlcaSetRequest,
+};
+pub use privflags::{
+    EthtoolPrivFlagsAttr, EthtoolPrivFlagsGetRequest, EthtoolPrivFlagsHandle,
+    EthtoolPrivFlagsSetRequest,
+};
+pub use pse::{
+    EthtoolC33PsePwDStatus, EthtoolPodlPsePwDStatus, EthtoolPseAdminState,
+    EthtoolPseAttr, EthtoolPseGetRequest, EthtoolPseHandle,
+    EthtoolPsePwLimitRange, EthtoolPseSetRequest,
+};
+pub use ring::{
+    EthtoolRingAttr, EthtoolRingGetRequest, EthtoolRingHandle,
+    EthtoolRingSetRequest,
+};
+pub use rss::{
+    EthtoolRssAttr, EthtoolRssCreateRequest, EthtoolRssDeleteRequest,
+    EthtoolRssGetRequest, EthtoolRssHandle, EthtoolRssHashFunction,
+    EthtoolRssInputXfrm, EthtoolRssSetRequest,
+};
+pub use stats::{
+    EthtoolEthCtrlStats, EthtoolEthMacStats, EthtoolEthPhyStats,
+    EthtoolRmonStats, EthtoolStatsAttr, EthtoolStatsGetRequest,
+    EthtoolStatsGroup, EthtoolStatsGroupId, EthtoolStatsHandle,
+    EthtoolStatsHistBucket, EthtoolStatsSrc,
+};
+pub use strset::{
+    EthtoolStringSet, EthtoolStringSetAttr, EthtoolStringSetGetRequest,
+    EthtoolStringSetHandle, EthtoolStringSetId,
 };
-pub use ring::{EthtoolRingAttr, EthtoolRingGetRequest, EthtoolRingHandle};
 pub use tsinfo::{
     EthtoolTsInfoAttr, EthtoolTsInfoGetRequest, EthtoolTsInfoHandle,
 };
+pub use tunnel_info::{
+    EthtoolTunnelInfoAttr, EthtoolTunnelInfoGetRequest,
+    EthtoolTunnelInfoHandle, EthtoolUdpTunnelEntry, EthtoolUdpTunnelTable,
+    EthtoolUdpTunnelType,
+};
+pub use wol::{
+    EthtoolWolAttr, EthtoolWolGetRequest, EthtoolWolHandle, EthtoolWolMode,
+    EthtoolWolSetRequest,
+};
 
-pub(crate) use handle::ethtool_execute;
+pub(crate) use handle::{ethtool_act_ntf, ethtool_execute};
+pub(crate) use strset::EthtoolStringSetCache;
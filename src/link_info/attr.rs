@@ -0,0 +1,218 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    parse_u8, DecodeError, DefaultNla, Emitable, ErrorContext, Nla, NlaBuffer,
+    NlasIterator, Parseable, NLA_F_NESTED,
+};
+
+use crate::{EthtoolAttr, EthtoolHeader};
+
+const ETHTOOL_A_LINKINFO_HEADER: u16 = 1;
+const ETHTOOL_A_LINKINFO_PORT: u16 = 2;
+const ETHTOOL_A_LINKINFO_PHYADDR: u16 = 3;
+const ETHTOOL_A_LINKINFO_TP_MDIX: u16 = 4;
+const ETHTOOL_A_LINKINFO_TP_MDIX_CTRL: u16 = 5;
+const ETHTOOL_A_LINKINFO_TRANSCEIVER: u16 = 6;
+
+const PORT_TP: u8 = 0x00;
+const PORT_AUI: u8 = 0x01;
+const PORT_BNC: u8 = 0x02;
+const PORT_MII: u8 = 0x03;
+const PORT_FIBRE: u8 = 0x04;
+const PORT_DA: u8 = 0x05;
+const PORT_NONE: u8 = 0xef;
+
+const ETH_TP_MDI_INVALID: u8 = 0x00;
+const ETH_TP_MDI: u8 = 0x01;
+const ETH_TP_MDI_X: u8 = 0x02;
+const ETH_TP_MDI_AUTO: u8 = 0x03;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EthtoolLinkInfoPort {
+    /// Twisted pair
+    Tp,
+    Aui,
+    Bnc,
+    Mii,
+    Fibre,
+    /// Direct attach copper
+    Da,
+    None,
+    /// Kernel `PORT_OTHER`(0xff) or port type unknown to this crate
+    Other(u8),
+}
+
+impl From<u8> for EthtoolLinkInfoPort {
+    fn from(d: u8) -> Self {
+        match d {
+            PORT_TP => Self::Tp,
+            PORT_AUI => Self::Aui,
+            PORT_BNC => Self::Bnc,
+            PORT_MII => Self::Mii,
+            PORT_FIBRE => Self::Fibre,
+            PORT_DA => Self::Da,
+            PORT_NONE => Self::None,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<EthtoolLinkInfoPort> for u8 {
+    fn from(v: EthtoolLinkInfoPort) -> u8 {
+        match v {
+            EthtoolLinkInfoPort::Tp => PORT_TP,
+            EthtoolLinkInfoPort::Aui => PORT_AUI,
+            EthtoolLinkInfoPort::Bnc => PORT_BNC,
+            EthtoolLinkInfoPort::Mii => PORT_MII,
+            EthtoolLinkInfoPort::Fibre => PORT_FIBRE,
+            EthtoolLinkInfoPort::Da => PORT_DA,
+            EthtoolLinkInfoPort::None => PORT_NONE,
+            EthtoolLinkInfoPort::Other(d) => d,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EthtoolLinkInfoMdix {
+    /// Unknown or not supported
+    Invalid,
+    Mdi,
+    MdiX,
+    /// Automatic crossover, only valid for control
+    Auto,
+    Other(u8),
+}
+
+impl From<u8> for EthtoolLinkInfoMdix {
+    fn from(d: u8) -> Self {
+        match d {
+            ETH_TP_MDI_INVALID => Self::Invalid,
+            ETH_TP_MDI => Self::Mdi,
+            ETH_TP_MDI_X => Self::MdiX,
+            ETH_TP_MDI_AUTO => Self::Auto,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<EthtoolLinkInfoMdix> for u8 {
+    fn from(v: EthtoolLinkInfoMdix) -> u8 {
+        match v {
+            EthtoolLinkInfoMdix::Invalid => ETH_TP_MDI_INVALID,
+            EthtoolLinkInfoMdix::Mdi => ETH_TP_MDI,
+            EthtoolLinkInfoMdix::MdiX => ETH_TP_MDI_X,
+            EthtoolLinkInfoMdix::Auto => ETH_TP_MDI_AUTO,
+            EthtoolLinkInfoMdix::Other(d) => d,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum EthtoolLinkInfoAttr {
+    Header(Vec<EthtoolHeader>),
+    Port(EthtoolLinkInfoPort),
+    PhyAddr(u8),
+    TpMdix(EthtoolLinkInfoMdix),
+    TpMdixCtrl(EthtoolLinkInfoMdix),
+    /// Kernel `XCVR_INTERNAL`(0) or `XCVR_EXTERNAL`(1)
+    Transceiver(u8),
+    Other(DefaultNla),
+}
+
+impl Nla for EthtoolLinkInfoAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Header(hdrs) => hdrs.as_slice().buffer_len(),
+            Self::Port(_)
+            | Self::PhyAddr(_)
+            | Self::TpMdix(_)
+            | Self::TpMdixCtrl(_)
+            | Self::Transceiver(_) => 1,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Header(_) => ETHTOOL_A_LINKINFO_HEADER | NLA_F_NESTED,
+            Self::Port(_) => ETHTOOL_A_LINKINFO_PORT,
+            Self::PhyAddr(_) => ETHTOOL_A_LINKINFO_PHYADDR,
+            Self::TpMdix(_) => ETHTOOL_A_LINKINFO_TP_MDIX,
+            Self::TpMdixCtrl(_) => ETHTOOL_A_LINKINFO_TP_MDIX_CTRL,
+            Self::Transceiver(_) => ETHTOOL_A_LINKINFO_TRANSCEIVER,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Header(ref nlas) => nlas.as_slice().emit(buffer),
+            Self::Port(d) => buffer[0] = (*d).into(),
+            Self::TpMdix(d) | Self::TpMdixCtrl(d) => buffer[0] = (*d).into(),
+            Self::PhyAddr(d) | Self::Transceiver(d) => buffer[0] = *d,
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for EthtoolLinkInfoAttr
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            ETHTOOL_A_LINKINFO_HEADER => {
+                let mut nlas = Vec::new();
+                let error_msg = "failed to parse link info header attributes";
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    let parsed =
+                        EthtoolHeader::parse(nla).context(error_msg)?;
+                    nlas.push(parsed);
+                }
+                Self::Header(nlas)
+            }
+            ETHTOOL_A_LINKINFO_PORT => Self::Port(
+                parse_u8(payload)
+                    .context("Invalid ETHTOOL_A_LINKINFO_PORT value")?
+                    .into(),
+            ),
+            ETHTOOL_A_LINKINFO_PHYADDR => Self::PhyAddr(
+                parse_u8(payload)
+                    .context("Invalid ETHTOOL_A_LINKINFO_PHYADDR value")?,
+            ),
+            ETHTOOL_A_LINKINFO_TP_MDIX => Self::TpMdix(
+                parse_u8(payload)
+                    .context("Invalid ETHTOOL_A_LINKINFO_TP_MDIX value")?
+                    .into(),
+            ),
+            ETHTOOL_A_LINKINFO_TP_MDIX_CTRL => Self::TpMdixCtrl(
+                parse_u8(payload)
+                    .context("Invalid ETHTOOL_A_LINKINFO_TP_MDIX_CTRL value")?
+                    .into(),
+            ),
+            ETHTOOL_A_LINKINFO_TRANSCEIVER => Self::Transceiver(
+                parse_u8(payload)
+                    .context("Invalid ETHTOOL_A_LINKINFO_TRANSCEIVER value")?,
+            ),
+            kind => Self::Other(DefaultNla::parse(buf).context(format!(
+                "invalid ethtool link info NLA kind {kind}"
+            ))?),
+        })
+    }
+}
+
+pub(crate) fn parse_link_info_nlas(
+    buffer: &[u8],
+) -> Result<Vec<EthtoolAttr>, DecodeError> {
+    let mut nlas = Vec::new();
+    for nla in NlasIterator::new(buffer) {
+        let error_msg = format!(
+            "Failed to parse ethtool link info message attribute {nla:?}"
+        );
+        let nla = &nla.context(error_msg.clone())?;
+        let parsed = EthtoolLinkInfoAttr::parse(nla).context(error_msg)?;
+        nlas.push(EthtoolAttr::LinkInfo(parsed));
+    }
+    Ok(nlas)
+}
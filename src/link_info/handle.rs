@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    EthtoolHandle, EthtoolLinkInfoGetRequest, EthtoolLinkInfoSetRequest,
+};
+
+pub struct EthtoolLinkInfoHandle(EthtoolHandle);
+
+impl EthtoolLinkInfoHandle {
+    pub fn new(handle: EthtoolHandle) -> Self {
+        EthtoolLinkInfoHandle(handle)
+    }
+
+    /// Retrieve the port type, PHY address, transceiver and MDI-X status of
+    /// a interface (as shown by `ethtool eth1`)
+    pub fn get(
+        &mut self,
+        iface_name: Option<&str>,
+    ) -> EthtoolLinkInfoGetRequest {
+        EthtoolLinkInfoGetRequest::new(self.0.clone(), iface_name)
+    }
+
+    /// Set the port type, PHY address or MDI-X control of a interface
+    /// (equivalent to `ethtool -s eth1 port tp mdix on`)
+    pub fn set(&mut self, iface_name: &str) -> EthtoolLinkInfoSetRequest {
+        EthtoolLinkInfoSetRequest::new(self.0.clone(), iface_name)
+    }
+}
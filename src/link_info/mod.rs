@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: MIT
+
+mod attr;
+mod get;
+mod handle;
+mod set;
+
+pub(crate) use attr::parse_link_info_nlas;
+pub use attr::{EthtoolLinkInfoAttr, EthtoolLinkInfoMdix, EthtoolLinkInfoPort};
+pub use get::EthtoolLinkInfoGetRequest;
+pub use handle::EthtoolLinkInfoHandle;
+pub use set::EthtoolLinkInfoSetRequest;
@@ -0,0 +1,86 @@
+// SPDX-License-Identifier: MIT
+
+use futures_util::StreamExt;
+use netlink_packet_core::{NetlinkMessage, NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    try_ethtool, EthtoolAttr, EthtoolError, EthtoolHandle, EthtoolLinkInfoAttr,
+    EthtoolLinkInfoMdix, EthtoolLinkInfoPort, EthtoolMessage,
+};
+
+pub struct EthtoolLinkInfoSetRequest {
+    handle: EthtoolHandle,
+    message: EthtoolMessage,
+    port: Option<EthtoolLinkInfoPort>,
+    phy_addr: Option<u8>,
+    tp_mdix_ctrl: Option<EthtoolLinkInfoMdix>,
+}
+
+impl EthtoolLinkInfoSetRequest {
+    pub(crate) fn new(handle: EthtoolHandle, iface_name: &str) -> Self {
+        EthtoolLinkInfoSetRequest {
+            handle,
+            message: EthtoolMessage::new_link_info_set(iface_name),
+            port: None,
+            phy_addr: None,
+            tp_mdix_ctrl: None,
+        }
+    }
+
+    pub fn port(mut self, port: EthtoolLinkInfoPort) -> Self {
+        self.port = Some(port);
+        self
+    }
+
+    pub fn phy_addr(mut self, addr: u8) -> Self {
+        self.phy_addr = Some(addr);
+        self
+    }
+
+    /// Force MDI or MDI-X, or use `EthtoolLinkInfoMdix::Auto` for automatic
+    /// crossover
+    pub fn tp_mdix_ctrl(mut self, mdix: EthtoolLinkInfoMdix) -> Self {
+        self.tp_mdix_ctrl = Some(mdix);
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), EthtoolError> {
+        let EthtoolLinkInfoSetRequest {
+            mut handle,
+            mut message,
+            port,
+            phy_addr,
+            tp_mdix_ctrl,
+        } = self;
+
+        if let Some(port) = port {
+            message
+                .nlas
+                .push(EthtoolAttr::LinkInfo(EthtoolLinkInfoAttr::Port(port)));
+        }
+        if let Some(addr) = phy_addr {
+            message.nlas.push(EthtoolAttr::LinkInfo(
+                EthtoolLinkInfoAttr::PhyAddr(addr),
+            ));
+        }
+        if let Some(mdix) = tp_mdix_ctrl {
+            message.nlas.push(EthtoolAttr::LinkInfo(
+                EthtoolLinkInfoAttr::TpMdixCtrl(mdix),
+            ));
+        }
+
+        let mut nl_msg =
+            NetlinkMessage::from(GenlMessage::from_payload(message));
+
+        nl_msg.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        let mut response = handle.request(nl_msg).await?;
+
+        while let Some(message) = response.next().await {
+            try_ethtool!(message);
+        }
+
+        Ok(())
+    }
+}
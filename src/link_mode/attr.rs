@@ -1,12 +1,16 @@
 // SPDX-License-Identifier: MIT
 
 use netlink_packet_core::{
-    parse_u32, parse_u8, DecodeError, DefaultNla, Emitable, ErrorContext, Nla,
-    NlaBuffer, NlasIterator, Parseable, NLA_F_NESTED,
+    emit_u32, parse_u32, parse_u8, DecodeError, DefaultNla, Emitable,
+    ErrorContext, Nla, NlaBuffer, NlasIterator, Parseable, NLA_F_NESTED,
 };
 
 use crate::{
-    bitset_util::parse_bitset_bits_string_nlas, EthtoolAttr, EthtoolHeader,
+    bitset_util::{
+        bitset_nlas_len, emit_bitset_nlas, parse_bitset_set_bits_nlas,
+        EthtoolBit, EthtoolBitSet,
+    },
+    EthtoolAttr, EthtoolHeader,
 };
 
 const ETHTOOL_A_LINKMODES_HEADER: u16 = 1;
@@ -57,8 +61,11 @@ impl From<EthtoolLinkModeDuplex> for u8 {
 pub enum EthtoolLinkModeAttr {
     Header(Vec<EthtoolHeader>),
     Autoneg(bool),
-    Ours(Vec<String>),
-    Peer(Vec<String>),
+    /// Link modes advertised, indexed by `LinkModes` string set
+    Ours(Vec<EthtoolBit>),
+    /// Link modes advertised by link partner, indexed by `LinkModes` string
+    /// set
+    Peer(Vec<EthtoolBit>),
     Speed(u32),
     Duplex(EthtoolLinkModeDuplex),
     ControllerSubordinateCfg(u8),
@@ -75,11 +82,8 @@ impl Nla for EthtoolLinkModeAttr {
             | Self::Duplex(_)
             | Self::ControllerSubordinateCfg(_)
             | Self::ControllerSubordinateState(_) => 1,
-            Self::Ours(_) => {
-                todo!("Does not support changing ethtool link mode yet")
-            }
-            Self::Peer(_) => {
-                todo!("Does not support changing ethtool link mode yet")
+            Self::Ours(modes) | Self::Peer(modes) => {
+                bitset_nlas_len(&link_modes_to_bitset(modes), true)
             }
             Self::Speed(_) | Self::Lanes(_) => 4,
             Self::Other(attr) => attr.value_len(),
@@ -90,8 +94,8 @@ impl Nla for EthtoolLinkModeAttr {
         match self {
             Self::Header(_) => ETHTOOL_A_LINKMODES_HEADER | NLA_F_NESTED,
             Self::Autoneg(_) => ETHTOOL_A_LINKMODES_AUTONEG,
-            Self::Ours(_) => ETHTOOL_A_LINKMODES_OURS,
-            Self::Peer(_) => ETHTOOL_A_LINKMODES_PEER,
+            Self::Ours(_) => ETHTOOL_A_LINKMODES_OURS | NLA_F_NESTED,
+            Self::Peer(_) => ETHTOOL_A_LINKMODES_PEER | NLA_F_NESTED,
             Self::Speed(_) => ETHTOOL_A_LINKMODES_SPEED,
             Self::Duplex(_) => ETHTOOL_A_LINKMODES_DUPLEX,
             Self::ControllerSubordinateCfg(_) => {
@@ -108,12 +112,27 @@ impl Nla for EthtoolLinkModeAttr {
     fn emit_value(&self, buffer: &mut [u8]) {
         match self {
             Self::Header(ref nlas) => nlas.as_slice().emit(buffer),
+            Self::Autoneg(d) => buffer[0] = *d as u8,
+            Self::Ours(modes) | Self::Peer(modes) => {
+                emit_bitset_nlas(&link_modes_to_bitset(modes), true, buffer)
+            }
+            Self::Speed(d) | Self::Lanes(d) => emit_u32(buffer, *d).unwrap(),
+            Self::Duplex(d) => buffer[0] = (*d).into(),
+            Self::ControllerSubordinateCfg(d)
+            | Self::ControllerSubordinateState(d) => buffer[0] = *d,
             Self::Other(ref attr) => attr.emit(buffer),
-            _ => todo!("Does not support changing ethtool link mode yet"),
         }
     }
 }
 
+// The link modes are sent as a list, so every mode not included is disabled.
+fn link_modes_to_bitset(modes: &[EthtoolBit]) -> Vec<EthtoolBitSet> {
+    modes
+        .iter()
+        .map(|mode| EthtoolBitSet::new(mode, true))
+        .collect()
+}
+
 impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
     for EthtoolLinkModeAttr
 {
@@ -138,10 +157,10 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
             ),
 
             ETHTOOL_A_LINKMODES_OURS => {
-                Self::Ours(parse_bitset_bits_string_nlas(payload)?)
+                Self::Ours(parse_bitset_set_bits_nlas(payload)?)
             }
             ETHTOOL_A_LINKMODES_PEER => {
-                Self::Peer(parse_bitset_bits_string_nlas(payload)?)
+                Self::Peer(parse_bitset_set_bits_nlas(payload)?)
             }
             ETHTOOL_A_LINKMODES_SPEED => Self::Speed(
                 parse_u32(payload)
@@ -1,6 +1,8 @@
 // SPDX-License-Identifier: MIT
 
-use crate::{EthtoolHandle, EthtoolLinkModeGetRequest};
+use crate::{
+    EthtoolHandle, EthtoolLinkModeGetRequest, EthtoolLinkModeSetRequest,
+};
 
 pub struct EthtoolLinkModeHandle(EthtoolHandle);
 
@@ -17,4 +19,10 @@ impl EthtoolLinkModeHandle {
     ) -> EthtoolLinkModeGetRequest {
         EthtoolLinkModeGetRequest::new(self.0.clone(), iface_name)
     }
+
+    /// Set the ethtool link_modes(duplex, link speed and etc) of a interface
+    /// (equivalent to `ethtool -s eth1`)
+    pub fn set(&mut self, iface_name: &str) -> EthtoolLinkModeSetRequest {
+        EthtoolLinkModeSetRequest::new(self.0.clone(), iface_name)
+    }
 }
@@ -0,0 +1,129 @@
+// SPDX-License-Identifier: MIT
+
+use futures_util::StreamExt;
+use netlink_packet_core::{NetlinkMessage, NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    try_ethtool, EthtoolAttr, EthtoolBit, EthtoolError, EthtoolHandle,
+    EthtoolLinkModeAttr, EthtoolLinkModeDuplex, EthtoolMessage,
+};
+
+pub struct EthtoolLinkModeSetRequest {
+    handle: EthtoolHandle,
+    message: EthtoolMessage,
+    autoneg: Option<bool>,
+    speed: Option<u32>,
+    duplex: Option<EthtoolLinkModeDuplex>,
+    lanes: Option<u32>,
+    controller_subordinate_cfg: Option<u8>,
+    advertise: Option<Vec<EthtoolBit>>,
+}
+
+impl EthtoolLinkModeSetRequest {
+    pub(crate) fn new(handle: EthtoolHandle, iface_name: &str) -> Self {
+        EthtoolLinkModeSetRequest {
+            handle,
+            message: EthtoolMessage::new_link_mode_set(iface_name),
+            autoneg: None,
+            speed: None,
+            duplex: None,
+            lanes: None,
+            controller_subordinate_cfg: None,
+            advertise: None,
+        }
+    }
+
+    pub fn autoneg(mut self, enabled: bool) -> Self {
+        self.autoneg = Some(enabled);
+        self
+    }
+
+    /// Link speed in Mb/s
+    pub fn speed(mut self, speed: u32) -> Self {
+        self.speed = Some(speed);
+        self
+    }
+
+    pub fn duplex(mut self, duplex: EthtoolLinkModeDuplex) -> Self {
+        self.duplex = Some(duplex);
+        self
+    }
+
+    pub fn lanes(mut self, lanes: u32) -> Self {
+        self.lanes = Some(lanes);
+        self
+    }
+
+    /// Master/slave configuration of the PHY, using the kernel
+    /// `MASTER_SLAVE_CFG_*` values.
+    pub fn controller_subordinate_cfg(mut self, cfg: u8) -> Self {
+        self.controller_subordinate_cfg = Some(cfg);
+        self
+    }
+
+    /// Link modes to advertise, using kernel link mode names like
+    /// `1000baseT/Full`. Link modes not listed will not be advertised.
+    pub fn advertise(mut self, modes: &[&str]) -> Self {
+        self.advertise =
+            Some(modes.iter().map(|m| EthtoolBit::from(*m)).collect());
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), EthtoolError> {
+        let EthtoolLinkModeSetRequest {
+            mut handle,
+            mut message,
+            autoneg,
+            speed,
+            duplex,
+            lanes,
+            controller_subordinate_cfg,
+            advertise,
+        } = self;
+
+        if let Some(enabled) = autoneg {
+            message.nlas.push(EthtoolAttr::LinkMode(
+                EthtoolLinkModeAttr::Autoneg(enabled),
+            ));
+        }
+        if let Some(modes) = advertise {
+            message
+                .nlas
+                .push(EthtoolAttr::LinkMode(EthtoolLinkModeAttr::Ours(modes)));
+        }
+        if let Some(speed) = speed {
+            message
+                .nlas
+                .push(EthtoolAttr::LinkMode(EthtoolLinkModeAttr::Speed(speed)));
+        }
+        if let Some(duplex) = duplex {
+            message.nlas.push(EthtoolAttr::LinkMode(
+                EthtoolLinkModeAttr::Duplex(duplex),
+            ));
+        }
+        if let Some(cfg) = controller_subordinate_cfg {
+            message.nlas.push(EthtoolAttr::LinkMode(
+                EthtoolLinkModeAttr::ControllerSubordinateCfg(cfg),
+            ));
+        }
+        if let Some(lanes) = lanes {
+            message
+                .nlas
+                .push(EthtoolAttr::LinkMode(EthtoolLinkModeAttr::Lanes(lanes)));
+        }
+
+        let mut nl_msg =
+            NetlinkMessage::from(GenlMessage::from_payload(message));
+
+        nl_msg.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        let mut response = handle.request(nl_msg).await?;
+
+        while let Some(message) = response.next().await {
+            try_ethtool!(message);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,360 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32, parse_u32, parse_u8, DecodeError, DefaultNla, Emitable,
+    ErrorContext, Nla, NlaBuffer, NlasIterator, Parseable, NLA_F_NESTED,
+};
+
+use crate::{EthtoolAttr, EthtoolHeader};
+
+const ETHTOOL_A_LINKSTATE_HEADER: u16 = 1;
+const ETHTOOL_A_LINKSTATE_LINK: u16 = 2;
+const ETHTOOL_A_LINKSTATE_SQI: u16 = 3;
+const ETHTOOL_A_LINKSTATE_SQI_MAX: u16 = 4;
+const ETHTOOL_A_LINKSTATE_EXT_STATE: u16 = 5;
+const ETHTOOL_A_LINKSTATE_EXT_SUBSTATE: u16 = 6;
+const ETHTOOL_A_LINKSTATE_EXT_DOWN_CNT: u16 = 7;
+
+const ETHTOOL_LINK_EXT_STATE_AUTONEG: u8 = 0;
+const ETHTOOL_LINK_EXT_STATE_LINK_TRAINING_FAILURE: u8 = 1;
+const ETHTOOL_LINK_EXT_STATE_LINK_LOGICAL_MISMATCH: u8 = 2;
+const ETHTOOL_LINK_EXT_STATE_BAD_SIGNAL_INTEGRITY: u8 = 3;
+const ETHTOOL_LINK_EXT_STATE_NO_CABLE: u8 = 4;
+const ETHTOOL_LINK_EXT_STATE_CABLE_ISSUE: u8 = 5;
+const ETHTOOL_LINK_EXT_STATE_EEPROM_ISSUE: u8 = 6;
+const ETHTOOL_LINK_EXT_STATE_CALIBRATION_FAILURE: u8 = 7;
+const ETHTOOL_LINK_EXT_STATE_POWER_BUDGET_EXCEEDED: u8 = 8;
+const ETHTOOL_LINK_EXT_STATE_OVERHEAT: u8 = 9;
+const ETHTOOL_LINK_EXT_STATE_MODULE: u8 = 10;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EthtoolLinkExtState {
+    Autoneg,
+    LinkTrainingFailure,
+    LinkLogicalMismatch,
+    BadSignalIntegrity,
+    NoCable,
+    CableIssue,
+    EepromIssue,
+    CalibrationFailure,
+    PowerBudgetExceeded,
+    Overheat,
+    Module,
+    Other(u8),
+}
+
+impl From<u8> for EthtoolLinkExtState {
+    fn from(d: u8) -> Self {
+        match d {
+            ETHTOOL_LINK_EXT_STATE_AUTONEG => Self::Autoneg,
+            ETHTOOL_LINK_EXT_STATE_LINK_TRAINING_FAILURE => {
+                Self::LinkTrainingFailure
+            }
+            ETHTOOL_LINK_EXT_STATE_LINK_LOGICAL_MISMATCH => {
+                Self::LinkLogicalMismatch
+            }
+            ETHTOOL_LINK_EXT_STATE_BAD_SIGNAL_INTEGRITY => {
+                Self::BadSignalIntegrity
+            }
+            ETHTOOL_LINK_EXT_STATE_NO_CABLE => Self::NoCable,
+            ETHTOOL_LINK_EXT_STATE_CABLE_ISSUE => Self::CableIssue,
+            ETHTOOL_LINK_EXT_STATE_EEPROM_ISSUE => Self::EepromIssue,
+            ETHTOOL_LINK_EXT_STATE_CALIBRATION_FAILURE => {
+                Self::CalibrationFailure
+            }
+            ETHTOOL_LINK_EXT_STATE_POWER_BUDGET_EXCEEDED => {
+                Self::PowerBudgetExceeded
+            }
+            ETHTOOL_LINK_EXT_STATE_OVERHEAT => Self::Overheat,
+            ETHTOOL_LINK_EXT_STATE_MODULE => Self::Module,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<EthtoolLinkExtState> for u8 {
+    fn from(v: EthtoolLinkExtState) -> u8 {
+        match v {
+            EthtoolLinkExtState::Autoneg => ETHTOOL_LINK_EXT_STATE_AUTONEG,
+            EthtoolLinkExtState::LinkTrainingFailure => {
+                ETHTOOL_LINK_EXT_STATE_LINK_TRAINING_FAILURE
+            }
+            EthtoolLinkExtState::LinkLogicalMismatch => {
+                ETHTOOL_LINK_EXT_STATE_LINK_LOGICAL_MISMATCH
+            }
+            EthtoolLinkExtState::BadSignalIntegrity => {
+                ETHTOOL_LINK_EXT_STATE_BAD_SIGNAL_INTEGRITY
+            }
+            EthtoolLinkExtState::NoCable => ETHTOOL_LINK_EXT_STATE_NO_CABLE,
+            EthtoolLinkExtState::CableIssue => {
+                ETHTOOL_LINK_EXT_STATE_CABLE_ISSUE
+            }
+            EthtoolLinkExtState::EepromIssue => {
+                ETHTOOL_LINK_EXT_STATE_EEPROM_ISSUE
+            }
+            EthtoolLinkExtState::CalibrationFailure => {
+                ETHTOOL_LINK_EXT_STATE_CALIBRATION_FAILURE
+            }
+            EthtoolLinkExtState::PowerBudgetExceeded => {
+                ETHTOOL_LINK_EXT_STATE_POWER_BUDGET_EXCEEDED
+            }
+            EthtoolLinkExtState::Overheat => ETHTOOL_LINK_EXT_STATE_OVERHEAT,
+            EthtoolLinkExtState::Module => ETHTOOL_LINK_EXT_STATE_MODULE,
+            EthtoolLinkExtState::Other(d) => d,
+        }
+    }
+}
+
+/// The meaning of extended substate depends on the extended state, hence
+/// the variants are prefixed by the abbreviation of their extended state.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EthtoolLinkExtSubstate {
+    AnNoPartnerDetected,
+    AnAckNotReceived,
+    AnNextPageExchangeFailed,
+    AnNoPartnerDetectedForceMode,
+    AnFecMismatchDuringOverride,
+    AnNoHcd,
+    LtKrFrameLockNotAcquired,
+    LtKrLinkInhibitTimeout,
+    LtKrLinkPartnerDidNotSetReceiverReady,
+    LtRemoteFault,
+    LlmPcsDidNotAcquireBlockLock,
+    LlmPcsDidNotAcquireAmLock,
+    LlmPcsDidNotGetAlignStatus,
+    LlmFcFecIsNotLocked,
+    LlmRsFecIsNotLocked,
+    BsiLargeNumberOfPhysicalErrors,
+    BsiUnsupportedRate,
+    BsiSerdesReferenceClockLost,
+    BsiSerdesAlos,
+    CiUnsupportedCable,
+    CiCableTestFailure,
+    ModuleCmisNotReady,
+    /// Substate not known by this crate or not yet resolved against the
+    /// extended state.
+    Other(u8),
+}
+
+impl EthtoolLinkExtSubstate {
+    pub fn new(state: EthtoolLinkExtState, d: u8) -> Self {
+        match (state, d) {
+            (EthtoolLinkExtState::Autoneg, 1) => Self::AnNoPartnerDetected,
+            (EthtoolLinkExtState::Autoneg, 2) => Self::AnAckNotReceived,
+            (EthtoolLinkExtState::Autoneg, 3) => Self::AnNextPageExchangeFailed,
+            (EthtoolLinkExtState::Autoneg, 4) => {
+                Self::AnNoPartnerDetectedForceMode
+            }
+            (EthtoolLinkExtState::Autoneg, 5) => {
+                Self::AnFecMismatchDuringOverride
+            }
+            (EthtoolLinkExtState::Autoneg, 6) => Self::AnNoHcd,
+            (EthtoolLinkExtState::LinkTrainingFailure, 1) => {
+                Self::LtKrFrameLockNotAcquired
+            }
+            (EthtoolLinkExtState::LinkTrainingFailure, 2) => {
+                Self::LtKrLinkInhibitTimeout
+            }
+            (EthtoolLinkExtState::LinkTrainingFailure, 3) => {
+                Self::LtKrLinkPartnerDidNotSetReceiverReady
+            }
+            (EthtoolLinkExtState::LinkTrainingFailure, 4) => {
+                Self::LtRemoteFault
+            }
+            (EthtoolLinkExtState::LinkLogicalMismatch, 1) => {
+                Self::LlmPcsDidNotAcquireBlockLock
+            }
+            (EthtoolLinkExtState::LinkLogicalMismatch, 2) => {
+                Self::LlmPcsDidNotAcquireAmLock
+            }
+            (EthtoolLinkExtState::LinkLogicalMismatch, 3) => {
+                Self::LlmPcsDidNotGetAlignStatus
+            }
+            (EthtoolLinkExtState::LinkLogicalMismatch, 4) => {
+                Self::LlmFcFecIsNotLocked
+            }
+            (EthtoolLinkExtState::LinkLogicalMismatch, 5) => {
+                Self::LlmRsFecIsNotLocked
+            }
+            (EthtoolLinkExtState::BadSignalIntegrity, 1) => {
+                Self::BsiLargeNumberOfPhysicalErrors
+            }
+            (EthtoolLinkExtState::BadSignalIntegrity, 2) => {
+                Self::BsiUnsupportedRate
+            }
+            (EthtoolLinkExtState::BadSignalIntegrity, 3) => {
+                Self::BsiSerdesReferenceClockLost
+            }
+            (EthtoolLinkExtState::BadSignalIntegrity, 4) => Self::BsiSerdesAlos,
+            (EthtoolLinkExtState::CableIssue, 1) => Self::CiUnsupportedCable,
+            (EthtoolLinkExtState::CableIssue, 2) => Self::CiCableTestFailure,
+            (EthtoolLinkExtState::Module, 1) => Self::ModuleCmisNotReady,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<EthtoolLinkExtSubstate> for u8 {
+    fn from(v: EthtoolLinkExtSubstate) -> u8 {
+        match v {
+            EthtoolLinkExtSubstate::AnNoPartnerDetected
+            | EthtoolLinkExtSubstate::LtKrFrameLockNotAcquired
+            | EthtoolLinkExtSubstate::LlmPcsDidNotAcquireBlockLock
+            | EthtoolLinkExtSubstate::BsiLargeNumberOfPhysicalErrors
+            | EthtoolLinkExtSubstate::CiUnsupportedCable
+            | EthtoolLinkExtSubstate::ModuleCmisNotReady => 1,
+            EthtoolLinkExtSubstate::AnAckNotReceived
+            | EthtoolLinkExtSubstate::LtKrLinkInhibitTimeout
+            | EthtoolLinkExtSubstate::LlmPcsDidNotAcquireAmLock
+            | EthtoolLinkExtSubstate::BsiUnsupportedRate
+            | EthtoolLinkExtSubstate::CiCableTestFailure => 2,
+            EthtoolLinkExtSubstate::AnNextPageExchangeFailed
+            | EthtoolLinkExtSubstate::LtKrLinkPartnerDidNotSetReceiverReady
+            | EthtoolLinkExtSubstate::LlmPcsDidNotGetAlignStatus
+            | EthtoolLinkExtSubstate::BsiSerdesReferenceClockLost => 3,
+            EthtoolLinkExtSubstate::AnNoPartnerDetectedForceMode
+            | EthtoolLinkExtSubstate::LtRemoteFault
+            | EthtoolLinkExtSubstate::LlmFcFecIsNotLocked
+            | EthtoolLinkExtSubstate::BsiSerdesAlos => 4,
+            EthtoolLinkExtSubstate::AnFecMismatchDuringOverride
+            | EthtoolLinkExtSubstate::LlmRsFecIsNotLocked => 5,
+            EthtoolLinkExtSubstate::AnNoHcd => 6,
+            EthtoolLinkExtSubstate::Other(d) => d,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum EthtoolLinkStateAttr {
+    Header(Vec<EthtoolHeader>),
+    Link(bool),
+    Sqi(u32),
+    SqiMax(u32),
+    ExtState(EthtoolLinkExtState),
+    ExtSubstate(EthtoolLinkExtSubstate),
+    ExtDownCount(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for EthtoolLinkStateAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Header(hdrs) => hdrs.as_slice().buffer_len(),
+            Self::Link(_) | Self::ExtState(_) | Self::ExtSubstate(_) => 1,
+            Self::Sqi(_) | Self::SqiMax(_) | Self::ExtDownCount(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Header(_) => ETHTOOL_A_LINKSTATE_HEADER | NLA_F_NESTED,
+            Self::Link(_) => ETHTOOL_A_LINKSTATE_LINK,
+            Self::Sqi(_) => ETHTOOL_A_LINKSTATE_SQI,
+            Self::SqiMax(_) => ETHTOOL_A_LINKSTATE_SQI_MAX,
+            Self::ExtState(_) => ETHTOOL_A_LINKSTATE_EXT_STATE,
+            Self::ExtSubstate(_) => ETHTOOL_A_LINKSTATE_EXT_SUBSTATE,
+            Self::ExtDownCount(_) => ETHTOOL_A_LINKSTATE_EXT_DOWN_CNT,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Header(ref nlas) => nlas.as_slice().emit(buffer),
+            Self::Link(d) => buffer[0] = *d as u8,
+            Self::ExtState(d) => buffer[0] = (*d).into(),
+            Self::ExtSubstate(d) => buffer[0] = (*d).into(),
+            Self::Sqi(d) | Self::SqiMax(d) | Self::ExtDownCount(d) => {
+                emit_u32(buffer, *d).unwrap()
+            }
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for EthtoolLinkStateAttr
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            ETHTOOL_A_LINKSTATE_HEADER => {
+                let mut nlas = Vec::new();
+                let error_msg = "failed to parse link state header attributes";
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    let parsed =
+                        EthtoolHeader::parse(nla).context(error_msg)?;
+                    nlas.push(parsed);
+                }
+                Self::Header(nlas)
+            }
+            ETHTOOL_A_LINKSTATE_LINK => Self::Link(
+                parse_u8(payload)
+                    .context("Invalid ETHTOOL_A_LINKSTATE_LINK value")?
+                    > 0,
+            ),
+            ETHTOOL_A_LINKSTATE_SQI => Self::Sqi(
+                parse_u32(payload)
+                    .context("Invalid ETHTOOL_A_LINKSTATE_SQI value")?,
+            ),
+            ETHTOOL_A_LINKSTATE_SQI_MAX => Self::SqiMax(
+                parse_u32(payload)
+                    .context("Invalid ETHTOOL_A_LINKSTATE_SQI_MAX value")?,
+            ),
+            ETHTOOL_A_LINKSTATE_EXT_STATE => Self::ExtState(
+                parse_u8(payload)
+                    .context("Invalid ETHTOOL_A_LINKSTATE_EXT_STATE value")?
+                    .into(),
+            ),
+            // Resolved against the extended state in parse_link_state_nlas()
+            ETHTOOL_A_LINKSTATE_EXT_SUBSTATE => Self::ExtSubstate(
+                EthtoolLinkExtSubstate::Other(parse_u8(payload).context(
+                    "Invalid ETHTOOL_A_LINKSTATE_EXT_SUBSTATE value",
+                )?),
+            ),
+            ETHTOOL_A_LINKSTATE_EXT_DOWN_CNT => {
+                Self::ExtDownCount(parse_u32(payload).context(
+                    "Invalid ETHTOOL_A_LINKSTATE_EXT_DOWN_CNT value",
+                )?)
+            }
+            kind => Self::Other(DefaultNla::parse(buf).context(format!(
+                "invalid ethtool link state NLA kind {kind}"
+            ))?),
+        })
+    }
+}
+
+pub(crate) fn parse_link_state_nlas(
+    buffer: &[u8],
+) -> Result<Vec<EthtoolAttr>, DecodeError> {
+    let mut nlas = Vec::new();
+    let mut ext_state = None;
+    for nla in NlasIterator::new(buffer) {
+        let error_msg = format!(
+            "Failed to parse ethtool link state message attribute {nla:?}"
+        );
+        let nla = &nla.context(error_msg.clone())?;
+        let parsed = EthtoolLinkStateAttr::parse(nla).context(error_msg)?;
+        if let EthtoolLinkStateAttr::ExtState(state) = parsed {
+            ext_state = Some(state);
+        }
+        nlas.push(parsed);
+    }
+    Ok(nlas
+        .into_iter()
+        .map(|nla| match (nla, ext_state) {
+            (
+                EthtoolLinkStateAttr::ExtSubstate(
+                    EthtoolLinkExtSubstate::Other(d),
+                ),
+                Some(state),
+            ) => EthtoolLinkStateAttr::ExtSubstate(
+                EthtoolLinkExtSubstate::new(state, d),
+            ),
+            (nla, _) => nla,
+        })
+        .map(EthtoolAttr::LinkState)
+        .collect())
+}
@@ -0,0 +1,20 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{EthtoolHandle, EthtoolLinkStateGetRequest};
+
+pub struct EthtoolLinkStateHandle(EthtoolHandle);
+
+impl EthtoolLinkStateHandle {
+    pub fn new(handle: EthtoolHandle) -> Self {
+        EthtoolLinkStateHandle(handle)
+    }
+
+    /// Retrieve the link state and the extended link down reason of a
+    /// interface (equivalent to `Link detected` of `ethtool eth1`)
+    pub fn get(
+        &mut self,
+        iface_name: Option<&str>,
+    ) -> EthtoolLinkStateGetRequest {
+        EthtoolLinkStateGetRequest::new(self.0.clone(), iface_name)
+    }
+}
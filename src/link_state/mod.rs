@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: MIT
+
+mod attr;
+mod get;
+mod handle;
+
+pub(crate) use attr::parse_link_state_nlas;
+pub use attr::{
+    EthtoolLinkExtState, EthtoolLinkExtSubstate, EthtoolLinkStateAttr,
+};
+pub use get::EthtoolLinkStateGetRequest;
+pub use handle::EthtoolLinkStateHandle;
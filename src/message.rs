@@ -4,59 +4,227 @@ use netlink_packet_core::{DecodeError, Emitable, Nla, ParseableParametrized};
 use netlink_packet_generic::{GenlFamily, GenlHeader};
 
 use crate::{
+    cable_test::{
+        parse_cable_test_nlas, parse_cable_test_tdr_nlas, EthtoolCableTestAttr,
+        EthtoolCableTestTdrAttr,
+    },
     channel::{parse_channel_nlas, EthtoolChannelAttr},
     coalesce::{parse_coalesce_nlas, EthtoolCoalesceAttr},
+    debug::{parse_debug_nlas, EthtoolDebugAttr},
+    eee::{parse_eee_nlas, EthtoolEeeAttr},
     eeprom::{parse_module_eeprom_nlas, EthtoolModuleEEPROMAttr},
     feature::{parse_feature_nlas, EthtoolFeatureAttr},
     fec::{parse_fec_nlas, EthtoolFecAttr},
+    link_info::{parse_link_info_nlas, EthtoolLinkInfoAttr},
     link_mode::{parse_link_mode_nlas, EthtoolLinkModeAttr},
+    link_state::{parse_link_state_nlas, EthtoolLinkStateAttr},
+    mm::{parse_mm_nlas, EthtoolMmAttr},
+    module::{parse_module_nlas, EthtoolModuleAttr},
+    module_fw_flash::{parse_module_fw_flash_nlas, EthtoolModuleFwFlashAttr},
     pause::{parse_pause_nlas, EthtoolPauseAttr},
+    phc_vclocks::{parse_phc_vclocks_nlas, EthtoolPhcVclocksAttr},
+    plca::{parse_plca_nlas, EthtoolPlcaAttr},
+    privflags::{parse_privflags_nlas, EthtoolPrivFlagsAttr},
+    pse::{parse_pse_nlas, EthtoolPseAttr},
     ring::{parse_ring_nlas, EthtoolRingAttr},
+    rss::{parse_rss_nlas, EthtoolRssAttr},
+    stats::{parse_stats_nlas, EthtoolStatsAttr, EthtoolStatsGroupId},
+    strset::{parse_strset_nlas, EthtoolStringSetAttr},
     tsinfo::{parse_tsinfo_nlas, EthtoolTsInfoAttr},
+    tunnel_info::{parse_tunnel_info_nlas, EthtoolTunnelInfoAttr},
+    wol::{parse_wol_nlas, EthtoolWolAttr},
     EthtoolHeader,
 };
 
 const ETHTOOL_MSG_PAUSE_GET: u8 = 21;
 const ETHTOOL_MSG_PAUSE_GET_REPLY: u8 = 22;
+const ETHTOOL_MSG_PAUSE_SET: u8 = 22;
 const ETHTOOL_MSG_FEATURES_GET: u8 = 11;
 const ETHTOOL_MSG_FEATURES_GET_REPLY: u8 = 11;
+const ETHTOOL_MSG_FEATURES_SET: u8 = 12;
+const ETHTOOL_MSG_FEATURES_SET_REPLY: u8 = 12;
 const ETHTOOL_MSG_LINKMODES_GET: u8 = 4;
 const ETHTOOL_MSG_LINKMODES_GET_REPLY: u8 = 4;
+const ETHTOOL_MSG_LINKMODES_SET: u8 = 5;
 const ETHTOOL_MSG_RINGS_GET: u8 = 15;
 const ETHTOOL_MSG_RINGS_GET_REPLY: u8 = 16;
+const ETHTOOL_MSG_RINGS_SET: u8 = 16;
 const ETHTOOL_MSG_COALESCE_GET: u8 = 19;
 const ETHTOOL_MSG_COALESCE_GET_REPLY: u8 = 20;
+const ETHTOOL_MSG_COALESCE_SET: u8 = 20;
 const ETHTOOL_MSG_TSINFO_GET: u8 = 25;
 const ETHTOOL_MSG_TSINFO_GET_REPLY: u8 = 26;
 const ETHTOOL_MSG_FEC_GET: u8 = 29;
 const ETHTOOL_MSG_FEC_GET_REPLY: u8 = 30;
+const ETHTOOL_MSG_FEC_SET: u8 = 30;
 const ETHTOOL_MSG_CHANNELS_GET: u8 = 17;
 const ETHTOOL_MSG_CHANNELS_GET_REPLY: u8 = 18;
 const ETHTOOL_MSG_CHANNELS_SET: u8 = 18;
 const ETHTOOL_MSG_MODULE_EEPROM_GET: u8 = 31;
 const ETHTOOL_MSG_MODULE_EEPROM_GET_REPLY: u8 = 32;
+const ETHTOOL_MSG_LINKSTATE_GET: u8 = 6;
+const ETHTOOL_MSG_LINKSTATE_GET_REPLY: u8 = 6;
+const ETHTOOL_MSG_LINKINFO_GET: u8 = 2;
+const ETHTOOL_MSG_LINKINFO_GET_REPLY: u8 = 2;
+const ETHTOOL_MSG_LINKINFO_SET: u8 = 3;
+const ETHTOOL_MSG_WOL_GET: u8 = 9;
+const ETHTOOL_MSG_WOL_GET_REPLY: u8 = 9;
+const ETHTOOL_MSG_WOL_SET: u8 = 10;
+const ETHTOOL_MSG_EEE_GET: u8 = 23;
+const ETHTOOL_MSG_EEE_GET_REPLY: u8 = 24;
+const ETHTOOL_MSG_EEE_SET: u8 = 24;
+const ETHTOOL_MSG_STATS_GET: u8 = 32;
+const ETHTOOL_MSG_STATS_GET_REPLY: u8 = 33;
+const ETHTOOL_MSG_STRSET_GET: u8 = 1;
+const ETHTOOL_MSG_STRSET_GET_REPLY: u8 = 1;
+const ETHTOOL_MSG_PRIVFLAGS_GET: u8 = 13;
+const ETHTOOL_MSG_PRIVFLAGS_GET_REPLY: u8 = 14;
+const ETHTOOL_MSG_PRIVFLAGS_SET: u8 = 14;
+const ETHTOOL_MSG_CABLE_TEST_ACT: u8 = 26;
+const ETHTOOL_MSG_CABLE_TEST_NTF: u8 = 27;
+const ETHTOOL_MSG_CABLE_TEST_TDR_ACT: u8 = 27;
+const ETHTOOL_MSG_CABLE_TEST_TDR_NTF: u8 = 28;
+const ETHTOOL_MSG_LINKINFO_NTF: u8 = 3;
+const ETHTOOL_MSG_LINKMODES_NTF: u8 = 5;
+const ETHTOOL_MSG_WOL_NTF: u8 = 10;
+const ETHTOOL_MSG_FEATURES_NTF: u8 = 13;
+const ETHTOOL_MSG_PRIVFLAGS_NTF: u8 = 15;
+const ETHTOOL_MSG_RINGS_NTF: u8 = 17;
+const ETHTOOL_MSG_CHANNELS_NTF: u8 = 19;
+const ETHTOOL_MSG_COALESCE_NTF: u8 = 21;
+const ETHTOOL_MSG_PAUSE_NTF: u8 = 23;
+const ETHTOOL_MSG_EEE_NTF: u8 = 25;
+const ETHTOOL_MSG_FEC_NTF: u8 = 31;
+const ETHTOOL_MSG_RSS_GET: u8 = 38;
+const ETHTOOL_MSG_RSS_GET_REPLY: u8 = 38;
+const ETHTOOL_MSG_RSS_SET: u8 = 48;
+const ETHTOOL_MSG_RSS_NTF: u8 = 50;
+const ETHTOOL_MSG_RSS_CREATE_ACT: u8 = 49;
+const ETHTOOL_MSG_RSS_CREATE_ACT_REPLY: u8 = 51;
+const ETHTOOL_MSG_RSS_DELETE_ACT: u8 = 50;
+const ETHTOOL_MSG_MODULE_GET: u8 = 34;
+const ETHTOOL_MSG_MODULE_GET_REPLY: u8 = 35;
+const ETHTOOL_MSG_MODULE_SET: u8 = 35;
+const ETHTOOL_MSG_MODULE_NTF: u8 = 36;
+const ETHTOOL_MSG_MODULE_FW_FLASH_ACT: u8 = 44;
+const ETHTOOL_MSG_MODULE_FW_FLASH_NTF: u8 = 44;
+const ETHTOOL_MSG_PHC_VCLOCKS_GET: u8 = 33;
+const ETHTOOL_MSG_PHC_VCLOCKS_GET_REPLY: u8 = 34;
+const ETHTOOL_MSG_DEBUG_GET: u8 = 7;
+const ETHTOOL_MSG_DEBUG_GET_REPLY: u8 = 7;
+const ETHTOOL_MSG_DEBUG_SET: u8 = 8;
+const ETHTOOL_MSG_DEBUG_NTF: u8 = 8;
+const ETHTOOL_MSG_TUNNEL_INFO_GET: u8 = 28;
+const ETHTOOL_MSG_TUNNEL_INFO_GET_REPLY: u8 = 29;
+const ETHTOOL_MSG_PSE_GET: u8 = 36;
+const ETHTOOL_MSG_PSE_GET_REPLY: u8 = 37;
+const ETHTOOL_MSG_PSE_SET: u8 = 37;
+const ETHTOOL_MSG_PLCA_GET_CFG: u8 = 39;
+const ETHTOOL_MSG_PLCA_GET_CFG_REPLY: u8 = 39;
+const ETHTOOL_MSG_PLCA_SET_CFG: u8 = 40;
+const ETHTOOL_MSG_PLCA_GET_STATUS: u8 = 41;
+const ETHTOOL_MSG_PLCA_GET_STATUS_REPLY: u8 = 40;
+const ETHTOOL_MSG_PLCA_NTF: u8 = 41;
+const ETHTOOL_MSG_MM_GET: u8 = 42;
+const ETHTOOL_MSG_MM_GET_REPLY: u8 = 42;
+const ETHTOOL_MSG_MM_SET: u8 = 43;
+const ETHTOOL_MSG_MM_NTF: u8 = 43;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum EthtoolCmd {
     PauseGet,
     PauseGetReply,
+    PauseSet,
     FeatureGet,
     FeatureGetReply,
+    FeatureSet,
+    FeatureSetReply,
     LinkModeGet,
     LinkModeGetReply,
+    LinkModeSet,
     RingGet,
     RingGetReply,
+    RingSet,
     CoalesceGet,
     CoalesceGetReply,
+    CoalesceSet,
     TsInfoGet,
     TsInfoGetReply,
     FecGet,
     FecGetReply,
+    FecSet,
     ChannelGet,
     ChannelGetReply,
     ChannelSet,
     ModuleEEPROMGet,
     ModuleEEPROMGetReply,
+    LinkStateGet,
+    LinkStateGetReply,
+    LinkInfoGet,
+    LinkInfoGetReply,
+    LinkInfoSet,
+    WolGet,
+    WolGetReply,
+    WolSet,
+    EeeGet,
+    EeeGetReply,
+    EeeSet,
+    StatsGet,
+    StatsGetReply,
+    StringSetGet,
+    StringSetGetReply,
+    PrivFlagsGet,
+    PrivFlagsGetReply,
+    PrivFlagsSet,
+    CableTestAct,
+    CableTestNtf,
+    CableTestTdrAct,
+    CableTestTdrNtf,
+    LinkInfoNtf,
+    LinkModeNtf,
+    WolNtf,
+    FeatureNtf,
+    PrivFlagsNtf,
+    RingNtf,
+    ChannelNtf,
+    CoalesceNtf,
+    PauseNtf,
+    EeeNtf,
+    FecNtf,
+    RssGet,
+    RssGetReply,
+    RssSet,
+    RssNtf,
+    RssCreateAct,
+    RssCreateActReply,
+    RssDeleteAct,
+    ModuleGet,
+    ModuleGetReply,
+    ModuleSet,
+    ModuleNtf,
+    ModuleFwFlashAct,
+    ModuleFwFlashNtf,
+    PhcVclocksGet,
+    PhcVclocksGetReply,
+    DebugGet,
+    DebugGetReply,
+    DebugSet,
+    DebugNtf,
+    TunnelInfoGet,
+    TunnelInfoGetReply,
+    PseGet,
+    PseGetReply,
+    PseSet,
+    PlcaGetCfg,
+    PlcaGetCfgReply,
+    PlcaSetCfg,
+    PlcaGetStatus,
+    PlcaGetStatusReply,
+    PlcaNtf,
+    MmGet,
+    MmGetReply,
+    MmSet,
+    MmNtf,
 }
 
 impl From<EthtoolCmd> for u8 {
@@ -64,18 +232,25 @@ impl From<EthtoolCmd> for u8 {
         match cmd {
             EthtoolCmd::PauseGet => ETHTOOL_MSG_PAUSE_GET,
             EthtoolCmd::PauseGetReply => ETHTOOL_MSG_PAUSE_GET_REPLY,
+            EthtoolCmd::PauseSet => ETHTOOL_MSG_PAUSE_SET,
             EthtoolCmd::FeatureGet => ETHTOOL_MSG_FEATURES_GET,
             EthtoolCmd::FeatureGetReply => ETHTOOL_MSG_FEATURES_GET_REPLY,
+            EthtoolCmd::FeatureSet => ETHTOOL_MSG_FEATURES_SET,
+            EthtoolCmd::FeatureSetReply => ETHTOOL_MSG_FEATURES_SET_REPLY,
             EthtoolCmd::LinkModeGet => ETHTOOL_MSG_LINKMODES_GET,
             EthtoolCmd::LinkModeGetReply => ETHTOOL_MSG_LINKMODES_GET_REPLY,
+            EthtoolCmd::LinkModeSet => ETHTOOL_MSG_LINKMODES_SET,
             EthtoolCmd::RingGet => ETHTOOL_MSG_RINGS_GET,
             EthtoolCmd::RingGetReply => ETHTOOL_MSG_RINGS_GET_REPLY,
+            EthtoolCmd::RingSet => ETHTOOL_MSG_RINGS_SET,
             EthtoolCmd::CoalesceGet => ETHTOOL_MSG_COALESCE_GET,
             EthtoolCmd::CoalesceGetReply => ETHTOOL_MSG_COALESCE_GET_REPLY,
+            EthtoolCmd::CoalesceSet => ETHTOOL_MSG_COALESCE_SET,
             EthtoolCmd::TsInfoGet => ETHTOOL_MSG_TSINFO_GET,
             EthtoolCmd::TsInfoGetReply => ETHTOOL_MSG_TSINFO_GET_REPLY,
             EthtoolCmd::FecGet => ETHTOOL_MSG_FEC_GET,
             EthtoolCmd::FecGetReply => ETHTOOL_MSG_FEC_GET_REPLY,
+            EthtoolCmd::FecSet => ETHTOOL_MSG_FEC_SET,
             EthtoolCmd::ChannelGet => ETHTOOL_MSG_CHANNELS_GET,
             EthtoolCmd::ChannelGetReply => ETHTOOL_MSG_CHANNELS_GET_REPLY,
             EthtoolCmd::ChannelSet => ETHTOOL_MSG_CHANNELS_SET,
@@ -83,6 +258,73 @@ impl From<EthtoolCmd> for u8 {
             EthtoolCmd::ModuleEEPROMGetReply => {
                 ETHTOOL_MSG_MODULE_EEPROM_GET_REPLY
             }
+            EthtoolCmd::LinkStateGet => ETHTOOL_MSG_LINKSTATE_GET,
+            EthtoolCmd::LinkStateGetReply => ETHTOOL_MSG_LINKSTATE_GET_REPLY,
+            EthtoolCmd::LinkInfoGet => ETHTOOL_MSG_LINKINFO_GET,
+            EthtoolCmd::LinkInfoGetReply => ETHTOOL_MSG_LINKINFO_GET_REPLY,
+            EthtoolCmd::LinkInfoSet => ETHTOOL_MSG_LINKINFO_SET,
+            EthtoolCmd::WolGet => ETHTOOL_MSG_WOL_GET,
+            EthtoolCmd::WolGetReply => ETHTOOL_MSG_WOL_GET_REPLY,
+            EthtoolCmd::WolSet => ETHTOOL_MSG_WOL_SET,
+            EthtoolCmd::EeeGet => ETHTOOL_MSG_EEE_GET,
+            EthtoolCmd::EeeGetReply => ETHTOOL_MSG_EEE_GET_REPLY,
+            EthtoolCmd::EeeSet => ETHTOOL_MSG_EEE_SET,
+            EthtoolCmd::StatsGet => ETHTOOL_MSG_STATS_GET,
+            EthtoolCmd::StatsGetReply => ETHTOOL_MSG_STATS_GET_REPLY,
+            EthtoolCmd::StringSetGet => ETHTOOL_MSG_STRSET_GET,
+            EthtoolCmd::StringSetGetReply => ETHTOOL_MSG_STRSET_GET_REPLY,
+            EthtoolCmd::PrivFlagsGet => ETHTOOL_MSG_PRIVFLAGS_GET,
+            EthtoolCmd::PrivFlagsGetReply => ETHTOOL_MSG_PRIVFLAGS_GET_REPLY,
+            EthtoolCmd::PrivFlagsSet => ETHTOOL_MSG_PRIVFLAGS_SET,
+            EthtoolCmd::CableTestAct => ETHTOOL_MSG_CABLE_TEST_ACT,
+            EthtoolCmd::CableTestNtf => ETHTOOL_MSG_CABLE_TEST_NTF,
+            EthtoolCmd::CableTestTdrAct => ETHTOOL_MSG_CABLE_TEST_TDR_ACT,
+            EthtoolCmd::CableTestTdrNtf => ETHTOOL_MSG_CABLE_TEST_TDR_NTF,
+            EthtoolCmd::LinkInfoNtf => ETHTOOL_MSG_LINKINFO_NTF,
+            EthtoolCmd::LinkModeNtf => ETHTOOL_MSG_LINKMODES_NTF,
+            EthtoolCmd::WolNtf => ETHTOOL_MSG_WOL_NTF,
+            EthtoolCmd::FeatureNtf => ETHTOOL_MSG_FEATURES_NTF,
+            EthtoolCmd::PrivFlagsNtf => ETHTOOL_MSG_PRIVFLAGS_NTF,
+            EthtoolCmd::RingNtf => ETHTOOL_MSG_RINGS_NTF,
+            EthtoolCmd::ChannelNtf => ETHTOOL_MSG_CHANNELS_NTF,
+            EthtoolCmd::CoalesceNtf => ETHTOOL_MSG_COALESCE_NTF,
+            EthtoolCmd::PauseNtf => ETHTOOL_MSG_PAUSE_NTF,
+            EthtoolCmd::EeeNtf => ETHTOOL_MSG_EEE_NTF,
+            EthtoolCmd::FecNtf => ETHTOOL_MSG_FEC_NTF,
+            EthtoolCmd::RssGet => ETHTOOL_MSG_RSS_GET,
+            EthtoolCmd::RssGetReply => ETHTOOL_MSG_RSS_GET_REPLY,
+            EthtoolCmd::RssSet => ETHTOOL_MSG_RSS_SET,
+            EthtoolCmd::RssNtf => ETHTOOL_MSG_RSS_NTF,
+            EthtoolCmd::RssCreateAct => ETHTOOL_MSG_RSS_CREATE_ACT,
+            EthtoolCmd::RssCreateActReply => ETHTOOL_MSG_RSS_CREATE_ACT_REPLY,
+            EthtoolCmd::RssDeleteAct => ETHTOOL_MSG_RSS_DELETE_ACT,
+            EthtoolCmd::ModuleGet => ETHTOOL_MSG_MODULE_GET,
+            EthtoolCmd::ModuleGetReply => ETHTOOL_MSG_MODULE_GET_REPLY,
+            EthtoolCmd::ModuleSet => ETHTOOL_MSG_MODULE_SET,
+            EthtoolCmd::ModuleNtf => ETHTOOL_MSG_MODULE_NTF,
+            EthtoolCmd::ModuleFwFlashAct => ETHTOOL_MSG_MODULE_FW_FLASH_ACT,
+            EthtoolCmd::ModuleFwFlashNtf => ETHTOOL_MSG_MODULE_FW_FLASH_NTF,
+            EthtoolCmd::PhcVclocksGet => ETHTOOL_MSG_PHC_VCLOCKS_GET,
+            EthtoolCmd::PhcVclocksGetReply => ETHTOOL_MSG_PHC_VCLOCKS_GET_REPLY,
+            EthtoolCmd::DebugGet => ETHTOOL_MSG_DEBUG_GET,
+            EthtoolCmd::DebugGetReply => ETHTOOL_MSG_DEBUG_GET_REPLY,
+            EthtoolCmd::DebugSet => ETHTOOL_MSG_DEBUG_SET,
+            EthtoolCmd::DebugNtf => ETHTOOL_MSG_DEBUG_NTF,
+            EthtoolCmd::TunnelInfoGet => ETHTOOL_MSG_TUNNEL_INFO_GET,
+            EthtoolCmd::TunnelInfoGetReply => ETHTOOL_MSG_TUNNEL_INFO_GET_REPLY,
+            EthtoolCmd::PseGet => ETHTOOL_MSG_PSE_GET,
+            EthtoolCmd::PseGetReply => ETHTOOL_MSG_PSE_GET_REPLY,
+            EthtoolCmd::PseSet => ETHTOOL_MSG_PSE_SET,
+            EthtoolCmd::PlcaGetCfg => ETHTOOL_MSG_PLCA_GET_CFG,
+            EthtoolCmd::PlcaGetCfgReply => ETHTOOL_MSG_PLCA_GET_CFG_REPLY,
+            EthtoolCmd::PlcaSetCfg => ETHTOOL_MSG_PLCA_SET_CFG,
+            EthtoolCmd::PlcaGetStatus => ETHTOOL_MSG_PLCA_GET_STATUS,
+            EthtoolCmd::PlcaGetStatusReply => ETHTOOL_MSG_PLCA_GET_STATUS_REPLY,
+            EthtoolCmd::PlcaNtf => ETHTOOL_MSG_PLCA_NTF,
+            EthtoolCmd::MmGet => ETHTOOL_MSG_MM_GET,
+            EthtoolCmd::MmGetReply => ETHTOOL_MSG_MM_GET_REPLY,
+            EthtoolCmd::MmSet => ETHTOOL_MSG_MM_SET,
+            EthtoolCmd::MmNtf => ETHTOOL_MSG_MM_NTF,
         }
     }
 }
@@ -98,6 +340,24 @@ pub enum EthtoolAttr {
     Fec(EthtoolFecAttr),
     Channel(EthtoolChannelAttr),
     ModuleEEPROM(EthtoolModuleEEPROMAttr),
+    LinkState(EthtoolLinkStateAttr),
+    LinkInfo(EthtoolLinkInfoAttr),
+    Wol(EthtoolWolAttr),
+    Eee(EthtoolEeeAttr),
+    Stats(EthtoolStatsAttr),
+    StringSet(EthtoolStringSetAttr),
+    PrivFlags(EthtoolPrivFlagsAttr),
+    CableTest(EthtoolCableTestAttr),
+    CableTestTdr(EthtoolCableTestTdrAttr),
+    Rss(EthtoolRssAttr),
+    Module(EthtoolModuleAttr),
+    ModuleFwFlash(EthtoolModuleFwFlashAttr),
+    PhcVclocks(EthtoolPhcVclocksAttr),
+    Debug(EthtoolDebugAttr),
+    TunnelInfo(EthtoolTunnelInfoAttr),
+    Pse(EthtoolPseAttr),
+    Plca(EthtoolPlcaAttr),
+    Mm(EthtoolMmAttr),
 }
 
 impl Nla for EthtoolAttr {
@@ -112,6 +372,24 @@ impl Nla for EthtoolAttr {
             Self::Fec(attr) => attr.value_len(),
             Self::Channel(attr) => attr.value_len(),
             Self::ModuleEEPROM(attr) => attr.value_len(),
+            Self::LinkState(attr) => attr.value_len(),
+            Self::LinkInfo(attr) => attr.value_len(),
+            Self::Wol(attr) => attr.value_len(),
+            Self::Eee(attr) => attr.value_len(),
+            Self::Stats(attr) => attr.value_len(),
+            Self::StringSet(attr) => attr.value_len(),
+            Self::PrivFlags(attr) => attr.value_len(),
+            Self::CableTest(attr) => attr.value_len(),
+            Self::CableTestTdr(attr) => attr.value_len(),
+            Self::Rss(attr) => attr.value_len(),
+            Self::Module(attr) => attr.value_len(),
+            Self::ModuleFwFlash(attr) => attr.value_len(),
+            Self::PhcVclocks(attr) => attr.value_len(),
+            Self::Debug(attr) => attr.value_len(),
+            Self::TunnelInfo(attr) => attr.value_len(),
+            Self::Pse(attr) => attr.value_len(),
+            Self::Plca(attr) => attr.value_len(),
+            Self::Mm(attr) => attr.value_len(),
         }
     }
 
@@ -126,6 +404,24 @@ impl Nla for EthtoolAttr {
             Self::Fec(attr) => attr.kind(),
             Self::Channel(attr) => attr.kind(),
             Self::ModuleEEPROM(attr) => attr.kind(),
+            Self::LinkState(attr) => attr.kind(),
+            Self::LinkInfo(attr) => attr.kind(),
+            Self::Wol(attr) => attr.kind(),
+            Self::Eee(attr) => attr.kind(),
+            Self::Stats(attr) => attr.kind(),
+            Self::StringSet(attr) => attr.kind(),
+            Self::PrivFlags(attr) => attr.kind(),
+            Self::CableTest(attr) => attr.kind(),
+            Self::CableTestTdr(attr) => attr.kind(),
+            Self::Rss(attr) => attr.kind(),
+            Self::Module(attr) => attr.kind(),
+            Self::ModuleFwFlash(attr) => attr.kind(),
+            Self::PhcVclocks(attr) => attr.kind(),
+            Self::Debug(attr) => attr.kind(),
+            Self::TunnelInfo(attr) => attr.kind(),
+            Self::Pse(attr) => attr.kind(),
+            Self::Plca(attr) => attr.kind(),
+            Self::Mm(attr) => attr.kind(),
         }
     }
 
@@ -140,6 +436,24 @@ impl Nla for EthtoolAttr {
             Self::Fec(attr) => attr.emit_value(buffer),
             Self::Channel(attr) => attr.emit_value(buffer),
             Self::ModuleEEPROM(attr) => attr.emit_value(buffer),
+            Self::LinkState(attr) => attr.emit_value(buffer),
+            Self::LinkInfo(attr) => attr.emit_value(buffer),
+            Self::Wol(attr) => attr.emit_value(buffer),
+            Self::Eee(attr) => attr.emit_value(buffer),
+            Self::Stats(attr) => attr.emit_value(buffer),
+            Self::StringSet(attr) => attr.emit_value(buffer),
+            Self::PrivFlags(attr) => attr.emit_value(buffer),
+            Self::CableTest(attr) => attr.emit_value(buffer),
+            Self::CableTestTdr(attr) => attr.emit_value(buffer),
+            Self::Rss(attr) => attr.emit_value(buffer),
+            Self::Module(attr) => attr.emit_value(buffer),
+            Self::ModuleFwFlash(attr) => attr.emit_value(buffer),
+            Self::PhcVclocks(attr) => attr.emit_value(buffer),
+            Self::Debug(attr) => attr.emit_value(buffer),
+            Self::TunnelInfo(attr) => attr.emit_value(buffer),
+            Self::Pse(attr) => attr.emit_value(buffer),
+            Self::Plca(attr) => attr.emit_value(buffer),
+            Self::Mm(attr) => attr.emit_value(buffer),
         }
     }
 }
@@ -180,6 +494,17 @@ impl EthtoolMessage {
         }
     }
 
+    pub fn new_pause_set(iface_name: &str) -> Self {
+        let nlas = vec![EthtoolAttr::Pause(EthtoolPauseAttr::Header(vec![
+            EthtoolHeader::DevName(iface_name.to_string()),
+        ]))];
+
+        EthtoolMessage {
+            cmd: EthtoolCmd::PauseSet,
+            nlas,
+        }
+    }
+
     pub fn new_feature_get(iface_name: Option<&str>) -> Self {
         let nlas = match iface_name {
             Some(s) => {
@@ -197,6 +522,18 @@ impl EthtoolMessage {
         }
     }
 
+    pub fn new_feature_set(iface_name: &str) -> Self {
+        let nlas =
+            vec![EthtoolAttr::Feature(EthtoolFeatureAttr::Header(vec![
+                EthtoolHeader::DevName(iface_name.to_string()),
+            ]))];
+
+        EthtoolMessage {
+            cmd: EthtoolCmd::FeatureSet,
+            nlas,
+        }
+    }
+
     pub fn new_link_mode_get(iface_name: Option<&str>) -> Self {
         let nlas = match iface_name {
             Some(s) => {
@@ -214,6 +551,18 @@ impl EthtoolMessage {
         }
     }
 
+    pub fn new_link_mode_set(iface_name: &str) -> Self {
+        let nlas =
+            vec![EthtoolAttr::LinkMode(EthtoolLinkModeAttr::Header(vec![
+                EthtoolHeader::DevName(iface_name.to_string()),
+            ]))];
+
+        EthtoolMessage {
+            cmd: EthtoolCmd::LinkModeSet,
+            nlas,
+        }
+    }
+
     pub fn new_ring_get(iface_name: Option<&str>) -> Self {
         let nlas = match iface_name {
             Some(s) => vec![EthtoolAttr::Ring(EthtoolRingAttr::Header(vec![
@@ -227,6 +576,17 @@ impl EthtoolMessage {
         }
     }
 
+    pub fn new_ring_set(iface_name: &str) -> Self {
+        let nlas = vec![EthtoolAttr::Ring(EthtoolRingAttr::Header(vec![
+            EthtoolHeader::DevName(iface_name.to_string()),
+        ]))];
+
+        EthtoolMessage {
+            cmd: EthtoolCmd::RingSet,
+            nlas,
+        }
+    }
+
     pub fn new_coalesce_get(iface_name: Option<&str>) -> Self {
         let nlas = match iface_name {
             Some(s) => {
@@ -244,6 +604,18 @@ impl EthtoolMessage {
         }
     }
 
+    pub fn new_coalesce_set(iface_name: &str) -> Self {
+        let nlas =
+            vec![EthtoolAttr::Coalesce(EthtoolCoalesceAttr::Header(vec![
+                EthtoolHeader::DevName(iface_name.to_string()),
+            ]))];
+
+        EthtoolMessage {
+            cmd: EthtoolCmd::CoalesceSet,
+            nlas,
+        }
+    }
+
     pub fn new_tsinfo_get(iface_name: Option<&str>) -> Self {
         let nlas = match iface_name {
             Some(s) => {
@@ -278,6 +650,17 @@ impl EthtoolMessage {
         }
     }
 
+    pub fn new_fec_set(iface_name: &str) -> Self {
+        let nlas = vec![EthtoolAttr::Fec(EthtoolFecAttr::Header(vec![
+            EthtoolHeader::DevName(iface_name.to_string()),
+        ]))];
+
+        EthtoolMessage {
+            cmd: EthtoolCmd::FecSet,
+            nlas,
+        }
+    }
+
     pub fn new_channel_get(iface_name: Option<&str>) -> Self {
         let nlas = match iface_name {
             Some(s) => {
@@ -349,6 +732,459 @@ impl EthtoolMessage {
             nlas,
         }
     }
+
+    pub fn new_link_state_get(iface_name: Option<&str>) -> Self {
+        let nlas = match iface_name {
+            Some(s) => {
+                vec![EthtoolAttr::LinkState(EthtoolLinkStateAttr::Header(
+                    vec![EthtoolHeader::DevName(s.to_string())],
+                ))]
+            }
+            None => {
+                vec![EthtoolAttr::LinkState(EthtoolLinkStateAttr::Header(
+                    vec![],
+                ))]
+            }
+        };
+        EthtoolMessage {
+            cmd: EthtoolCmd::LinkStateGet,
+            nlas,
+        }
+    }
+
+    pub fn new_link_info_get(iface_name: Option<&str>) -> Self {
+        let nlas = match iface_name {
+            Some(s) => {
+                vec![EthtoolAttr::LinkInfo(EthtoolLinkInfoAttr::Header(vec![
+                    EthtoolHeader::DevName(s.to_string()),
+                ]))]
+            }
+            None => {
+                vec![EthtoolAttr::LinkInfo(EthtoolLinkInfoAttr::Header(vec![]))]
+            }
+        };
+        EthtoolMessage {
+            cmd: EthtoolCmd::LinkInfoGet,
+            nlas,
+        }
+    }
+
+    pub fn new_link_info_set(iface_name: &str) -> Self {
+        let nlas =
+            vec![EthtoolAttr::LinkInfo(EthtoolLinkInfoAttr::Header(vec![
+                EthtoolHeader::DevName(iface_name.to_string()),
+            ]))];
+
+        EthtoolMessage {
+            cmd: EthtoolCmd::LinkInfoSet,
+            nlas,
+        }
+    }
+
+    pub fn new_wol_get(iface_name: Option<&str>) -> Self {
+        let nlas = match iface_name {
+            Some(s) => {
+                vec![EthtoolAttr::Wol(EthtoolWolAttr::Header(vec![
+                    EthtoolHeader::DevName(s.to_string()),
+                ]))]
+            }
+            None => {
+                vec![EthtoolAttr::Wol(EthtoolWolAttr::Header(vec![]))]
+            }
+        };
+        EthtoolMessage {
+            cmd: EthtoolCmd::WolGet,
+            nlas,
+        }
+    }
+
+    pub fn new_wol_set(iface_name: &str) -> Self {
+        let nlas = vec![EthtoolAttr::Wol(EthtoolWolAttr::Header(vec![
+            EthtoolHeader::DevName(iface_name.to_string()),
+        ]))];
+
+        EthtoolMessage {
+            cmd: EthtoolCmd::WolSet,
+            nlas,
+        }
+    }
+
+    pub fn new_eee_get(iface_name: Option<&str>) -> Self {
+        let nlas = match iface_name {
+            Some(s) => {
+                vec![EthtoolAttr::Eee(EthtoolEeeAttr::Header(vec![
+                    EthtoolHeader::DevName(s.to_string()),
+                ]))]
+            }
+            None => {
+                vec![EthtoolAttr::Eee(EthtoolEeeAttr::Header(vec![]))]
+            }
+        };
+        EthtoolMessage {
+            cmd: EthtoolCmd::EeeGet,
+            nlas,
+        }
+    }
+
+    pub fn new_eee_set(iface_name: &str) -> Self {
+        let nlas = vec![EthtoolAttr::Eee(EthtoolEeeAttr::Header(vec![
+            EthtoolHeader::DevName(iface_name.to_string()),
+        ]))];
+
+        EthtoolMessage {
+            cmd: EthtoolCmd::EeeSet,
+            nlas,
+        }
+    }
+
+    pub fn new_stats_get(
+        iface_name: Option<&str>,
+        groups: &[EthtoolStatsGroupId],
+    ) -> Self {
+        let header = match iface_name {
+            Some(s) => vec![EthtoolHeader::DevName(s.to_string())],
+            None => vec![],
+        };
+        EthtoolMessage {
+            cmd: EthtoolCmd::StatsGet,
+            nlas: vec![
+                EthtoolAttr::Stats(EthtoolStatsAttr::Header(header)),
+                EthtoolAttr::Stats(EthtoolStatsAttr::Groups(groups.to_vec())),
+            ],
+        }
+    }
+
+    pub fn new_strset_get(iface_name: Option<&str>) -> Self {
+        let nlas = match iface_name {
+            Some(s) => {
+                vec![EthtoolAttr::StringSet(EthtoolStringSetAttr::Header(
+                    vec![EthtoolHeader::DevName(s.to_string())],
+                ))]
+            }
+            None => {
+                vec![EthtoolAttr::StringSet(EthtoolStringSetAttr::Header(
+                    vec![],
+                ))]
+            }
+        };
+        EthtoolMessage {
+            cmd: EthtoolCmd::StringSetGet,
+            nlas,
+        }
+    }
+
+    pub fn new_privflags_get(iface_name: Option<&str>) -> Self {
+        let nlas = match iface_name {
+            Some(s) => {
+                vec![EthtoolAttr::PrivFlags(EthtoolPrivFlagsAttr::Header(
+                    vec![EthtoolHeader::DevName(s.to_string())],
+                ))]
+            }
+            None => {
+                vec![EthtoolAttr::PrivFlags(EthtoolPrivFlagsAttr::Header(
+                    vec![],
+                ))]
+            }
+        };
+        EthtoolMessage {
+            cmd: EthtoolCmd::PrivFlagsGet,
+            nlas,
+        }
+    }
+
+    pub fn new_privflags_set(iface_name: &str) -> Self {
+        let nlas =
+            vec![EthtoolAttr::PrivFlags(EthtoolPrivFlagsAttr::Header(vec![
+                EthtoolHeader::DevName(iface_name.to_string()),
+            ]))];
+
+        EthtoolMessage {
+            cmd: EthtoolCmd::PrivFlagsSet,
+            nlas,
+        }
+    }
+
+    pub fn new_cable_test_act(iface_name: &str) -> Self {
+        let nlas =
+            vec![EthtoolAttr::CableTest(EthtoolCableTestAttr::Header(vec![
+                EthtoolHeader::DevName(iface_name.to_string()),
+            ]))];
+
+        EthtoolMessage {
+            cmd: EthtoolCmd::CableTestAct,
+            nlas,
+        }
+    }
+
+    pub fn new_cable_test_tdr_act(iface_name: &str) -> Self {
+        let nlas =
+            vec![EthtoolAttr::CableTestTdr(EthtoolCableTestTdrAttr::Header(
+                vec![EthtoolHeader::DevName(iface_name.to_string())],
+            ))];
+
+        EthtoolMessage {
+            cmd: EthtoolCmd::CableTestTdrAct,
+            nlas,
+        }
+    }
+
+    pub fn new_rss_get(iface_name: Option<&str>) -> Self {
+        let nlas = match iface_name {
+            Some(s) => {
+                vec![EthtoolAttr::Rss(EthtoolRssAttr::Header(vec![
+                    EthtoolHeader::DevName(s.to_string()),
+                ]))]
+            }
+            None => {
+                vec![EthtoolAttr::Rss(EthtoolRssAttr::Header(vec![]))]
+            }
+        };
+        EthtoolMessage {
+            cmd: EthtoolCmd::RssGet,
+            nlas,
+        }
+    }
+
+    pub fn new_rss_set(iface_name: &str) -> Self {
+        let nlas = vec![EthtoolAttr::Rss(EthtoolRssAttr::Header(vec![
+            EthtoolHeader::DevName(iface_name.to_string()),
+        ]))];
+
+        EthtoolMessage {
+            cmd: EthtoolCmd::RssSet,
+            nlas,
+        }
+    }
+
+    pub fn new_rss_create_act(iface_name: &str) -> Self {
+        let nlas = vec![EthtoolAttr::Rss(EthtoolRssAttr::Header(vec![
+            EthtoolHeader::DevName(iface_name.to_string()),
+        ]))];
+
+        EthtoolMessage {
+            cmd: EthtoolCmd::RssCreateAct,
+            nlas,
+        }
+    }
+
+    pub fn new_rss_delete_act(iface_name: &str, context: u32) -> Self {
+        let nlas = vec![
+            EthtoolAttr::Rss(EthtoolRssAttr::Header(vec![
+                EthtoolHeader::DevName(iface_name.to_string()),
+            ])),
+            EthtoolAttr::Rss(EthtoolRssAttr::Context(context)),
+        ];
+
+        EthtoolMessage {
+            cmd: EthtoolCmd::RssDeleteAct,
+            nlas,
+        }
+    }
+
+    pub fn new_module_get(iface_name: Option<&str>) -> Self {
+        let nlas = match iface_name {
+            Some(s) => {
+                vec![EthtoolAttr::Module(EthtoolModuleAttr::Header(vec![
+                    EthtoolHeader::DevName(s.to_string()),
+                ]))]
+            }
+            None => {
+                vec![EthtoolAttr::Module(EthtoolModuleAttr::Header(vec![]))]
+            }
+        };
+        EthtoolMessage {
+            cmd: EthtoolCmd::ModuleGet,
+            nlas,
+        }
+    }
+
+    pub fn new_module_set(iface_name: &str) -> Self {
+        let nlas = vec![EthtoolAttr::Module(EthtoolModuleAttr::Header(vec![
+            EthtoolHeader::DevName(iface_name.to_string()),
+        ]))];
+
+        EthtoolMessage {
+            cmd: EthtoolCmd::ModuleSet,
+            nlas,
+        }
+    }
+
+    pub fn new_module_fw_flash_act(iface_name: &str, file_name: &str) -> Self {
+        let nlas = vec![
+            EthtoolAttr::ModuleFwFlash(EthtoolModuleFwFlashAttr::Header(vec![
+                EthtoolHeader::DevName(iface_name.to_string()),
+            ])),
+            EthtoolAttr::ModuleFwFlash(EthtoolModuleFwFlashAttr::FileName(
+                file_name.to_string(),
+            )),
+        ];
+        EthtoolMessage {
+            cmd: EthtoolCmd::ModuleFwFlashAct,
+            nlas,
+        }
+    }
+
+    pub fn new_phc_vclocks_get(iface_name: Option<&str>) -> Self {
+        let nlas = match iface_name {
+            Some(s) => {
+                vec![EthtoolAttr::PhcVclocks(EthtoolPhcVclocksAttr::Header(
+                    vec![EthtoolHeader::DevName(s.to_string())],
+                ))]
+            }
+            None => {
+                vec![EthtoolAttr::PhcVclocks(EthtoolPhcVclocksAttr::Header(
+                    vec![],
+                ))]
+            }
+        };
+        EthtoolMessage {
+            cmd: EthtoolCmd::PhcVclocksGet,
+            nlas,
+        }
+    }
+
+    pub fn new_debug_get(iface_name: Option<&str>) -> Self {
+        let nlas = match iface_name {
+            Some(s) => {
+                vec![EthtoolAttr::Debug(EthtoolDebugAttr::Header(vec![
+                    EthtoolHeader::DevName(s.to_string()),
+                ]))]
+            }
+            None => {
+                vec![EthtoolAttr::Debug(EthtoolDebugAttr::Header(vec![]))]
+            }
+        };
+        EthtoolMessage {
+            cmd: EthtoolCmd::DebugGet,
+            nlas,
+        }
+    }
+
+    pub fn new_debug_set(iface_name: &str) -> Self {
+        let nlas = vec![EthtoolAttr::Debug(EthtoolDebugAttr::Header(vec![
+            EthtoolHeader::DevName(iface_name.to_string()),
+        ]))];
+
+        EthtoolMessage {
+            cmd: EthtoolCmd::DebugSet,
+            nlas,
+        }
+    }
+
+    pub fn new_tunnel_info_get(iface_name: Option<&str>) -> Self {
+        let nlas = match iface_name {
+            Some(s) => {
+                vec![EthtoolAttr::TunnelInfo(EthtoolTunnelInfoAttr::Header(
+                    vec![EthtoolHeader::DevName(s.to_string())],
+                ))]
+            }
+            None => {
+                vec![EthtoolAttr::TunnelInfo(EthtoolTunnelInfoAttr::Header(
+                    vec![],
+                ))]
+            }
+        };
+        EthtoolMessage {
+            cmd: EthtoolCmd::TunnelInfoGet,
+            nlas,
+        }
+    }
+
+    pub fn new_pse_get(iface_name: Option<&str>) -> Self {
+        let nlas = match iface_name {
+            Some(s) => {
+                vec![EthtoolAttr::Pse(EthtoolPseAttr::Header(vec![
+                    EthtoolHeader::DevName(s.to_string()),
+                ]))]
+            }
+            None => {
+                vec![EthtoolAttr::Pse(EthtoolPseAttr::Header(vec![]))]
+            }
+        };
+        EthtoolMessage {
+            cmd: EthtoolCmd::PseGet,
+            nlas,
+        }
+    }
+
+    pub fn new_pse_set(iface_name: &str) -> Self {
+        let nlas = vec![EthtoolAttr::Pse(EthtoolPseAttr::Header(vec![
+            EthtoolHeader::DevName(iface_name.to_string()),
+        ]))];
+
+        EthtoolMessage {
+            cmd: EthtoolCmd::PseSet,
+            nlas,
+        }
+    }
+
+    pub fn new_plca_get_cfg(iface_name: Option<&str>) -> Self {
+        let nlas = match iface_name {
+            Some(s) => {
+                vec![EthtoolAttr::Plca(EthtoolPlcaAttr::Header(vec![
+                    EthtoolHeader::DevName(s.to_string()),
+                ]))]
+            }
+            None => vec![EthtoolAttr::Plca(EthtoolPlcaAttr::Header(vec![]))],
+        };
+        EthtoolMessage {
+            cmd: EthtoolCmd::PlcaGetCfg,
+            nlas,
+        }
+    }
+
+    pub fn new_plca_get_status(iface_name: Option<&str>) -> Self {
+        let nlas = match iface_name {
+            Some(s) => {
+                vec![EthtoolAttr::Plca(EthtoolPlcaAttr::Header(vec![
+                    EthtoolHeader::DevName(s.to_string()),
+                ]))]
+            }
+            None => vec![EthtoolAttr::Plca(EthtoolPlcaAttr::Header(vec![]))],
+        };
+        EthtoolMessage {
+            cmd: EthtoolCmd::PlcaGetStatus,
+            nlas,
+        }
+    }
+
+    pub fn new_plca_set_cfg(iface_name: &str) -> Self {
+        let nlas = vec![EthtoolAttr::Plca(EthtoolPlcaAttr::Header(vec![
+            EthtoolHeader::DevName(iface_name.to_string()),
+        ]))];
+        EthtoolMessage {
+            cmd: EthtoolCmd::PlcaSetCfg,
+            nlas,
+        }
+    }
+
+    pub fn new_mm_get(iface_name: Option<&str>) -> Self {
+        let nlas = match iface_name {
+            Some(s) => {
+                vec![EthtoolAttr::Mm(EthtoolMmAttr::Header(vec![
+                    EthtoolHeader::DevName(s.to_string()),
+                ]))]
+            }
+            None => {
+                vec![EthtoolAttr::Mm(EthtoolMmAttr::Header(vec![]))]
+            }
+        };
+        EthtoolMessage {
+            cmd: EthtoolCmd::MmGet,
+            nlas,
+        }
+    }
+
+    pub fn new_mm_set(iface_name: &str) -> Self {
+        let nlas = vec![EthtoolAttr::Mm(EthtoolMmAttr::Header(vec![
+            EthtoolHeader::DevName(iface_name.to_string()),
+        ]))];
+
+        EthtoolMessage {
+            cmd: EthtoolCmd::MmSet,
+            nlas,
+        }
+    }
 }
 
 impl Emitable for EthtoolMessage {
@@ -361,6 +1197,33 @@ impl Emitable for EthtoolMessage {
     }
 }
 
+// Notifications parsed by `EthtoolMessage::parse_with_param()`, others are
+// unknown to this crate.
+pub(crate) fn is_ntf_supported(cmd: u8) -> bool {
+    matches!(
+        cmd,
+        ETHTOOL_MSG_CABLE_TEST_NTF
+            | ETHTOOL_MSG_CABLE_TEST_TDR_NTF
+            | ETHTOOL_MSG_LINKINFO_NTF
+            | ETHTOOL_MSG_LINKMODES_NTF
+            | ETHTOOL_MSG_WOL_NTF
+            | ETHTOOL_MSG_FEATURES_NTF
+            | ETHTOOL_MSG_PRIVFLAGS_NTF
+            | ETHTOOL_MSG_RINGS_NTF
+            | ETHTOOL_MSG_CHANNELS_NTF
+            | ETHTOOL_MSG_COALESCE_NTF
+            | ETHTOOL_MSG_PAUSE_NTF
+            | ETHTOOL_MSG_EEE_NTF
+            | ETHTOOL_MSG_FEC_NTF
+            | ETHTOOL_MSG_RSS_NTF
+            | ETHTOOL_MSG_MODULE_NTF
+            | ETHTOOL_MSG_MODULE_FW_FLASH_NTF
+            | ETHTOOL_MSG_DEBUG_NTF
+            | ETHTOOL_MSG_PLCA_NTF
+            | ETHTOOL_MSG_MM_NTF
+    )
+}
+
 impl ParseableParametrized<[u8], GenlHeader> for EthtoolMessage {
     fn parse_with_param(
         buffer: &[u8],
@@ -375,6 +1238,10 @@ impl ParseableParametrized<[u8], GenlHeader> for EthtoolMessage {
                 cmd: EthtoolCmd::FeatureGetReply,
                 nlas: parse_feature_nlas(buffer)?,
             },
+            ETHTOOL_MSG_FEATURES_SET_REPLY => Self {
+                cmd: EthtoolCmd::FeatureSetReply,
+                nlas: parse_feature_nlas(buffer)?,
+            },
             ETHTOOL_MSG_LINKMODES_GET_REPLY => Self {
                 cmd: EthtoolCmd::LinkModeGetReply,
                 nlas: parse_link_mode_nlas(buffer)?,
@@ -403,6 +1270,150 @@ impl ParseableParametrized<[u8], GenlHeader> for EthtoolMessage {
                 cmd: EthtoolCmd::ModuleEEPROMGetReply,
                 nlas: parse_module_eeprom_nlas(buffer)?,
             },
+            ETHTOOL_MSG_LINKSTATE_GET_REPLY => Self {
+                cmd: EthtoolCmd::LinkStateGetReply,
+                nlas: parse_link_state_nlas(buffer)?,
+            },
+            ETHTOOL_MSG_LINKINFO_GET_REPLY => Self {
+                cmd: EthtoolCmd::LinkInfoGetReply,
+                nlas: parse_link_info_nlas(buffer)?,
+            },
+            ETHTOOL_MSG_WOL_GET_REPLY => Self {
+                cmd: EthtoolCmd::WolGetReply,
+                nlas: parse_wol_nlas(buffer)?,
+            },
+            ETHTOOL_MSG_EEE_GET_REPLY => Self {
+                cmd: EthtoolCmd::EeeGetReply,
+                nlas: parse_eee_nlas(buffer)?,
+            },
+            ETHTOOL_MSG_STATS_GET_REPLY => Self {
+                cmd: EthtoolCmd::StatsGetReply,
+                nlas: parse_stats_nlas(buffer)?,
+            },
+            ETHTOOL_MSG_STRSET_GET_REPLY => Self {
+                cmd: EthtoolCmd::StringSetGetReply,
+                nlas: parse_strset_nlas(buffer)?,
+            },
+            ETHTOOL_MSG_PRIVFLAGS_GET_REPLY => Self {
+                cmd: EthtoolCmd::PrivFlagsGetReply,
+                nlas: parse_privflags_nlas(buffer)?,
+            },
+            ETHTOOL_MSG_CABLE_TEST_NTF => Self {
+                cmd: EthtoolCmd::CableTestNtf,
+                nlas: parse_cable_test_nlas(buffer)?,
+            },
+            ETHTOOL_MSG_CABLE_TEST_TDR_NTF => Self {
+                cmd: EthtoolCmd::CableTestTdrNtf,
+                nlas: parse_cable_test_tdr_nlas(buffer)?,
+            },
+            ETHTOOL_MSG_LINKINFO_NTF => Self {
+                cmd: EthtoolCmd::LinkInfoNtf,
+                nlas: parse_link_info_nlas(buffer)?,
+            },
+            ETHTOOL_MSG_LINKMODES_NTF => Self {
+                cmd: EthtoolCmd::LinkModeNtf,
+                nlas: parse_link_mode_nlas(buffer)?,
+            },
+            ETHTOOL_MSG_WOL_NTF => Self {
+                cmd: EthtoolCmd::WolNtf,
+                nlas: parse_wol_nlas(buffer)?,
+            },
+            ETHTOOL_MSG_FEATURES_NTF => Self {
+                cmd: EthtoolCmd::FeatureNtf,
+                nlas: parse_feature_nlas(buffer)?,
+            },
+            ETHTOOL_MSG_PRIVFLAGS_NTF => Self {
+                cmd: EthtoolCmd::PrivFlagsNtf,
+                nlas: parse_privflags_nlas(buffer)?,
+            },
+            ETHTOOL_MSG_RINGS_NTF => Self {
+                cmd: EthtoolCmd::RingNtf,
+                nlas: parse_ring_nlas(buffer)?,
+            },
+            ETHTOOL_MSG_CHANNELS_NTF => Self {
+                cmd: EthtoolCmd::ChannelNtf,
+                nlas: parse_channel_nlas(buffer)?,
+            },
+            ETHTOOL_MSG_COALESCE_NTF => Self {
+                cmd: EthtoolCmd::CoalesceNtf,
+                nlas: parse_coalesce_nlas(buffer)?,
+            },
+            ETHTOOL_MSG_PAUSE_NTF => Self {
+                cmd: EthtoolCmd::PauseNtf,
+                nlas: parse_pause_nlas(buffer)?,
+            },
+            ETHTOOL_MSG_EEE_NTF => Self {
+                cmd: EthtoolCmd::EeeNtf,
+                nlas: parse_eee_nlas(buffer)?,
+            },
+            ETHTOOL_MSG_FEC_NTF => Self {
+                cmd: EthtoolCmd::FecNtf,
+                nlas: parse_fec_nlas(buffer)?,
+            },
+            ETHTOOL_MSG_RSS_GET_REPLY => Self {
+                cmd: EthtoolCmd::RssGetReply,
+                nlas: parse_rss_nlas(buffer)?,
+            },
+            ETHTOOL_MSG_RSS_NTF => Self {
+                cmd: EthtoolCmd::RssNtf,
+                nlas: parse_rss_nlas(buffer)?,
+            },
+            ETHTOOL_MSG_RSS_CREATE_ACT_REPLY => Self {
+                cmd: EthtoolCmd::RssCreateActReply,
+                nlas: parse_rss_nlas(buffer)?,
+            },
+            ETHTOOL_MSG_MODULE_GET_REPLY => Self {
+                cmd: EthtoolCmd::ModuleGetReply,
+                nlas: parse_module_nlas(buffer)?,
+            },
+            ETHTOOL_MSG_MODULE_NTF => Self {
+                cmd: EthtoolCmd::ModuleNtf,
+                nlas: parse_module_nlas(buffer)?,
+            },
+            ETHTOOL_MSG_MODULE_FW_FLASH_NTF => Self {
+                cmd: EthtoolCmd::ModuleFwFlashNtf,
+                nlas: parse_module_fw_flash_nlas(buffer)?,
+            },
+            ETHTOOL_MSG_PHC_VCLOCKS_GET_REPLY => Self {
+                cmd: EthtoolCmd::PhcVclocksGetReply,
+                nlas: parse_phc_vclocks_nlas(buffer)?,
+            },
+            ETHTOOL_MSG_DEBUG_GET_REPLY => Self {
+                cmd: EthtoolCmd::DebugGetReply,
+                nlas: parse_debug_nlas(buffer)?,
+            },
+            ETHTOOL_MSG_DEBUG_NTF => Self {
+                cmd: EthtoolCmd::DebugNtf,
+                nlas: parse_debug_nlas(buffer)?,
+            },
+            ETHTOOL_MSG_TUNNEL_INFO_GET_REPLY => Self {
+                cmd: EthtoolCmd::TunnelInfoGetReply,
+                nlas: parse_tunnel_info_nlas(buffer)?,
+            },
+            ETHTOOL_MSG_PSE_GET_REPLY => Self {
+                cmd: EthtoolCmd::PseGetReply,
+                nlas: parse_pse_nlas(buffer)?,
+            },
+            ETHTOOL_MSG_PLCA_GET_CFG_REPLY => Self {
+                cmd: EthtoolCmd::PlcaGetCfgReply,
+                nlas: parse_plca_nlas(buffer)?,
+            },
+            ETHTOOL_MSG_PLCA_GET_STATUS_REPLY => Self {
+                cmd: EthtoolCmd::PlcaGetStatusReply,
+                nlas: parse_plca_nlas(buffer)?,
+            },
+            ETHTOOL_MSG_PLCA_NTF => Self {
+                cmd: EthtoolCmd::PlcaNtf,
+                nlas: parse_plca_nlas(buffer)?,
+            },
+            ETHTOOL_MSG_MM_GET_REPLY => Self {
+                cmd: EthtoolCmd::MmGetReply,
+                nlas: parse_mm_nlas(buffer)?,
+            },
+            ETHTOOL_MSG_MM_NTF => Self {
+                cmd: EthtoolCmd::MmNtf,
+                nlas: parse_mm_nlas(buffer)?,
+            },
             cmd => {
                 return Err(DecodeError::from(format!(
                     "Unsupported ethtool reply command: {cmd}"
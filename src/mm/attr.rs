@@ -0,0 +1,356 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32, emit_u64, parse_u32, parse_u64, parse_u8, DecodeError,
+    DefaultNla, Emitable, ErrorContext, Nla, NlaBuffer, NlasIterator,
+    Parseable, NLA_F_NESTED,
+};
+
+use crate::{EthtoolAttr, EthtoolHeader};
+
+const ETHTOOL_A_MM_HEADER: u16 = 1;
+const ETHTOOL_A_MM_PMAC_ENABLED: u16 = 2;
+const ETHTOOL_A_MM_TX_ENABLED: u16 = 3;
+const ETHTOOL_A_MM_TX_ACTIVE: u16 = 4;
+const ETHTOOL_A_MM_TX_MIN_FRAG_SIZE: u16 = 5;
+const ETHTOOL_A_MM_RX_MIN_FRAG_SIZE: u16 = 6;
+const ETHTOOL_A_MM_VERIFY_ENABLED: u16 = 7;
+const ETHTOOL_A_MM_VERIFY_STATUS: u16 = 8;
+const ETHTOOL_A_MM_VERIFY_TIME: u16 = 9;
+const ETHTOOL_A_MM_MAX_VERIFY_TIME: u16 = 10;
+const ETHTOOL_A_MM_STATS: u16 = 11;
+
+const ETHTOOL_A_MM_STAT_REASSEMBLY_ERRORS: u16 = 2;
+const ETHTOOL_A_MM_STAT_SMD_ERRORS: u16 = 3;
+const ETHTOOL_A_MM_STAT_REASSEMBLY_OK: u16 = 4;
+const ETHTOOL_A_MM_STAT_RX_FRAG_COUNT: u16 = 5;
+const ETHTOOL_A_MM_STAT_TX_FRAG_COUNT: u16 = 6;
+const ETHTOOL_A_MM_STAT_HOLD_COUNT: u16 = 7;
+
+const ETHTOOL_MM_VERIFY_STATUS_UNKNOWN: u8 = 0;
+const ETHTOOL_MM_VERIFY_STATUS_INITIAL: u8 = 1;
+const ETHTOOL_MM_VERIFY_STATUS_VERIFYING: u8 = 2;
+const ETHTOOL_MM_VERIFY_STATUS_SUCCEEDED: u8 = 3;
+const ETHTOOL_MM_VERIFY_STATUS_FAILED: u8 = 4;
+const ETHTOOL_MM_VERIFY_STATUS_DISABLED: u8 = 5;
+
+/// Status of the verification of link partner MAC Merge support
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EthtoolMmVerifyStatus {
+    Unknown,
+    /// Verification not started yet
+    Initial,
+    Verifying,
+    Succeeded,
+    Failed,
+    /// Verification disabled, preemption is used without it
+    Disabled,
+    Other(u8),
+}
+
+impl std::fmt::Display for EthtoolMmVerifyStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unknown => write!(f, "unknown"),
+            Self::Initial => write!(f, "initial"),
+            Self::Verifying => write!(f, "verifying"),
+            Self::Succeeded => write!(f, "succeeded"),
+            Self::Failed => write!(f, "failed"),
+            Self::Disabled => write!(f, "disabled"),
+            Self::Other(d) => write!(f, "{d}"),
+        }
+    }
+}
+
+impl From<u8> for EthtoolMmVerifyStatus {
+    fn from(d: u8) -> Self {
+        match d {
+            ETHTOOL_MM_VERIFY_STATUS_UNKNOWN => Self::Unknown,
+            ETHTOOL_MM_VERIFY_STATUS_INITIAL => Self::Initial,
+            ETHTOOL_MM_VERIFY_STATUS_VERIFYING => Self::Verifying,
+            ETHTOOL_MM_VERIFY_STATUS_SUCCEEDED => Self::Succeeded,
+            ETHTOOL_MM_VERIFY_STATUS_FAILED => Self::Failed,
+            ETHTOOL_MM_VERIFY_STATUS_DISABLED => Self::Disabled,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<EthtoolMmVerifyStatus> for u8 {
+    fn from(v: EthtoolMmVerifyStatus) -> u8 {
+        match v {
+            EthtoolMmVerifyStatus::Unknown => ETHTOOL_MM_VERIFY_STATUS_UNKNOWN,
+            EthtoolMmVerifyStatus::Initial => ETHTOOL_MM_VERIFY_STATUS_INITIAL,
+            EthtoolMmVerifyStatus::Verifying => {
+                ETHTOOL_MM_VERIFY_STATUS_VERIFYING
+            }
+            EthtoolMmVerifyStatus::Succeeded => {
+                ETHTOOL_MM_VERIFY_STATUS_SUCCEEDED
+            }
+            EthtoolMmVerifyStatus::Failed => ETHTOOL_MM_VERIFY_STATUS_FAILED,
+            EthtoolMmVerifyStatus::Disabled => {
+                ETHTOOL_MM_VERIFY_STATUS_DISABLED
+            }
+            EthtoolMmVerifyStatus::Other(d) => d,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum EthtoolMmStatAttr {
+    /// Received frames with reassembly errors
+    ReassemblyErrors(u64),
+    /// Received frames with unknown SMD (Start of mPacket Delimiter)
+    SmdErrors(u64),
+    /// Received frames successfully reassembled
+    ReassemblyOk(u64),
+    /// Received additional mPackets (fragments)
+    RxFragCount(u64),
+    /// Transmitted additional mPackets (fragments)
+    TxFragCount(u64),
+    /// Times the preemptible MAC was held
+    HoldCount(u64),
+    Other(DefaultNla),
+}
+
+impl Nla for EthtoolMmStatAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::ReassemblyErrors(_)
+            | Self::SmdErrors(_)
+            | Self::ReassemblyOk(_)
+            | Self::RxFragCount(_)
+            | Self::TxFragCount(_)
+            | Self::HoldCount(_) => 8,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::ReassemblyErrors(_) => ETHTOOL_A_MM_STAT_REASSEMBLY_ERRORS,
+            Self::SmdErrors(_) => ETHTOOL_A_MM_STAT_SMD_ERRORS,
+            Self::ReassemblyOk(_) => ETHTOOL_A_MM_STAT_REASSEMBLY_OK,
+            Self::RxFragCount(_) => ETHTOOL_A_MM_STAT_RX_FRAG_COUNT,
+            Self::TxFragCount(_) => ETHTOOL_A_MM_STAT_TX_FRAG_COUNT,
+            Self::HoldCount(_) => ETHTOOL_A_MM_STAT_HOLD_COUNT,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::ReassemblyErrors(value)
+            | Self::SmdErrors(value)
+            | Self::ReassemblyOk(value)
+            | Self::RxFragCount(value)
+            | Self::TxFragCount(value)
+            | Self::HoldCount(value) => emit_u64(buffer, *value).unwrap(),
+            Self::Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for EthtoolMmStatAttr
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            ETHTOOL_A_MM_STAT_REASSEMBLY_ERRORS => {
+                Self::ReassemblyErrors(parse_u64(payload).context(
+                    "Invalid ETHTOOL_A_MM_STAT_REASSEMBLY_ERRORS value",
+                )?)
+            }
+            ETHTOOL_A_MM_STAT_SMD_ERRORS => Self::SmdErrors(
+                parse_u64(payload)
+                    .context("Invalid ETHTOOL_A_MM_STAT_SMD_ERRORS value")?,
+            ),
+            ETHTOOL_A_MM_STAT_REASSEMBLY_OK => Self::ReassemblyOk(
+                parse_u64(payload)
+                    .context("Invalid ETHTOOL_A_MM_STAT_REASSEMBLY_OK value")?,
+            ),
+            ETHTOOL_A_MM_STAT_RX_FRAG_COUNT => Self::RxFragCount(
+                parse_u64(payload)
+                    .context("Invalid ETHTOOL_A_MM_STAT_RX_FRAG_COUNT value")?,
+            ),
+            ETHTOOL_A_MM_STAT_TX_FRAG_COUNT => Self::TxFragCount(
+                parse_u64(payload)
+                    .context("Invalid ETHTOOL_A_MM_STAT_TX_FRAG_COUNT value")?,
+            ),
+            ETHTOOL_A_MM_STAT_HOLD_COUNT => Self::HoldCount(
+                parse_u64(payload)
+                    .context("Invalid ETHTOOL_A_MM_STAT_HOLD_COUNT value")?,
+            ),
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("invalid ethtool mm NLA kind {kind}"))?,
+            ),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum EthtoolMmAttr {
+    Header(Vec<EthtoolHeader>),
+    /// Whether the preemptible MAC is enabled to receive
+    PmacEnabled(bool),
+    /// Whether transmission of preemptible frames is enabled
+    TxEnabled(bool),
+    /// Whether preemptible frames are being transmitted
+    TxActive(bool),
+    /// Minimum size of transmitted non-final fragments, in octets
+    TxMinFragSize(u32),
+    RxMinFragSize(u32),
+    VerifyEnabled(bool),
+    VerifyStatus(EthtoolMmVerifyStatus),
+    /// Verification retry interval, in milliseconds
+    VerifyTime(u32),
+    MaxVerifyTime(u32),
+    Stats(Vec<EthtoolMmStatAttr>),
+    Other(DefaultNla),
+}
+
+impl Nla for EthtoolMmAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Header(hdrs) => hdrs.as_slice().buffer_len(),
+            Self::PmacEnabled(_)
+            | Self::TxEnabled(_)
+            | Self::TxActive(_)
+            | Self::VerifyEnabled(_)
+            | Self::VerifyStatus(_) => 1,
+            Self::TxMinFragSize(_)
+            | Self::RxMinFragSize(_)
+            | Self::VerifyTime(_)
+            | Self::MaxVerifyTime(_) => 4,
+            Self::Stats(ref nlas) => nlas.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Header(_) => ETHTOOL_A_MM_HEADER | NLA_F_NESTED,
+            Self::PmacEnabled(_) => ETHTOOL_A_MM_PMAC_ENABLED,
+            Self::TxEnabled(_) => ETHTOOL_A_MM_TX_ENABLED,
+            Self::TxActive(_) => ETHTOOL_A_MM_TX_ACTIVE,
+            Self::TxMinFragSize(_) => ETHTOOL_A_MM_TX_MIN_FRAG_SIZE,
+            Self::RxMinFragSize(_) => ETHTOOL_A_MM_RX_MIN_FRAG_SIZE,
+            Self::VerifyEnabled(_) => ETHTOOL_A_MM_VERIFY_ENABLED,
+            Self::VerifyStatus(_) => ETHTOOL_A_MM_VERIFY_STATUS,
+            Self::VerifyTime(_) => ETHTOOL_A_MM_VERIFY_TIME,
+            Self::MaxVerifyTime(_) => ETHTOOL_A_MM_MAX_VERIFY_TIME,
+            Self::Stats(_) => ETHTOOL_A_MM_STATS | NLA_F_NESTED,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Header(ref nlas) => nlas.as_slice().emit(buffer),
+            Self::PmacEnabled(d)
+            | Self::TxEnabled(d)
+            | Self::TxActive(d)
+            | Self::VerifyEnabled(d) => buffer[0] = *d as u8,
+            Self::VerifyStatus(d) => buffer[0] = (*d).into(),
+            Self::TxMinFragSize(d)
+            | Self::RxMinFragSize(d)
+            | Self::VerifyTime(d)
+            | Self::MaxVerifyTime(d) => emit_u32(buffer, *d).unwrap(),
+            Self::Stats(ref nlas) => nlas.as_slice().emit(buffer),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for EthtoolMmAttr
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            ETHTOOL_A_MM_HEADER => {
+                let mut nlas = Vec::new();
+                let error_msg = "failed to parse mm header attributes";
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    let parsed =
+                        EthtoolHeader::parse(nla).context(error_msg)?;
+                    nlas.push(parsed);
+                }
+                Self::Header(nlas)
+            }
+            ETHTOOL_A_MM_PMAC_ENABLED => Self::PmacEnabled(
+                parse_u8(payload)
+                    .context("Invalid ETHTOOL_A_MM_PMAC_ENABLED value")?
+                    > 0,
+            ),
+            ETHTOOL_A_MM_TX_ENABLED => Self::TxEnabled(
+                parse_u8(payload)
+                    .context("Invalid ETHTOOL_A_MM_TX_ENABLED value")?
+                    > 0,
+            ),
+            ETHTOOL_A_MM_TX_ACTIVE => Self::TxActive(
+                parse_u8(payload)
+                    .context("Invalid ETHTOOL_A_MM_TX_ACTIVE value")?
+                    > 0,
+            ),
+            ETHTOOL_A_MM_TX_MIN_FRAG_SIZE => Self::TxMinFragSize(
+                parse_u32(payload)
+                    .context("Invalid ETHTOOL_A_MM_TX_MIN_FRAG_SIZE value")?,
+            ),
+            ETHTOOL_A_MM_RX_MIN_FRAG_SIZE => Self::RxMinFragSize(
+                parse_u32(payload)
+                    .context("Invalid ETHTOOL_A_MM_RX_MIN_FRAG_SIZE value")?,
+            ),
+            ETHTOOL_A_MM_VERIFY_ENABLED => Self::VerifyEnabled(
+                parse_u8(payload)
+                    .context("Invalid ETHTOOL_A_MM_VERIFY_ENABLED value")?
+                    > 0,
+            ),
+            ETHTOOL_A_MM_VERIFY_STATUS => Self::VerifyStatus(
+                parse_u8(payload)
+                    .context("Invalid ETHTOOL_A_MM_VERIFY_STATUS value")?
+                    .into(),
+            ),
+            ETHTOOL_A_MM_VERIFY_TIME => Self::VerifyTime(
+                parse_u32(payload)
+                    .context("Invalid ETHTOOL_A_MM_VERIFY_TIME value")?,
+            ),
+            ETHTOOL_A_MM_MAX_VERIFY_TIME => Self::MaxVerifyTime(
+                parse_u32(payload)
+                    .context("Invalid ETHTOOL_A_MM_MAX_VERIFY_TIME value")?,
+            ),
+            ETHTOOL_A_MM_STATS => {
+                let mut nlas = Vec::new();
+                let error_msg = "failed to parse mm stats attributes";
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    let parsed =
+                        EthtoolMmStatAttr::parse(nla).context(error_msg)?;
+                    nlas.push(parsed);
+                }
+                Self::Stats(nlas)
+            }
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("invalid ethtool mm NLA kind {kind}"))?,
+            ),
+        })
+    }
+}
+
+pub(crate) fn parse_mm_nlas(
+    buffer: &[u8],
+) -> Result<Vec<EthtoolAttr>, DecodeError> {
+    let mut nlas = Vec::new();
+    for nla in NlasIterator::new(buffer) {
+        let error_msg =
+            format!("Failed to parse ethtool mm message attribute {nla:?}");
+        let nla = &nla.context(error_msg.clone())?;
+        let parsed = EthtoolMmAttr::parse(nla).context(error_msg)?;
+        nlas.push(EthtoolAttr::Mm(parsed));
+    }
+    Ok(nlas)
+}
@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: MIT
+
+use futures_util::Stream;
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    ethtool_execute, EthtoolAttr, EthtoolError, EthtoolHandle, EthtoolHeader,
+    EthtoolMessage, EthtoolMmAttr,
+};
+
+const ETHTOOL_FLAG_STATS: u32 = 1 << 2;
+
+pub struct EthtoolMmGetRequest {
+    handle: EthtoolHandle,
+    iface_name: Option<String>,
+    stats: bool,
+}
+
+impl EthtoolMmGetRequest {
+    pub(crate) fn new(handle: EthtoolHandle, iface_name: Option<&str>) -> Self {
+        EthtoolMmGetRequest {
+            handle,
+            iface_name: iface_name.map(|i| i.to_string()),
+            stats: false,
+        }
+    }
+
+    /// Include the MAC Merge statistics in reply
+    pub fn stats(mut self) -> Self {
+        self.stats = true;
+        self
+    }
+
+    pub async fn execute(
+        self,
+    ) -> Result<
+        impl Stream<Item = Result<GenlMessage<EthtoolMessage>, EthtoolError>>,
+        EthtoolError,
+    > {
+        let EthtoolMmGetRequest {
+            mut handle,
+            iface_name,
+            stats,
+        } = self;
+
+        let mut ethtool_msg = EthtoolMessage::new_mm_get(iface_name.as_deref());
+        if stats {
+            for nla in ethtool_msg.nlas.iter_mut() {
+                if let EthtoolAttr::Mm(EthtoolMmAttr::Header(hdrs)) = nla {
+                    hdrs.push(EthtoolHeader::Flags(ETHTOOL_FLAG_STATS));
+                }
+            }
+        }
+        ethtool_execute(&mut handle, iface_name.is_none(), ethtool_msg).await
+    }
+}
@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{EthtoolHandle, EthtoolMmGetRequest, EthtoolMmSetRequest};
+
+pub struct EthtoolMmHandle(EthtoolHandle);
+
+impl EthtoolMmHandle {
+    pub fn new(handle: EthtoolHandle) -> Self {
+        EthtoolMmHandle(handle)
+    }
+
+    /// Retrieve the MAC Merge (IEEE 802.3br frame preemption) state of a
+    /// interface (used by `ethtool --show-mm eth1`)
+    pub fn get(&mut self, iface_name: Option<&str>) -> EthtoolMmGetRequest {
+        EthtoolMmGetRequest::new(self.0.clone(), iface_name)
+    }
+
+    /// Change the MAC Merge settings of a interface (used by
+    /// `ethtool --set-mm eth1 tx-enabled on`)
+    pub fn set(&mut self, iface_name: &str) -> EthtoolMmSetRequest {
+        EthtoolMmSetRequest::new(self.0.clone(), iface_name)
+    }
+}
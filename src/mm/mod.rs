@@ -0,0 +1,13 @@
+// SPDX-License-Identifier: MIT
+
+mod attr;
+mod get;
+mod handle;
+mod set;
+
+pub(crate) use attr::parse_mm_nlas;
+
+pub use attr::{EthtoolMmAttr, EthtoolMmStatAttr, EthtoolMmVerifyStatus};
+pub use get::EthtoolMmGetRequest;
+pub use handle::EthtoolMmHandle;
+pub use set::EthtoolMmSetRequest;
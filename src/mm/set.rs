@@ -0,0 +1,110 @@
+// SPDX-License-Identifier: MIT
+
+use futures_util::StreamExt;
+use netlink_packet_core::{NetlinkMessage, NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    try_ethtool, EthtoolAttr, EthtoolError, EthtoolHandle, EthtoolMessage,
+    EthtoolMmAttr,
+};
+
+pub struct EthtoolMmSetRequest {
+    handle: EthtoolHandle,
+    message: EthtoolMessage,
+    pmac_enabled: Option<bool>,
+    tx_enabled: Option<bool>,
+    tx_min_frag_size: Option<u32>,
+    verify_enabled: Option<bool>,
+    verify_time: Option<u32>,
+}
+
+impl EthtoolMmSetRequest {
+    pub(crate) fn new(handle: EthtoolHandle, iface_name: &str) -> Self {
+        EthtoolMmSetRequest {
+            handle,
+            message: EthtoolMessage::new_mm_set(iface_name),
+            pmac_enabled: None,
+            tx_enabled: None,
+            tx_min_frag_size: None,
+            verify_enabled: None,
+            verify_time: None,
+        }
+    }
+
+    pub fn pmac_enabled(mut self, value: bool) -> Self {
+        self.pmac_enabled = Some(value);
+        self
+    }
+
+    pub fn tx_enabled(mut self, value: bool) -> Self {
+        self.tx_enabled = Some(value);
+        self
+    }
+
+    pub fn tx_min_frag_size(mut self, value: u32) -> Self {
+        self.tx_min_frag_size = Some(value);
+        self
+    }
+
+    pub fn verify_enabled(mut self, value: bool) -> Self {
+        self.verify_enabled = Some(value);
+        self
+    }
+
+    pub fn verify_time(mut self, value: u32) -> Self {
+        self.verify_time = Some(value);
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), EthtoolError> {
+        let EthtoolMmSetRequest {
+            mut handle,
+            mut message,
+            pmac_enabled,
+            tx_enabled,
+            tx_min_frag_size,
+            verify_enabled,
+            verify_time,
+        } = self;
+
+        if let Some(value) = pmac_enabled {
+            message
+                .nlas
+                .push(EthtoolAttr::Mm(EthtoolMmAttr::PmacEnabled(value)));
+        }
+        if let Some(value) = tx_enabled {
+            message
+                .nlas
+                .push(EthtoolAttr::Mm(EthtoolMmAttr::TxEnabled(value)));
+        }
+        if let Some(value) = tx_min_frag_size {
+            message
+                .nlas
+                .push(EthtoolAttr::Mm(EthtoolMmAttr::TxMinFragSize(value)));
+        }
+        if let Some(value) = verify_enabled {
+            message
+                .nlas
+                .push(EthtoolAttr::Mm(EthtoolMmAttr::VerifyEnabled(value)));
+        }
+        if let Some(value) = verify_time {
+            message
+                .nlas
+                .push(EthtoolAttr::Mm(EthtoolMmAttr::VerifyTime(value)));
+        }
+
+        let mut nl_msg =
+            NetlinkMessage::from(GenlMessage::from_payload(message));
+
+        nl_msg.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        let mut response = handle.request(nl_msg).await?;
+
+        while let Some(message) = response.next().await {
+            try_ethtool!(message);
+        }
+
+        Ok(())
+    }
+}
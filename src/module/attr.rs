@@ -0,0 +1,190 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    parse_u8, DecodeError, DefaultNla, Emitable, ErrorContext, Nla, NlaBuffer,
+    NlasIterator, Parseable, NLA_F_NESTED,
+};
+
+use crate::{EthtoolAttr, EthtoolHeader};
+
+const ETHTOOL_A_MODULE_HEADER: u16 = 1;
+const ETHTOOL_A_MODULE_POWER_MODE_POLICY: u16 = 2;
+const ETHTOOL_A_MODULE_POWER_MODE: u16 = 3;
+
+const ETHTOOL_MODULE_POWER_MODE_POLICY_HIGH: u8 = 1;
+const ETHTOOL_MODULE_POWER_MODE_POLICY_AUTO: u8 = 2;
+
+const ETHTOOL_MODULE_POWER_MODE_LOW: u8 = 1;
+const ETHTOOL_MODULE_POWER_MODE_HIGH: u8 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EthtoolModulePowerModePolicy {
+    /// Module is always in high power mode
+    High,
+    /// Module is transitioned by the host to high power mode when the first
+    /// port using it is put administratively up and to low power mode when
+    /// the last port using it is put administratively down
+    Auto,
+    Other(u8),
+}
+
+impl std::fmt::Display for EthtoolModulePowerModePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::High => write!(f, "high"),
+            Self::Auto => write!(f, "auto"),
+            Self::Other(d) => write!(f, "{d}"),
+        }
+    }
+}
+
+impl From<u8> for EthtoolModulePowerModePolicy {
+    fn from(d: u8) -> Self {
+        match d {
+            ETHTOOL_MODULE_POWER_MODE_POLICY_HIGH => Self::High,
+            ETHTOOL_MODULE_POWER_MODE_POLICY_AUTO => Self::Auto,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<EthtoolModulePowerModePolicy> for u8 {
+    fn from(v: EthtoolModulePowerModePolicy) -> u8 {
+        match v {
+            EthtoolModulePowerModePolicy::High => {
+                ETHTOOL_MODULE_POWER_MODE_POLICY_HIGH
+            }
+            EthtoolModulePowerModePolicy::Auto => {
+                ETHTOOL_MODULE_POWER_MODE_POLICY_AUTO
+            }
+            EthtoolModulePowerModePolicy::Other(d) => d,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EthtoolModulePowerMode {
+    Low,
+    High,
+    Other(u8),
+}
+
+impl std::fmt::Display for EthtoolModulePowerMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Low => write!(f, "low"),
+            Self::High => write!(f, "high"),
+            Self::Other(d) => write!(f, "{d}"),
+        }
+    }
+}
+
+impl From<u8> for EthtoolModulePowerMode {
+    fn from(d: u8) -> Self {
+        match d {
+            ETHTOOL_MODULE_POWER_MODE_LOW => Self::Low,
+            ETHTOOL_MODULE_POWER_MODE_HIGH => Self::High,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<EthtoolModulePowerMode> for u8 {
+    fn from(v: EthtoolModulePowerMode) -> u8 {
+        match v {
+            EthtoolModulePowerMode::Low => ETHTOOL_MODULE_POWER_MODE_LOW,
+            EthtoolModulePowerMode::High => ETHTOOL_MODULE_POWER_MODE_HIGH,
+            EthtoolModulePowerMode::Other(d) => d,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum EthtoolModuleAttr {
+    Header(Vec<EthtoolHeader>),
+    PowerModePolicy(EthtoolModulePowerModePolicy),
+    /// Current power mode, only available when a module is plugged in
+    PowerMode(EthtoolModulePowerMode),
+    Other(DefaultNla),
+}
+
+impl Nla for EthtoolModuleAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Header(hdrs) => hdrs.as_slice().buffer_len(),
+            Self::PowerModePolicy(_) | Self::PowerMode(_) => 1,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Header(_) => ETHTOOL_A_MODULE_HEADER | NLA_F_NESTED,
+            Self::PowerModePolicy(_) => ETHTOOL_A_MODULE_POWER_MODE_POLICY,
+            Self::PowerMode(_) => ETHTOOL_A_MODULE_POWER_MODE,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Header(ref nlas) => nlas.as_slice().emit(buffer),
+            Self::PowerModePolicy(d) => buffer[0] = (*d).into(),
+            Self::PowerMode(d) => buffer[0] = (*d).into(),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for EthtoolModuleAttr
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            ETHTOOL_A_MODULE_HEADER => {
+                let mut nlas = Vec::new();
+                let error_msg = "failed to parse module header attributes";
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    let parsed =
+                        EthtoolHeader::parse(nla).context(error_msg)?;
+                    nlas.push(parsed);
+                }
+                Self::Header(nlas)
+            }
+            ETHTOOL_A_MODULE_POWER_MODE_POLICY => Self::PowerModePolicy(
+                parse_u8(payload)
+                    .context(format!(
+                        "Invalid ETHTOOL_A_MODULE_POWER_MODE_POLICY \
+                        {payload:?}"
+                    ))?
+                    .into(),
+            ),
+            ETHTOOL_A_MODULE_POWER_MODE => Self::PowerMode(
+                parse_u8(payload)
+                    .context(format!(
+                        "Invalid ETHTOOL_A_MODULE_POWER_MODE {payload:?}"
+                    ))?
+                    .into(),
+            ),
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+pub(crate) fn parse_module_nlas(
+    buffer: &[u8],
+) -> Result<Vec<EthtoolAttr>, DecodeError> {
+    let mut nlas = Vec::new();
+    for nla in NlasIterator::new(buffer) {
+        let error_msg =
+            format!("Failed to parse ethtool module message attribute {nla:?}");
+        let nla = &nla.context(error_msg.clone())?;
+        let parsed = EthtoolModuleAttr::parse(nla).context(error_msg)?;
+        nlas.push(EthtoolAttr::Module(parsed));
+    }
+    Ok(nlas)
+}
@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    EthtoolHandle, EthtoolModuleFwFlashRequest, EthtoolModuleGetRequest,
+    EthtoolModuleSetRequest,
+};
+
+pub struct EthtoolModuleHandle(EthtoolHandle);
+
+impl EthtoolModuleHandle {
+    pub fn new(handle: EthtoolHandle) -> Self {
+        EthtoolModuleHandle(handle)
+    }
+
+    /// Retrieve the transceiver module power mode policy and power mode of
+    /// a interface (used by `ethtool --show-module eth1`)
+    pub fn get(&mut self, iface_name: Option<&str>) -> EthtoolModuleGetRequest {
+        EthtoolModuleGetRequest::new(self.0.clone(), iface_name)
+    }
+
+    /// Change the transceiver module power mode policy of a interface (used
+    /// by `ethtool --set-module eth1 power-mode-policy high`)
+    pub fn set(&mut self, iface_name: &str) -> EthtoolModuleSetRequest {
+        EthtoolModuleSetRequest::new(self.0.clone(), iface_name)
+    }
+
+    /// Flash the firmware file to the transceiver module of a interface (used
+    /// by `ethtool --flash-module-firmware eth1 file fw.bin`)
+    pub fn flash_firmware(
+        &mut self,
+        iface_name: &str,
+        file_name: &str,
+    ) -> EthtoolModuleFwFlashRequest {
+        EthtoolModuleFwFlashRequest::new(self.0.clone(), iface_name, file_name)
+    }
+}
@@ -0,0 +1,15 @@
+// SPDX-License-Identifier: MIT
+
+mod attr;
+mod get;
+mod handle;
+mod set;
+
+pub(crate) use attr::parse_module_nlas;
+
+pub use attr::{
+    EthtoolModuleAttr, EthtoolModulePowerMode, EthtoolModulePowerModePolicy,
+};
+pub use get::EthtoolModuleGetRequest;
+pub use handle::EthtoolModuleHandle;
+pub use set::EthtoolModuleSetRequest;
@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: MIT
+
+use futures_util::StreamExt;
+use netlink_packet_core::{NetlinkMessage, NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    try_ethtool, EthtoolAttr, EthtoolError, EthtoolHandle, EthtoolMessage,
+    EthtoolModuleAttr, EthtoolModulePowerModePolicy,
+};
+
+pub struct EthtoolModuleSetRequest {
+    handle: EthtoolHandle,
+    message: EthtoolMessage,
+    power_mode_policy: Option<EthtoolModulePowerModePolicy>,
+}
+
+impl EthtoolModuleSetRequest {
+    pub(crate) fn new(handle: EthtoolHandle, iface_name: &str) -> Self {
+        EthtoolModuleSetRequest {
+            handle,
+            message: EthtoolMessage::new_module_set(iface_name),
+            power_mode_policy: None,
+        }
+    }
+
+    pub fn power_mode_policy(
+        mut self,
+        policy: EthtoolModulePowerModePolicy,
+    ) -> Self {
+        self.power_mode_policy = Some(policy);
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), EthtoolError> {
+        let EthtoolModuleSetRequest {
+            mut handle,
+            mut message,
+            power_mode_policy,
+        } = self;
+
+        if let Some(policy) = power_mode_policy {
+            message.nlas.push(EthtoolAttr::Module(
+                EthtoolModuleAttr::PowerModePolicy(policy),
+            ));
+        }
+
+        let mut nl_msg =
+            NetlinkMessage::from(GenlMessage::from_payload(message));
+
+        nl_msg.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        let mut response = handle.request(nl_msg).await?;
+
+        while let Some(message) = response.next().await {
+            try_ethtool!(message);
+        }
+
+        Ok(())
+    }
+}
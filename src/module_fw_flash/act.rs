@@ -0,0 +1,111 @@
+// SPDX-License-Identifier: MIT
+
+use futures_channel::mpsc::UnboundedReceiver;
+use futures_util::Stream;
+use genetlink::message::RawGenlMessage;
+use netlink_packet_core::NetlinkMessage;
+use netlink_packet_generic::GenlMessage;
+use netlink_sys::SocketAddr;
+
+use crate::{
+    ethtool_act_ntf, EthtoolAttr, EthtoolCmd, EthtoolError, EthtoolHandle,
+    EthtoolHeader, EthtoolMessage, EthtoolModuleFwFlashAttr,
+    EthtoolModuleFwFlashStatus,
+};
+
+pub struct EthtoolModuleFwFlashRequest {
+    handle: EthtoolHandle,
+    iface_name: String,
+    file_name: String,
+    password: Option<u32>,
+}
+
+impl EthtoolModuleFwFlashRequest {
+    pub(crate) fn new(
+        handle: EthtoolHandle,
+        iface_name: &str,
+        file_name: &str,
+    ) -> Self {
+        EthtoolModuleFwFlashRequest {
+            handle,
+            iface_name: iface_name.to_string(),
+            file_name: file_name.to_string(),
+            password: None,
+        }
+    }
+
+    /// Password required by the module to accept the firmware
+    pub fn password(mut self, password: u32) -> Self {
+        self.password = Some(password);
+        self
+    }
+
+    /// Start flashing and return the `ModuleFwFlashNtf` progress
+    /// notifications of this interface, ending with the one of `Completed`
+    /// or `Error` status.
+    /// Kernel only sends the notifications to the requesting socket, hence
+    /// `messages` should be the receiver returned along with the handle by
+    /// `new_connection()`. Closing that socket aborts the flashing. Messages
+    /// queued in it before flashing and other notifications are dropped.
+    pub async fn execute(
+        self,
+        messages: &mut UnboundedReceiver<(
+            NetlinkMessage<RawGenlMessage>,
+            SocketAddr,
+        )>,
+    ) -> Result<
+        impl Stream<Item = Result<GenlMessage<EthtoolMessage>, EthtoolError>> + '_,
+        EthtoolError,
+    > {
+        let EthtoolModuleFwFlashRequest {
+            mut handle,
+            iface_name,
+            file_name,
+            password,
+        } = self;
+        let mut message =
+            EthtoolMessage::new_module_fw_flash_act(&iface_name, &file_name);
+        if let Some(password) = password {
+            message.nlas.push(EthtoolAttr::ModuleFwFlash(
+                EthtoolModuleFwFlashAttr::Password(password),
+            ));
+        }
+
+        ethtool_act_ntf(
+            &mut handle,
+            message,
+            EthtoolCmd::ModuleFwFlashNtf,
+            messages,
+            move |msg| is_iface_ntf(msg, &iface_name),
+            is_finished,
+        )
+        .await
+    }
+}
+
+fn is_iface_ntf(message: &EthtoolMessage, iface_name: &str) -> bool {
+    message.nlas.iter().any(|nla| {
+        if let EthtoolAttr::ModuleFwFlash(EthtoolModuleFwFlashAttr::Header(
+            hdrs,
+        )) = nla
+        {
+            hdrs.iter().any(|hdr| {
+                matches!(hdr, EthtoolHeader::DevName(n) if n == iface_name)
+            })
+        } else {
+            false
+        }
+    })
+}
+
+fn is_finished(message: &EthtoolMessage) -> bool {
+    message.nlas.iter().any(|nla| {
+        matches!(
+            nla,
+            EthtoolAttr::ModuleFwFlash(EthtoolModuleFwFlashAttr::Status(
+                EthtoolModuleFwFlashStatus::Completed
+                    | EthtoolModuleFwFlashStatus::Error
+            ))
+        )
+    })
+}
@@ -0,0 +1,213 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32, emit_u64, parse_string, parse_u32, parse_u64, DecodeError,
+    DefaultNla, Emitable, ErrorContext, Nla, NlaBuffer, NlasIterator,
+    Parseable, NLA_F_NESTED,
+};
+
+use crate::{EthtoolAttr, EthtoolHeader};
+
+const ETHTOOL_A_MODULE_FW_FLASH_HEADER: u16 = 1;
+const ETHTOOL_A_MODULE_FW_FLASH_FILE_NAME: u16 = 2;
+const ETHTOOL_A_MODULE_FW_FLASH_PASSWORD: u16 = 3;
+const ETHTOOL_A_MODULE_FW_FLASH_STATUS: u16 = 4;
+const ETHTOOL_A_MODULE_FW_FLASH_STATUS_MSG: u16 = 5;
+const ETHTOOL_A_MODULE_FW_FLASH_DONE: u16 = 6;
+const ETHTOOL_A_MODULE_FW_FLASH_TOTAL: u16 = 7;
+
+const ETHTOOL_MODULE_FW_FLASH_STATUS_STARTED: u32 = 1;
+const ETHTOOL_MODULE_FW_FLASH_STATUS_IN_PROGRESS: u32 = 2;
+const ETHTOOL_MODULE_FW_FLASH_STATUS_COMPLETED: u32 = 3;
+const ETHTOOL_MODULE_FW_FLASH_STATUS_ERROR: u32 = 4;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EthtoolModuleFwFlashStatus {
+    Started,
+    InProgress,
+    Completed,
+    /// Flashing failed, the reason is stored in the `StatusMsg` attribute
+    Error,
+    Other(u32),
+}
+
+impl std::fmt::Display for EthtoolModuleFwFlashStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Started => write!(f, "started"),
+            Self::InProgress => write!(f, "in progress"),
+            Self::Completed => write!(f, "completed"),
+            Self::Error => write!(f, "error"),
+            Self::Other(d) => write!(f, "{d}"),
+        }
+    }
+}
+
+impl From<u32> for EthtoolModuleFwFlashStatus {
+    fn from(d: u32) -> Self {
+        match d {
+            ETHTOOL_MODULE_FW_FLASH_STATUS_STARTED => Self::Started,
+            ETHTOOL_MODULE_FW_FLASH_STATUS_IN_PROGRESS => Self::InProgress,
+            ETHTOOL_MODULE_FW_FLASH_STATUS_COMPLETED => Self::Completed,
+            ETHTOOL_MODULE_FW_FLASH_STATUS_ERROR => Self::Error,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<EthtoolModuleFwFlashStatus> for u32 {
+    fn from(v: EthtoolModuleFwFlashStatus) -> u32 {
+        match v {
+            EthtoolModuleFwFlashStatus::Started => {
+                ETHTOOL_MODULE_FW_FLASH_STATUS_STARTED
+            }
+            EthtoolModuleFwFlashStatus::InProgress => {
+                ETHTOOL_MODULE_FW_FLASH_STATUS_IN_PROGRESS
+            }
+            EthtoolModuleFwFlashStatus::Completed => {
+                ETHTOOL_MODULE_FW_FLASH_STATUS_COMPLETED
+            }
+            EthtoolModuleFwFlashStatus::Error => {
+                ETHTOOL_MODULE_FW_FLASH_STATUS_ERROR
+            }
+            EthtoolModuleFwFlashStatus::Other(d) => d,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum EthtoolModuleFwFlashAttr {
+    Header(Vec<EthtoolHeader>),
+    /// Firmware file name, relative to the firmware search path of kernel
+    FileName(String),
+    Password(u32),
+    Status(EthtoolModuleFwFlashStatus),
+    StatusMsg(String),
+    /// Amount of work done, in the unit of `Total`
+    Done(u64),
+    Total(u64),
+    Other(DefaultNla),
+}
+
+impl Nla for EthtoolModuleFwFlashAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Header(hdrs) => hdrs.as_slice().buffer_len(),
+            Self::FileName(s) | Self::StatusMsg(s) => s.len() + 1,
+            Self::Password(_) | Self::Status(_) => 4,
+            Self::Done(_) | Self::Total(_) => 8,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Header(_) => ETHTOOL_A_MODULE_FW_FLASH_HEADER | NLA_F_NESTED,
+            Self::FileName(_) => ETHTOOL_A_MODULE_FW_FLASH_FILE_NAME,
+            Self::Password(_) => ETHTOOL_A_MODULE_FW_FLASH_PASSWORD,
+            Self::Status(_) => ETHTOOL_A_MODULE_FW_FLASH_STATUS,
+            Self::StatusMsg(_) => ETHTOOL_A_MODULE_FW_FLASH_STATUS_MSG,
+            Self::Done(_) => ETHTOOL_A_MODULE_FW_FLASH_DONE,
+            Self::Total(_) => ETHTOOL_A_MODULE_FW_FLASH_TOTAL,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Header(ref nlas) => nlas.as_slice().emit(buffer),
+            Self::FileName(s) | Self::StatusMsg(s) => {
+                buffer[..s.len()].copy_from_slice(s.as_bytes());
+                buffer[s.len()] = 0;
+            }
+            Self::Password(d) => emit_u32(buffer, *d).unwrap(),
+            Self::Status(d) => emit_u32(buffer, (*d).into()).unwrap(),
+            Self::Done(d) | Self::Total(d) => emit_u64(buffer, *d).unwrap(),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for EthtoolModuleFwFlashAttr
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            ETHTOOL_A_MODULE_FW_FLASH_HEADER => {
+                let mut nlas = Vec::new();
+                let error_msg =
+                    "failed to parse module firmware flash header attributes";
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    let parsed =
+                        EthtoolHeader::parse(nla).context(error_msg)?;
+                    nlas.push(parsed);
+                }
+                Self::Header(nlas)
+            }
+            ETHTOOL_A_MODULE_FW_FLASH_FILE_NAME => {
+                Self::FileName(parse_string(payload).context(format!(
+                    "Invalid ETHTOOL_A_MODULE_FW_FLASH_FILE_NAME \
+                    {payload:?}"
+                ))?)
+            }
+            ETHTOOL_A_MODULE_FW_FLASH_PASSWORD => {
+                Self::Password(parse_u32(payload).context(format!(
+                    "Invalid ETHTOOL_A_MODULE_FW_FLASH_PASSWORD {payload:?}"
+                ))?)
+            }
+            ETHTOOL_A_MODULE_FW_FLASH_STATUS => Self::Status(
+                parse_u32(payload)
+                    .context(format!(
+                        "Invalid ETHTOOL_A_MODULE_FW_FLASH_STATUS {payload:?}"
+                    ))?
+                    .into(),
+            ),
+            ETHTOOL_A_MODULE_FW_FLASH_STATUS_MSG => {
+                Self::StatusMsg(parse_string(payload).context(format!(
+                    "Invalid ETHTOOL_A_MODULE_FW_FLASH_STATUS_MSG \
+                    {payload:?}"
+                ))?)
+            }
+            ETHTOOL_A_MODULE_FW_FLASH_DONE => {
+                Self::Done(parse_uint(payload).context(format!(
+                    "Invalid ETHTOOL_A_MODULE_FW_FLASH_DONE {payload:?}"
+                ))?)
+            }
+            ETHTOOL_A_MODULE_FW_FLASH_TOTAL => {
+                Self::Total(parse_uint(payload).context(format!(
+                    "Invalid ETHTOOL_A_MODULE_FW_FLASH_TOTAL {payload:?}"
+                ))?)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+// Kernel sends NLA_UINT as u32 when the value fits, u64 otherwise.
+fn parse_uint(payload: &[u8]) -> Result<u64, DecodeError> {
+    if payload.len() == 4 {
+        parse_u32(payload).map(u64::from)
+    } else {
+        parse_u64(payload)
+    }
+}
+
+pub(crate) fn parse_module_fw_flash_nlas(
+    buffer: &[u8],
+) -> Result<Vec<EthtoolAttr>, DecodeError> {
+    let mut nlas = Vec::new();
+    for nla in NlasIterator::new(buffer) {
+        let error_msg = format!(
+            "Failed to parse ethtool module firmware flash message attribute \
+            {nla:?}"
+        );
+        let nla = &nla.context(error_msg.clone())?;
+        let parsed = EthtoolModuleFwFlashAttr::parse(nla).context(error_msg)?;
+        nlas.push(EthtoolAttr::ModuleFwFlash(parsed));
+    }
+    Ok(nlas)
+}
@@ -0,0 +1,9 @@
+// SPDX-License-Identifier: MIT
+
+mod act;
+mod attr;
+
+pub(crate) use attr::parse_module_fw_flash_nlas;
+
+pub use act::EthtoolModuleFwFlashRequest;
+pub use attr::{EthtoolModuleFwFlashAttr, EthtoolModuleFwFlashStatus};
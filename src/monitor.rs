@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT
+
+use futures_util::{future, Stream, StreamExt};
+use genetlink::message::RawGenlMessage;
+use netlink_packet_core::{NetlinkMessage, NetlinkPayload};
+use netlink_packet_generic::GenlMessage;
+use netlink_sys::SocketAddr;
+
+use crate::{message::is_ntf_supported, EthtoolError, EthtoolMessage};
+
+/// Decode the messages received by connection from
+/// `new_monitor_connection()` into ethtool notifications, like
+/// `ethtool --monitor` does.
+/// Notifications hold bitsets without bit names, hence `EthtoolBit` and
+/// `EthtoolFeatureBit` are only identified by index, use
+/// `EthtoolStringSetHandle::resolve_names()` to name them.
+/// Notifications unknown to this crate are skipped. Messages failed to
+/// decode are yielded as errors without ending the stream.
+pub fn ethtool_monitor<S>(
+    messages: S,
+) -> impl Stream<Item = Result<GenlMessage<EthtoolMessage>, EthtoolError>>
+where
+    S: Stream<Item = (NetlinkMessage<RawGenlMessage>, SocketAddr)>,
+{
+    messages.filter_map(|(nl_msg, _)| {
+        future::ready(match nl_msg.payload {
+            NetlinkPayload::InnerMessage(raw)
+                if is_ntf_supported(raw.header.cmd) =>
+            {
+                Some(raw.parse_into_genlmsg::<EthtoolMessage>().map_err(|e| {
+                    EthtoolError::Bug(format!("BUG: decode error {e:?}"))
+                }))
+            }
+            _ => None,
+        })
+    })
+}
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: MIT
 
-use crate::{EthtoolHandle, EthtoolPauseGetRequest};
+use crate::{EthtoolHandle, EthtoolPauseGetRequest, EthtoolPauseSetRequest};
 
 pub struct EthtoolPauseHandle(EthtoolHandle);
 
@@ -14,4 +14,10 @@ impl EthtoolPauseHandle {
     pub fn get(&mut self, iface_name: Option<&str>) -> EthtoolPauseGetRequest {
         EthtoolPauseGetRequest::new(self.0.clone(), iface_name)
     }
+
+    /// Set the pause setting of a interface (equivalent to `ethtool -A
+    /// eth1`)
+    pub fn set(&mut self, iface_name: &str) -> EthtoolPauseSetRequest {
+        EthtoolPauseSetRequest::new(self.0.clone(), iface_name)
+    }
 }
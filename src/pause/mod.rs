@@ -3,8 +3,10 @@
 mod attr;
 mod get;
 mod handle;
+mod set;
 
 pub(crate) use attr::parse_pause_nlas;
 pub use attr::{EthtoolPauseAttr, EthtoolPauseStatAttr};
 pub use get::EthtoolPauseGetRequest;
 pub use handle::EthtoolPauseHandle;
+pub use set::EthtoolPauseSetRequest;
@@ -0,0 +1,84 @@
+// SPDX-License-Identifier: MIT
+
+use futures_util::StreamExt;
+use netlink_packet_core::{NetlinkMessage, NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    try_ethtool, EthtoolAttr, EthtoolError, EthtoolHandle, EthtoolMessage,
+    EthtoolPauseAttr,
+};
+
+pub struct EthtoolPauseSetRequest {
+    handle: EthtoolHandle,
+    message: EthtoolMessage,
+    autoneg: Option<bool>,
+    rx: Option<bool>,
+    tx: Option<bool>,
+}
+
+impl EthtoolPauseSetRequest {
+    pub(crate) fn new(handle: EthtoolHandle, iface_name: &str) -> Self {
+        EthtoolPauseSetRequest {
+            handle,
+            message: EthtoolMessage::new_pause_set(iface_name),
+            autoneg: None,
+            rx: None,
+            tx: None,
+        }
+    }
+
+    pub fn autoneg(mut self, enabled: bool) -> Self {
+        self.autoneg = Some(enabled);
+        self
+    }
+
+    pub fn rx(mut self, enabled: bool) -> Self {
+        self.rx = Some(enabled);
+        self
+    }
+
+    pub fn tx(mut self, enabled: bool) -> Self {
+        self.tx = Some(enabled);
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), EthtoolError> {
+        let EthtoolPauseSetRequest {
+            mut handle,
+            mut message,
+            autoneg,
+            rx,
+            tx,
+        } = self;
+
+        if let Some(enabled) = autoneg {
+            message
+                .nlas
+                .push(EthtoolAttr::Pause(EthtoolPauseAttr::AutoNeg(enabled)));
+        }
+        if let Some(enabled) = rx {
+            message
+                .nlas
+                .push(EthtoolAttr::Pause(EthtoolPauseAttr::Rx(enabled)));
+        }
+        if let Some(enabled) = tx {
+            message
+                .nlas
+                .push(EthtoolAttr::Pause(EthtoolPauseAttr::Tx(enabled)));
+        }
+
+        let mut nl_msg =
+            NetlinkMessage::from(GenlMessage::from_payload(message));
+
+        nl_msg.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        let mut response = handle.request(nl_msg).await?;
+
+        while let Some(message) = response.next().await {
+            try_ethtool!(message);
+        }
+
+        Ok(())
+    }
+}
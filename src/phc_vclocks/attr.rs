@@ -0,0 +1,109 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32, parse_u32, DecodeError, DefaultNla, Emitable, ErrorContext, Nla,
+    NlaBuffer, NlasIterator, Parseable, NLA_F_NESTED,
+};
+
+use crate::{EthtoolAttr, EthtoolHeader};
+
+const ETHTOOL_A_PHC_VCLOCKS_HEADER: u16 = 1;
+const ETHTOOL_A_PHC_VCLOCKS_NUM: u16 = 2;
+const ETHTOOL_A_PHC_VCLOCKS_INDEX: u16 = 3;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum EthtoolPhcVclocksAttr {
+    Header(Vec<EthtoolHeader>),
+    Num(u32),
+    /// PHC indices of the virtual clocks, comparable to
+    /// `EthtoolTsInfoAttr::PhcIndex`
+    Index(Vec<u32>),
+    Other(DefaultNla),
+}
+
+impl Nla for EthtoolPhcVclocksAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Header(hdrs) => hdrs.as_slice().buffer_len(),
+            Self::Num(_) => 4,
+            Self::Index(indexes) => indexes.len() * 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Header(_) => ETHTOOL_A_PHC_VCLOCKS_HEADER | NLA_F_NESTED,
+            Self::Num(_) => ETHTOOL_A_PHC_VCLOCKS_NUM,
+            Self::Index(_) => ETHTOOL_A_PHC_VCLOCKS_INDEX,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Header(ref nlas) => nlas.as_slice().emit(buffer),
+            Self::Num(d) => emit_u32(buffer, *d).unwrap(),
+            Self::Index(indexes) => {
+                for (i, index) in indexes.iter().enumerate() {
+                    emit_u32(&mut buffer[i * 4..], *index).unwrap();
+                }
+            }
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for EthtoolPhcVclocksAttr
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            ETHTOOL_A_PHC_VCLOCKS_HEADER => {
+                let mut nlas = Vec::new();
+                let error_msg = "failed to parse phc vclocks header attributes";
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    let parsed =
+                        EthtoolHeader::parse(nla).context(error_msg)?;
+                    nlas.push(parsed);
+                }
+                Self::Header(nlas)
+            }
+            ETHTOOL_A_PHC_VCLOCKS_NUM => {
+                Self::Num(parse_u32(payload).context(format!(
+                    "Invalid ETHTOOL_A_PHC_VCLOCKS_NUM value {payload:?}"
+                ))?)
+            }
+            ETHTOOL_A_PHC_VCLOCKS_INDEX => {
+                let mut indexes = Vec::new();
+                for chunk in payload.chunks(4) {
+                    indexes.push(parse_u32(chunk).context(format!(
+                        "Invalid ETHTOOL_A_PHC_VCLOCKS_INDEX value \
+                        {payload:?}"
+                    ))?);
+                }
+                Self::Index(indexes)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+pub(crate) fn parse_phc_vclocks_nlas(
+    buffer: &[u8],
+) -> Result<Vec<EthtoolAttr>, DecodeError> {
+    let mut nlas = Vec::new();
+    for nla in NlasIterator::new(buffer) {
+        let error_msg = format!(
+            "Failed to parse ethtool phc vclocks message attribute {nla:?}"
+        );
+        let nla = &nla.context(error_msg.clone())?;
+        let parsed = EthtoolPhcVclocksAttr::parse(nla).context(error_msg)?;
+        nlas.push(EthtoolAttr::PhcVclocks(parsed));
+    }
+    Ok(nlas)
+}
@@ -0,0 +1,20 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{EthtoolHandle, EthtoolPhcVclocksGetRequest};
+
+pub struct EthtoolPhcVclocksHandle(EthtoolHandle);
+
+impl EthtoolPhcVclocksHandle {
+    pub fn new(handle: EthtoolHandle) -> Self {
+        EthtoolPhcVclocksHandle(handle)
+    }
+
+    /// Retrieve the PHC virtual clocks of an interface (used by
+    /// `ethtool --show-phc-vclocks eth1`)
+    pub fn get(
+        &mut self,
+        iface_name: Option<&str>,
+    ) -> EthtoolPhcVclocksGetRequest {
+        EthtoolPhcVclocksGetRequest::new(self.0.clone(), iface_name)
+    }
+}
@@ -0,0 +1,10 @@
+// SPDX-License-Identifier: MIT
+
+mod attr;
+mod get;
+mod handle;
+
+pub(crate) use attr::parse_phc_vclocks_nlas;
+pub use attr::EthtoolPhcVclocksAttr;
+pub use get::EthtoolPhcVclocksGetRequest;
+pub use handle::EthtoolPhcVclocksHandle;
@@ -0,0 +1,158 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u16, emit_u32, parse_u16, parse_u32, parse_u8, DecodeError,
+    DefaultNla, Emitable, ErrorContext, Nla, NlaBuffer, NlasIterator,
+    Parseable, NLA_F_NESTED,
+};
+
+use crate::{EthtoolAttr, EthtoolHeader};
+
+const ETHTOOL_A_PLCA_HEADER: u16 = 1;
+const ETHTOOL_A_PLCA_VERSION: u16 = 2;
+const ETHTOOL_A_PLCA_ENABLED: u16 = 3;
+const ETHTOOL_A_PLCA_STATUS: u16 = 4;
+const ETHTOOL_A_PLCA_NODE_CNT: u16 = 5;
+const ETHTOOL_A_PLCA_NODE_ID: u16 = 6;
+const ETHTOOL_A_PLCA_TO_TMR: u16 = 7;
+const ETHTOOL_A_PLCA_BURST_CNT: u16 = 8;
+const ETHTOOL_A_PLCA_BURST_TMR: u16 = 9;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum EthtoolPlcaAttr {
+    Header(Vec<EthtoolHeader>),
+    /// PLCA register map version supported by the PHY
+    Version(u16),
+    Enabled(bool),
+    /// Whether PLCA is up (beacons sent or received), only in status reply
+    Status(bool),
+    /// Maximum number of nodes on the link, used by the coordinator
+    NodeCount(u32),
+    /// Local node ID, 0 for the coordinator
+    NodeId(u32),
+    /// Transmit opportunity timer, in bit-times
+    ToTimer(u32),
+    /// Number of additional packets allowed in one transmit opportunity
+    BurstCount(u32),
+    /// Wait time for the next packet in a burst, in bit-times
+    BurstTimer(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for EthtoolPlcaAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Header(hdrs) => hdrs.as_slice().buffer_len(),
+            Self::Version(_) => 2,
+            Self::Enabled(_) | Self::Status(_) => 1,
+            Self::NodeCount(_)
+            | Self::NodeId(_)
+            | Self::ToTimer(_)
+            | Self::BurstCount(_)
+            | Self::BurstTimer(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Header(_) => ETHTOOL_A_PLCA_HEADER | NLA_F_NESTED,
+            Self::Version(_) => ETHTOOL_A_PLCA_VERSION,
+            Self::Enabled(_) => ETHTOOL_A_PLCA_ENABLED,
+            Self::Status(_) => ETHTOOL_A_PLCA_STATUS,
+            Self::NodeCount(_) => ETHTOOL_A_PLCA_NODE_CNT,
+            Self::NodeId(_) => ETHTOOL_A_PLCA_NODE_ID,
+            Self::ToTimer(_) => ETHTOOL_A_PLCA_TO_TMR,
+            Self::BurstCount(_) => ETHTOOL_A_PLCA_BURST_CNT,
+            Self::BurstTimer(_) => ETHTOOL_A_PLCA_BURST_TMR,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Header(ref nlas) => nlas.as_slice().emit(buffer),
+            Self::Version(d) => emit_u16(buffer, *d).unwrap(),
+            Self::Enabled(d) | Self::Status(d) => buffer[0] = *d as u8,
+            Self::NodeCount(d)
+            | Self::NodeId(d)
+            | Self::ToTimer(d)
+            | Self::BurstCount(d)
+            | Self::BurstTimer(d) => emit_u32(buffer, *d).unwrap(),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for EthtoolPlcaAttr
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            ETHTOOL_A_PLCA_HEADER => {
+                let mut nlas = Vec::new();
+                let error_msg = "failed to parse plca header attributes";
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    let parsed =
+                        EthtoolHeader::parse(nla).context(error_msg)?;
+                    nlas.push(parsed);
+                }
+                Self::Header(nlas)
+            }
+            ETHTOOL_A_PLCA_VERSION => Self::Version(
+                parse_u16(payload)
+                    .context("Invalid ETHTOOL_A_PLCA_VERSION value")?,
+            ),
+            ETHTOOL_A_PLCA_ENABLED => Self::Enabled(
+                parse_u8(payload)
+                    .context("Invalid ETHTOOL_A_PLCA_ENABLED value")?
+                    > 0,
+            ),
+            ETHTOOL_A_PLCA_STATUS => Self::Status(
+                parse_u8(payload)
+                    .context("Invalid ETHTOOL_A_PLCA_STATUS value")?
+                    > 0,
+            ),
+            ETHTOOL_A_PLCA_NODE_CNT => Self::NodeCount(
+                parse_u32(payload)
+                    .context("Invalid ETHTOOL_A_PLCA_NODE_CNT value")?,
+            ),
+            ETHTOOL_A_PLCA_NODE_ID => Self::NodeId(
+                parse_u32(payload)
+                    .context("Invalid ETHTOOL_A_PLCA_NODE_ID value")?,
+            ),
+            ETHTOOL_A_PLCA_TO_TMR => Self::ToTimer(
+                parse_u32(payload)
+                    .context("Invalid ETHTOOL_A_PLCA_TO_TMR value")?,
+            ),
+            ETHTOOL_A_PLCA_BURST_CNT => Self::BurstCount(
+                parse_u32(payload)
+                    .context("Invalid ETHTOOL_A_PLCA_BURST_CNT value")?,
+            ),
+            ETHTOOL_A_PLCA_BURST_TMR => Self::BurstTimer(
+                parse_u32(payload)
+                    .context("Invalid ETHTOOL_A_PLCA_BURST_TMR value")?,
+            ),
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("invalid ethtool plca NLA kind {kind}"))?,
+            ),
+        })
+    }
+}
+
+pub(crate) fn parse_plca_nlas(
+    buffer: &[u8],
+) -> Result<Vec<EthtoolAttr>, DecodeError> {
+    let mut nlas = Vec::new();
+    for nla in NlasIterator::new(buffer) {
+        let error_msg =
+            format!("Failed to parse ethtool plca message attribute {nla:?}");
+        let nla = &nla.context(error_msg.clone())?;
+        let parsed = EthtoolPlcaAttr::parse(nla).context(error_msg)?;
+        nlas.push(EthtoolAttr::Plca(parsed));
+    }
+    Ok(nlas)
+}
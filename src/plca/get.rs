@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: MIT
+
+use futures_util::Stream;
+use netlink_packet_generic::GenlMessage;
+
+use crate::{ethtool_execute, EthtoolError, EthtoolHandle, EthtoolMessage};
+
+pub struct EthtoolPlcaGetRequest {
+    handle: EthtoolHandle,
+    iface_name: Option<String>,
+    status: bool,
+}
+
+impl EthtoolPlcaGetRequest {
+    pub(crate) fn new(
+        handle: EthtoolHandle,
+        iface_name: Option<&str>,
+        status: bool,
+    ) -> Self {
+        EthtoolPlcaGetRequest {
+            handle,
+            iface_name: iface_name.map(|i| i.to_string()),
+            status,
+        }
+    }
+
+    pub async fn execute(
+        self,
+    ) -> Result<
+        impl Stream<Item = Result<GenlMessage<EthtoolMessage>, EthtoolError>>,
+        EthtoolError,
+    > {
+        let EthtoolPlcaGetRequest {
+            mut handle,
+            iface_name,
+            status,
+        } = self;
+
+        let ethtool_msg = if status {
+            EthtoolMessage::new_plca_get_status(iface_name.as_deref())
+        } else {
+            EthtoolMessage::new_plca_get_cfg(iface_name.as_deref())
+        };
+        ethtool_execute(&mut handle, iface_name.is_none(), ethtool_msg).await
+    }
+}
@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{EthtoolHandle, EthtoolPlcaGetRequest, EthtoolPlcaSetRequest};
+
+pub struct EthtoolPlcaHandle(EthtoolHandle);
+
+impl EthtoolPlcaHandle {
+    pub fn new(handle: EthtoolHandle) -> Self {
+        EthtoolPlcaHandle(handle)
+    }
+
+    /// Retrieve the PLCA (IEEE 802.3cg 10BASE-T1S) configuration of a
+    /// interface (used by `ethtool --get-plca-cfg eth1`)
+    pub fn get_cfg(
+        &mut self,
+        iface_name: Option<&str>,
+    ) -> EthtoolPlcaGetRequest {
+        EthtoolPlcaGetRequest::new(self.0.clone(), iface_name, false)
+    }
+
+    /// Change the PLCA configuration of a interface (used by
+    /// `ethtool --set-plca-cfg eth1 node-id 1`)
+    pub fn set_cfg(&mut self, iface_name: &str) -> EthtoolPlcaSetRequest {
+        EthtoolPlcaSetRequest::new(self.0.clone(), iface_name)
+    }
+
+    /// Retrieve the PLCA status of a interface (used by
+    /// `ethtool --get-plca-status eth1`)
+    pub fn get_status(
+        &mut self,
+        iface_name: Option<&str>,
+    ) -> EthtoolPlcaGetRequest {
+        EthtoolPlcaGetRequest::new(self.0.clone(), iface_name, true)
+    }
+}
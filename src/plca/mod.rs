@@ -0,0 +1,13 @@
+// SPDX-License-Identifier: MIT
+
+mod attr;
+mod get;
+mod handle;
+mod set;
+
+pub(crate) use attr::parse_plca_nlas;
+
+pub use attr::EthtoolPlcaAttr;
+pub use get::EthtoolPlcaGetRequest;
+pub use handle::EthtoolPlcaHandle;
+pub use set::EthtoolPlcaSetRequest;
@@ -0,0 +1,123 @@
+// SPDX-License-Identifier: MIT
+
+use futures_util::StreamExt;
+use netlink_packet_core::{NetlinkMessage, NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    try_ethtool, EthtoolAttr, EthtoolError, EthtoolHandle, EthtoolMessage,
+    EthtoolPlcaAttr,
+};
+
+pub struct EthtoolPlcaSetRequest {
+    handle: EthtoolHandle,
+    message: EthtoolMessage,
+    enabled: Option<bool>,
+    node_id: Option<u32>,
+    node_count: Option<u32>,
+    to_timer: Option<u32>,
+    burst_count: Option<u32>,
+    burst_timer: Option<u32>,
+}
+
+impl EthtoolPlcaSetRequest {
+    pub(crate) fn new(handle: EthtoolHandle, iface_name: &str) -> Self {
+        EthtoolPlcaSetRequest {
+            handle,
+            message: EthtoolMessage::new_plca_set_cfg(iface_name),
+            enabled: None,
+            node_id: None,
+            node_count: None,
+            to_timer: None,
+            burst_count: None,
+            burst_timer: None,
+        }
+    }
+
+    pub fn enabled(mut self, value: bool) -> Self {
+        self.enabled = Some(value);
+        self
+    }
+
+    pub fn node_id(mut self, value: u32) -> Self {
+        self.node_id = Some(value);
+        self
+    }
+
+    pub fn node_count(mut self, value: u32) -> Self {
+        self.node_count = Some(value);
+        self
+    }
+
+    pub fn to_timer(mut self, value: u32) -> Self {
+        self.to_timer = Some(value);
+        self
+    }
+
+    pub fn burst_count(mut self, value: u32) -> Self {
+        self.burst_count = Some(value);
+        self
+    }
+
+    pub fn burst_timer(mut self, value: u32) -> Self {
+        self.burst_timer = Some(value);
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), EthtoolError> {
+        let EthtoolPlcaSetRequest {
+            mut handle,
+            mut message,
+            enabled,
+            node_id,
+            node_count,
+            to_timer,
+            burst_count,
+            burst_timer,
+        } = self;
+
+        if let Some(value) = enabled {
+            message
+                .nlas
+                .push(EthtoolAttr::Plca(EthtoolPlcaAttr::Enabled(value)));
+        }
+        if let Some(value) = node_id {
+            message
+                .nlas
+                .push(EthtoolAttr::Plca(EthtoolPlcaAttr::NodeId(value)));
+        }
+        if let Some(value) = node_count {
+            message
+                .nlas
+                .push(EthtoolAttr::Plca(EthtoolPlcaAttr::NodeCount(value)));
+        }
+        if let Some(value) = to_timer {
+            message
+                .nlas
+                .push(EthtoolAttr::Plca(EthtoolPlcaAttr::ToTimer(value)));
+        }
+        if let Some(value) = burst_count {
+            message
+                .nlas
+                .push(EthtoolAttr::Plca(EthtoolPlcaAttr::BurstCount(value)));
+        }
+        if let Some(value) = burst_timer {
+            message
+                .nlas
+                .push(EthtoolAttr::Plca(EthtoolPlcaAttr::BurstTimer(value)));
+        }
+
+        let mut nl_msg =
+            NetlinkMessage::from(GenlMessage::from_payload(message));
+
+        nl_msg.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        let mut response = handle.request(nl_msg).await?;
+
+        while let Some(message) = response.next().await {
+            try_ethtool!(message);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,118 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    DecodeError, DefaultNla, Emitable, ErrorContext, Nla, NlaBuffer,
+    NlasIterator, Parseable, NLA_F_NESTED,
+};
+
+use crate::{
+    bitset_util::{
+        bitset_nlas_len, emit_bitset_nlas, parse_bitset_bits_nlas, EthtoolBit,
+        EthtoolBitSet,
+    },
+    EthtoolAttr, EthtoolHeader,
+};
+
+const ETHTOOL_A_PRIVFLAGS_HEADER: u16 = 1;
+const ETHTOOL_A_PRIVFLAGS_FLAGS: u16 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum EthtoolPrivFlagsAttr {
+    Header(Vec<EthtoolHeader>),
+    /// Driver private flags, indexed by `PrivFlags` string set of the
+    /// interface, and whether they are enabled.
+    /// When setting, only listed flags are changed.
+    Flags(Vec<(EthtoolBit, bool)>),
+    Other(DefaultNla),
+}
+
+// Private flags are emitted with mask, so flags not listed are left
+// unchanged.
+fn priv_flags_to_bitset(flags: &[(EthtoolBit, bool)]) -> Vec<EthtoolBitSet> {
+    flags
+        .iter()
+        .map(|(flag, enabled)| EthtoolBitSet::new(flag, *enabled))
+        .collect()
+}
+
+impl Nla for EthtoolPrivFlagsAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Header(hdrs) => hdrs.as_slice().buffer_len(),
+            Self::Flags(flags) => {
+                bitset_nlas_len(&priv_flags_to_bitset(flags), false)
+            }
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Header(_) => ETHTOOL_A_PRIVFLAGS_HEADER | NLA_F_NESTED,
+            Self::Flags(_) => ETHTOOL_A_PRIVFLAGS_FLAGS | NLA_F_NESTED,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Header(ref nlas) => nlas.as_slice().emit(buffer),
+            Self::Flags(flags) => {
+                emit_bitset_nlas(&priv_flags_to_bitset(flags), false, buffer)
+            }
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for EthtoolPrivFlagsAttr
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            ETHTOOL_A_PRIVFLAGS_HEADER => {
+                let mut nlas = Vec::new();
+                let error_msg = "failed to parse privflags header attributes";
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    let parsed =
+                        EthtoolHeader::parse(nla).context(error_msg)?;
+                    nlas.push(parsed);
+                }
+                Self::Header(nlas)
+            }
+            ETHTOOL_A_PRIVFLAGS_FLAGS => {
+                let bits = parse_bitset_bits_nlas(payload).context(format!(
+                    "Invalid ETHTOOL_A_PRIVFLAGS_FLAGS {payload:?}"
+                ))?;
+                Self::Flags(
+                    bits.into_iter()
+                        .map(|b| {
+                            let value = b.value;
+                            (EthtoolBit::from(b), value)
+                        })
+                        .collect(),
+                )
+            }
+            kind => Self::Other(DefaultNla::parse(buf).context(format!(
+                "invalid ethtool privflags NLA kind {kind}"
+            ))?),
+        })
+    }
+}
+
+pub(crate) fn parse_privflags_nlas(
+    buffer: &[u8],
+) -> Result<Vec<EthtoolAttr>, DecodeError> {
+    let mut nlas = Vec::new();
+    for nla in NlasIterator::new(buffer) {
+        let error_msg = format!(
+            "Failed to parse ethtool privflags message attribute {nla:?}"
+        );
+        let nla = &nla.context(error_msg.clone())?;
+        let parsed = EthtoolPrivFlagsAttr::parse(nla).context(error_msg)?;
+        nlas.push(EthtoolAttr::PrivFlags(parsed));
+    }
+    Ok(nlas)
+}
@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    EthtoolHandle, EthtoolPrivFlagsGetRequest, EthtoolPrivFlagsSetRequest,
+};
+
+pub struct EthtoolPrivFlagsHandle(EthtoolHandle);
+
+impl EthtoolPrivFlagsHandle {
+    pub fn new(handle: EthtoolHandle) -> Self {
+        EthtoolPrivFlagsHandle(handle)
+    }
+
+    /// Retrieve the driver private flags of a interface (used by
+    /// `ethtool --show-priv-flags eth1`)
+    pub fn get(
+        &mut self,
+        iface_name: Option<&str>,
+    ) -> EthtoolPrivFlagsGetRequest {
+        EthtoolPrivFlagsGetRequest::new(self.0.clone(), iface_name)
+    }
+
+    /// Change the driver private flags of a interface (used by
+    /// `ethtool --set-priv-flags eth1 rx_cqe_compress on`)
+    pub fn set(&mut self, iface_name: &str) -> EthtoolPrivFlagsSetRequest {
+        EthtoolPrivFlagsSetRequest::new(self.0.clone(), iface_name)
+    }
+}
@@ -0,0 +1,13 @@
+// SPDX-License-Identifier: MIT
+
+mod attr;
+mod get;
+mod handle;
+mod set;
+
+pub(crate) use attr::parse_privflags_nlas;
+
+pub use attr::EthtoolPrivFlagsAttr;
+pub use get::EthtoolPrivFlagsGetRequest;
+pub use handle::EthtoolPrivFlagsHandle;
+pub use set::EthtoolPrivFlagsSetRequest;
@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: MIT
+
+use futures_util::StreamExt;
+use netlink_packet_core::{NetlinkMessage, NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    try_ethtool, EthtoolAttr, EthtoolBit, EthtoolError, EthtoolHandle,
+    EthtoolMessage, EthtoolPrivFlagsAttr,
+};
+
+pub struct EthtoolPrivFlagsSetRequest {
+    handle: EthtoolHandle,
+    message: EthtoolMessage,
+    flags: Vec<(EthtoolBit, bool)>,
+}
+
+impl EthtoolPrivFlagsSetRequest {
+    pub(crate) fn new(handle: EthtoolHandle, iface_name: &str) -> Self {
+        EthtoolPrivFlagsSetRequest {
+            handle,
+            message: EthtoolMessage::new_privflags_set(iface_name),
+            flags: Vec::new(),
+        }
+    }
+
+    /// Request private flag to be enabled or disabled, using the driver
+    /// flag name like `rx_cqe_compress`. Flags not requested are left
+    /// unchanged.
+    pub fn flag(mut self, name: &str, enabled: bool) -> Self {
+        self.flags.push((EthtoolBit::from(name), enabled));
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), EthtoolError> {
+        let EthtoolPrivFlagsSetRequest {
+            mut handle,
+            mut message,
+            flags,
+        } = self;
+
+        message
+            .nlas
+            .push(EthtoolAttr::PrivFlags(EthtoolPrivFlagsAttr::Flags(flags)));
+
+        let mut nl_msg =
+            NetlinkMessage::from(GenlMessage::from_payload(message));
+
+        nl_msg.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        let mut response = handle.request(nl_msg).await?;
+
+        while let Some(message) = response.next().await {
+            try_ethtool!(message);
+        }
+
+        Ok(())
+    }
+}
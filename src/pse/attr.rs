@@ -0,0 +1,450 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32, parse_u32, DecodeError, DefaultNla, Emitable, ErrorContext, Nla,
+    NlaBuffer, NlasIterator, Parseable, NLA_F_NESTED,
+};
+
+use crate::{EthtoolAttr, EthtoolHeader};
+
+const ETHTOOL_A_PSE_HEADER: u16 = 1;
+const ETHTOOL_A_PODL_PSE_ADMIN_STATE: u16 = 2;
+const ETHTOOL_A_PODL_PSE_ADMIN_CONTROL: u16 = 3;
+const ETHTOOL_A_PODL_PSE_PW_D_STATUS: u16 = 4;
+const ETHTOOL_A_C33_PSE_ADMIN_STATE: u16 = 5;
+const ETHTOOL_A_C33_PSE_ADMIN_CONTROL: u16 = 6;
+const ETHTOOL_A_C33_PSE_PW_D_STATUS: u16 = 7;
+const ETHTOOL_A_C33_PSE_PW_CLASS: u16 = 8;
+const ETHTOOL_A_C33_PSE_ACTUAL_PW: u16 = 9;
+const ETHTOOL_A_C33_PSE_AVAIL_PW_LIMIT: u16 = 12;
+const ETHTOOL_A_C33_PSE_PW_LIMIT_RANGES: u16 = 13;
+
+const ETHTOOL_A_C33_PSE_PW_LIMIT_MIN: u16 = 1;
+const ETHTOOL_A_C33_PSE_PW_LIMIT_MAX: u16 = 2;
+
+// Shared by `ETHTOOL_PODL_PSE_ADMIN_STATE_*` and
+// `ETHTOOL_C33_PSE_ADMIN_STATE_*`
+const ETHTOOL_PSE_ADMIN_STATE_UNKNOWN: u32 = 1;
+const ETHTOOL_PSE_ADMIN_STATE_DISABLED: u32 = 2;
+const ETHTOOL_PSE_ADMIN_STATE_ENABLED: u32 = 3;
+
+const ETHTOOL_PODL_PSE_PW_D_STATUS_UNKNOWN: u32 = 1;
+const ETHTOOL_PODL_PSE_PW_D_STATUS_DISABLED: u32 = 2;
+const ETHTOOL_PODL_PSE_PW_D_STATUS_SEARCHING: u32 = 3;
+const ETHTOOL_PODL_PSE_PW_D_STATUS_DELIVERING: u32 = 4;
+const ETHTOOL_PODL_PSE_PW_D_STATUS_SLEEP: u32 = 5;
+const ETHTOOL_PODL_PSE_PW_D_STATUS_IDLE: u32 = 6;
+const ETHTOOL_PODL_PSE_PW_D_STATUS_ERROR: u32 = 7;
+
+const ETHTOOL_C33_PSE_PW_D_STATUS_UNKNOWN: u32 = 1;
+const ETHTOOL_C33_PSE_PW_D_STATUS_DISABLED: u32 = 2;
+const ETHTOOL_C33_PSE_PW_D_STATUS_SEARCHING: u32 = 3;
+const ETHTOOL_C33_PSE_PW_D_STATUS_DELIVERING: u32 = 4;
+const ETHTOOL_C33_PSE_PW_D_STATUS_TEST: u32 = 5;
+const ETHTOOL_C33_PSE_PW_D_STATUS_FAULT: u32 = 6;
+const ETHTOOL_C33_PSE_PW_D_STATUS_OTHERFAULT: u32 = 7;
+
+/// Admin state of PoDL (IEEE 802.3 clause 104) and C33 (IEEE 802.3
+/// clause 33, PoE) PSE
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EthtoolPseAdminState {
+    Unknown,
+    Disabled,
+    Enabled,
+    Other(u32),
+}
+
+impl std::fmt::Display for EthtoolPseAdminState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unknown => write!(f, "unknown"),
+            Self::Disabled => write!(f, "disabled"),
+            Self::Enabled => write!(f, "enabled"),
+            Self::Other(d) => write!(f, "{d}"),
+        }
+    }
+}
+
+impl From<u32> for EthtoolPseAdminState {
+    fn from(d: u32) -> Self {
+        match d {
+            ETHTOOL_PSE_ADMIN_STATE_UNKNOWN => Self::Unknown,
+            ETHTOOL_PSE_ADMIN_STATE_DISABLED => Self::Disabled,
+            ETHTOOL_PSE_ADMIN_STATE_ENABLED => Self::Enabled,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<EthtoolPseAdminState> for u32 {
+    fn from(v: EthtoolPseAdminState) -> u32 {
+        match v {
+            EthtoolPseAdminState::Unknown => ETHTOOL_PSE_ADMIN_STATE_UNKNOWN,
+            EthtoolPseAdminState::Disabled => ETHTOOL_PSE_ADMIN_STATE_DISABLED,
+            EthtoolPseAdminState::Enabled => ETHTOOL_PSE_ADMIN_STATE_ENABLED,
+            EthtoolPseAdminState::Other(d) => d,
+        }
+    }
+}
+
+/// Power detection status of PoDL PSE (IEEE 802.3 30.15.1.1.3)
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EthtoolPodlPsePwDStatus {
+    Unknown,
+    Disabled,
+    Searching,
+    Delivering,
+    Sleep,
+    Idle,
+    Error,
+    Other(u32),
+}
+
+impl std::fmt::Display for EthtoolPodlPsePwDStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unknown => write!(f, "unknown"),
+            Self::Disabled => write!(f, "disabled"),
+            Self::Searching => write!(f, "searching"),
+            Self::Delivering => write!(f, "delivering power"),
+            Self::Sleep => write!(f, "sleep"),
+            Self::Idle => write!(f, "idle"),
+            Self::Error => write!(f, "error"),
+            Self::Other(d) => write!(f, "{d}"),
+        }
+    }
+}
+
+impl From<u32> for EthtoolPodlPsePwDStatus {
+    fn from(d: u32) -> Self {
+        match d {
+            ETHTOOL_PODL_PSE_PW_D_STATUS_UNKNOWN => Self::Unknown,
+            ETHTOOL_PODL_PSE_PW_D_STATUS_DISABLED => Self::Disabled,
+            ETHTOOL_PODL_PSE_PW_D_STATUS_SEARCHING => Self::Searching,
+            ETHTOOL_PODL_PSE_PW_D_STATUS_DELIVERING => Self::Delivering,
+            ETHTOOL_PODL_PSE_PW_D_STATUS_SLEEP => Self::Sleep,
+            ETHTOOL_PODL_PSE_PW_D_STATUS_IDLE => Self::Idle,
+            ETHTOOL_PODL_PSE_PW_D_STATUS_ERROR => Self::Error,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<EthtoolPodlPsePwDStatus> for u32 {
+    fn from(v: EthtoolPodlPsePwDStatus) -> u32 {
+        match v {
+            EthtoolPodlPsePwDStatus::Unknown => {
+                ETHTOOL_PODL_PSE_PW_D_STATUS_UNKNOWN
+            }
+            EthtoolPodlPsePwDStatus::Disabled => {
+                ETHTOOL_PODL_PSE_PW_D_STATUS_DISABLED
+            }
+            EthtoolPodlPsePwDStatus::Searching => {
+                ETHTOOL_PODL_PSE_PW_D_STATUS_SEARCHING
+            }
+            EthtoolPodlPsePwDStatus::Delivering => {
+                ETHTOOL_PODL_PSE_PW_D_STATUS_DELIVERING
+            }
+            EthtoolPodlPsePwDStatus::Sleep => {
+                ETHTOOL_PODL_PSE_PW_D_STATUS_SLEEP
+            }
+            EthtoolPodlPsePwDStatus::Idle => ETHTOOL_PODL_PSE_PW_D_STATUS_IDLE,
+            EthtoolPodlPsePwDStatus::Error => {
+                ETHTOOL_PODL_PSE_PW_D_STATUS_ERROR
+            }
+            EthtoolPodlPsePwDStatus::Other(d) => d,
+        }
+    }
+}
+
+/// Power detection status of C33 PSE (IEEE 802.3 30.9.1.1.5)
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EthtoolC33PsePwDStatus {
+    Unknown,
+    Disabled,
+    Searching,
+    Delivering,
+    Test,
+    Fault,
+    OtherFault,
+    Other(u32),
+}
+
+impl std::fmt::Display for EthtoolC33PsePwDStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unknown => write!(f, "unknown"),
+            Self::Disabled => write!(f, "disabled"),
+            Self::Searching => write!(f, "searching"),
+            Self::Delivering => write!(f, "delivering power"),
+            Self::Test => write!(f, "test"),
+            Self::Fault => write!(f, "fault"),
+            Self::OtherFault => write!(f, "otherfault"),
+            Self::Other(d) => write!(f, "{d}"),
+        }
+    }
+}
+
+impl From<u32> for EthtoolC33PsePwDStatus {
+    fn from(d: u32) -> Self {
+        match d {
+            ETHTOOL_C33_PSE_PW_D_STATUS_UNKNOWN => Self::Unknown,
+            ETHTOOL_C33_PSE_PW_D_STATUS_DISABLED => Self::Disabled,
+            ETHTOOL_C33_PSE_PW_D_STATUS_SEARCHING => Self::Searching,
+            ETHTOOL_C33_PSE_PW_D_STATUS_DELIVERING => Self::Delivering,
+            ETHTOOL_C33_PSE_PW_D_STATUS_TEST => Self::Test,
+            ETHTOOL_C33_PSE_PW_D_STATUS_FAULT => Self::Fault,
+            ETHTOOL_C33_PSE_PW_D_STATUS_OTHERFAULT => Self::OtherFault,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<EthtoolC33PsePwDStatus> for u32 {
+    fn from(v: EthtoolC33PsePwDStatus) -> u32 {
+        match v {
+            EthtoolC33PsePwDStatus::Unknown => {
+                ETHTOOL_C33_PSE_PW_D_STATUS_UNKNOWN
+            }
+            EthtoolC33PsePwDStatus::Disabled => {
+                ETHTOOL_C33_PSE_PW_D_STATUS_DISABLED
+            }
+            EthtoolC33PsePwDStatus::Searching => {
+                ETHTOOL_C33_PSE_PW_D_STATUS_SEARCHING
+            }
+            EthtoolC33PsePwDStatus::Delivering => {
+                ETHTOOL_C33_PSE_PW_D_STATUS_DELIVERING
+            }
+            EthtoolC33PsePwDStatus::Test => ETHTOOL_C33_PSE_PW_D_STATUS_TEST,
+            EthtoolC33PsePwDStatus::Fault => ETHTOOL_C33_PSE_PW_D_STATUS_FAULT,
+            EthtoolC33PsePwDStatus::OtherFault => {
+                ETHTOOL_C33_PSE_PW_D_STATUS_OTHERFAULT
+            }
+            EthtoolC33PsePwDStatus::Other(d) => d,
+        }
+    }
+}
+
+/// Range of power limit supported by C33 PSE, in milliwatts
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct EthtoolPsePwLimitRange {
+    pub min: u32,
+    pub max: u32,
+}
+
+impl EthtoolPsePwLimitRange {
+    fn to_nlas(self) -> [EthtoolPsePwLimitAttr; 2] {
+        [
+            EthtoolPsePwLimitAttr(ETHTOOL_A_C33_PSE_PW_LIMIT_MIN, self.min),
+            EthtoolPsePwLimitAttr(ETHTOOL_A_C33_PSE_PW_LIMIT_MAX, self.max),
+        ]
+    }
+}
+
+struct EthtoolPsePwLimitAttr(u16, u32);
+
+impl Nla for EthtoolPsePwLimitAttr {
+    fn value_len(&self) -> usize {
+        4
+    }
+
+    fn kind(&self) -> u16 {
+        self.0
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        emit_u32(buffer, self.1).unwrap()
+    }
+}
+
+fn parse_pw_limit_range(
+    payload: &[u8],
+) -> Result<EthtoolPsePwLimitRange, DecodeError> {
+    let mut min = None;
+    let mut max = None;
+    for nla in NlasIterator::new(payload) {
+        let nla = &nla.context("failed to parse PSE power limit range")?;
+        let value = parse_u32(nla.value())
+            .context(format!("Invalid PSE power limit {:?}", nla.value()))?;
+        match nla.kind() {
+            ETHTOOL_A_C33_PSE_PW_LIMIT_MIN => min = Some(value),
+            ETHTOOL_A_C33_PSE_PW_LIMIT_MAX => max = Some(value),
+            _ => (),
+        }
+    }
+    match (min, max) {
+        (Some(min), Some(max)) => Ok(EthtoolPsePwLimitRange { min, max }),
+        _ => Err(DecodeError::from(
+            "Missing min or max in ETHTOOL_A_C33_PSE_PW_LIMIT_RANGES",
+        )),
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum EthtoolPseAttr {
+    Header(Vec<EthtoolHeader>),
+    PodlAdminState(EthtoolPseAdminState),
+    /// Only in request to enable or disable PoDL PSE
+    PodlAdminControl(EthtoolPseAdminState),
+    PodlPwDStatus(EthtoolPodlPsePwDStatus),
+    C33AdminState(EthtoolPseAdminState),
+    /// Only in request to enable or disable C33 PSE
+    C33AdminControl(EthtoolPseAdminState),
+    C33PwDStatus(EthtoolC33PsePwDStatus),
+    /// Power class of the powered device
+    C33PwClass(u32),
+    /// Actual power drawn, in milliwatts
+    C33ActualPw(u32),
+    /// Power limit, in milliwatts
+    C33AvailPwLimit(u32),
+    /// Each supported power limit range comes in its own attribute
+    C33PwLimitRange(EthtoolPsePwLimitRange),
+    Other(DefaultNla),
+}
+
+impl Nla for EthtoolPseAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Header(hdrs) => hdrs.as_slice().buffer_len(),
+            Self::PodlAdminState(_)
+            | Self::PodlAdminControl(_)
+            | Self::PodlPwDStatus(_)
+            | Self::C33AdminState(_)
+            | Self::C33AdminControl(_)
+            | Self::C33PwDStatus(_)
+            | Self::C33PwClass(_)
+            | Self::C33ActualPw(_)
+            | Self::C33AvailPwLimit(_) => 4,
+            Self::C33PwLimitRange(range) => {
+                range.to_nlas().as_slice().buffer_len()
+            }
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Header(_) => ETHTOOL_A_PSE_HEADER | NLA_F_NESTED,
+            Self::PodlAdminState(_) => ETHTOOL_A_PODL_PSE_ADMIN_STATE,
+            Self::PodlAdminControl(_) => ETHTOOL_A_PODL_PSE_ADMIN_CONTROL,
+            Self::PodlPwDStatus(_) => ETHTOOL_A_PODL_PSE_PW_D_STATUS,
+            Self::C33AdminState(_) => ETHTOOL_A_C33_PSE_ADMIN_STATE,
+            Self::C33AdminControl(_) => ETHTOOL_A_C33_PSE_ADMIN_CONTROL,
+            Self::C33PwDStatus(_) => ETHTOOL_A_C33_PSE_PW_D_STATUS,
+            Self::C33PwClass(_) => ETHTOOL_A_C33_PSE_PW_CLASS,
+            Self::C33ActualPw(_) => ETHTOOL_A_C33_PSE_ACTUAL_PW,
+            Self::C33AvailPwLimit(_) => ETHTOOL_A_C33_PSE_AVAIL_PW_LIMIT,
+            Self::C33PwLimitRange(_) => {
+                ETHTOOL_A_C33_PSE_PW_LIMIT_RANGES | NLA_F_NESTED
+            }
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Header(ref nlas) => nlas.as_slice().emit(buffer),
+            Self::PodlAdminState(d)
+            | Self::PodlAdminControl(d)
+            | Self::C33AdminState(d)
+            | Self::C33AdminControl(d) => {
+                emit_u32(buffer, (*d).into()).unwrap()
+            }
+            Self::PodlPwDStatus(d) => emit_u32(buffer, (*d).into()).unwrap(),
+            Self::C33PwDStatus(d) => emit_u32(buffer, (*d).into()).unwrap(),
+            Self::C33PwClass(d)
+            | Self::C33ActualPw(d)
+            | Self::C33AvailPwLimit(d) => emit_u32(buffer, *d).unwrap(),
+            Self::C33PwLimitRange(range) => {
+                range.to_nlas().as_slice().emit(buffer)
+            }
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for EthtoolPseAttr
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            ETHTOOL_A_PSE_HEADER => {
+                let mut nlas = Vec::new();
+                let error_msg = "failed to parse pse header attributes";
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    let parsed =
+                        EthtoolHeader::parse(nla).context(error_msg)?;
+                    nlas.push(parsed);
+                }
+                Self::Header(nlas)
+            }
+            ETHTOOL_A_PODL_PSE_ADMIN_STATE => Self::PodlAdminState(
+                parse_u32(payload)
+                    .context("Invalid ETHTOOL_A_PODL_PSE_ADMIN_STATE value")?
+                    .into(),
+            ),
+            ETHTOOL_A_PODL_PSE_ADMIN_CONTROL => Self::PodlAdminControl(
+                parse_u32(payload)
+                    .context("Invalid ETHTOOL_A_PODL_PSE_ADMIN_CONTROL value")?
+                    .into(),
+            ),
+            ETHTOOL_A_PODL_PSE_PW_D_STATUS => Self::PodlPwDStatus(
+                parse_u32(payload)
+                    .context("Invalid ETHTOOL_A_PODL_PSE_PW_D_STATUS value")?
+                    .into(),
+            ),
+            ETHTOOL_A_C33_PSE_ADMIN_STATE => Self::C33AdminState(
+                parse_u32(payload)
+                    .context("Invalid ETHTOOL_A_C33_PSE_ADMIN_STATE value")?
+                    .into(),
+            ),
+            ETHTOOL_A_C33_PSE_ADMIN_CONTROL => Self::C33AdminControl(
+                parse_u32(payload)
+                    .context("Invalid ETHTOOL_A_C33_PSE_ADMIN_CONTROL value")?
+                    .into(),
+            ),
+            ETHTOOL_A_C33_PSE_PW_D_STATUS => Self::C33PwDStatus(
+                parse_u32(payload)
+                    .context("Invalid ETHTOOL_A_C33_PSE_PW_D_STATUS value")?
+                    .into(),
+            ),
+            ETHTOOL_A_C33_PSE_PW_CLASS => Self::C33PwClass(
+                parse_u32(payload)
+                    .context("Invalid ETHTOOL_A_C33_PSE_PW_CLASS value")?,
+            ),
+            ETHTOOL_A_C33_PSE_ACTUAL_PW => Self::C33ActualPw(
+                parse_u32(payload)
+                    .context("Invalid ETHTOOL_A_C33_PSE_ACTUAL_PW value")?,
+            ),
+            ETHTOOL_A_C33_PSE_AVAIL_PW_LIMIT => {
+                Self::C33AvailPwLimit(parse_u32(payload).context(
+                    "Invalid ETHTOOL_A_C33_PSE_AVAIL_PW_LIMIT value",
+                )?)
+            }
+            ETHTOOL_A_C33_PSE_PW_LIMIT_RANGES => Self::C33PwLimitRange(
+                parse_pw_limit_range(payload)
+                    .context("Invalid ETHTOOL_A_C33_PSE_PW_LIMIT_RANGES")?,
+            ),
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("invalid ethtool pse NLA kind {kind}"))?,
+            ),
+        })
+    }
+}
+
+pub(crate) fn parse_pse_nlas(
+    buffer: &[u8],
+) -> Result<Vec<EthtoolAttr>, DecodeError> {
+    let mut nlas = Vec::new();
+    for nla in NlasIterator::new(buffer) {
+        let error_msg =
+            format!("Failed to parse ethtool pse message attribute {nla:?}");
+        let nla = &nla.context(error_msg.clone())?;
+        let parsed = EthtoolPseAttr::parse(nla).context(error_msg)?;
+        nlas.push(EthtoolAttr::Pse(parsed));
+    }
+    Ok(nlas)
+}
@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{EthtoolHandle, EthtoolPseGetRequest, EthtoolPseSetRequest};
+
+pub struct EthtoolPseHandle(EthtoolHandle);
+
+impl EthtoolPseHandle {
+    pub fn new(handle: EthtoolHandle) -> Self {
+        EthtoolPseHandle(handle)
+    }
+
+    /// Retrieve the Power Sourcing Equipment status of a interface (used by
+    /// `ethtool --show-pse eth1`)
+    pub fn get(&mut self, iface_name: Option<&str>) -> EthtoolPseGetRequest {
+        EthtoolPseGetRequest::new(self.0.clone(), iface_name)
+    }
+
+    /// Change the Power Sourcing Equipment settings of a interface (used by
+    /// `ethtool --set-pse eth1 c33-pse-admin-control enable`)
+    pub fn set(&mut self, iface_name: &str) -> EthtoolPseSetRequest {
+        EthtoolPseSetRequest::new(self.0.clone(), iface_name)
+    }
+}
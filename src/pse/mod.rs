@@ -0,0 +1,16 @@
+// SPDX-License-Identifier: MIT
+
+mod attr;
+mod get;
+mod handle;
+mod set;
+
+pub(crate) use attr::parse_pse_nlas;
+
+pub use attr::{
+    EthtoolC33PsePwDStatus, EthtoolPodlPsePwDStatus, EthtoolPseAdminState,
+    EthtoolPseAttr, EthtoolPsePwLimitRange,
+};
+pub use get::EthtoolPseGetRequest;
+pub use handle::EthtoolPseHandle;
+pub use set::EthtoolPseSetRequest;
@@ -0,0 +1,87 @@
+// SPDX-License-Identifier: MIT
+
+use futures_util::StreamExt;
+use netlink_packet_core::{NetlinkMessage, NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    try_ethtool, EthtoolAttr, EthtoolError, EthtoolHandle, EthtoolMessage,
+    EthtoolPseAdminState, EthtoolPseAttr,
+};
+
+pub struct EthtoolPseSetRequest {
+    handle: EthtoolHandle,
+    message: EthtoolMessage,
+    podl_admin_control: Option<EthtoolPseAdminState>,
+    c33_admin_control: Option<EthtoolPseAdminState>,
+    c33_avail_pw_limit: Option<u32>,
+}
+
+impl EthtoolPseSetRequest {
+    pub(crate) fn new(handle: EthtoolHandle, iface_name: &str) -> Self {
+        EthtoolPseSetRequest {
+            handle,
+            message: EthtoolMessage::new_pse_set(iface_name),
+            podl_admin_control: None,
+            c33_admin_control: None,
+            c33_avail_pw_limit: None,
+        }
+    }
+
+    /// Enable or disable PoDL PSE
+    pub fn podl_admin_control(mut self, state: EthtoolPseAdminState) -> Self {
+        self.podl_admin_control = Some(state);
+        self
+    }
+
+    /// Enable or disable C33 PSE
+    pub fn c33_admin_control(mut self, state: EthtoolPseAdminState) -> Self {
+        self.c33_admin_control = Some(state);
+        self
+    }
+
+    /// Power limit of C33 PSE in milliwatts
+    pub fn c33_avail_pw_limit(mut self, limit: u32) -> Self {
+        self.c33_avail_pw_limit = Some(limit);
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), EthtoolError> {
+        let EthtoolPseSetRequest {
+            mut handle,
+            mut message,
+            podl_admin_control,
+            c33_admin_control,
+            c33_avail_pw_limit,
+        } = self;
+
+        if let Some(state) = podl_admin_control {
+            message.nlas.push(EthtoolAttr::Pse(
+                EthtoolPseAttr::PodlAdminControl(state),
+            ));
+        }
+        if let Some(state) = c33_admin_control {
+            message
+                .nlas
+                .push(EthtoolAttr::Pse(EthtoolPseAttr::C33AdminControl(state)));
+        }
+        if let Some(limit) = c33_avail_pw_limit {
+            message
+                .nlas
+                .push(EthtoolAttr::Pse(EthtoolPseAttr::C33AvailPwLimit(limit)));
+        }
+
+        let mut nl_msg =
+            NetlinkMessage::from(GenlMessage::from_payload(message));
+
+        nl_msg.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        let mut response = handle.request(nl_msg).await?;
+
+        while let Some(message) = response.next().await {
+            try_ethtool!(message);
+        }
+
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: MIT
 
-use crate::{EthtoolHandle, EthtoolRingGetRequest};
+use crate::{EthtoolHandle, EthtoolRingGetRequest, EthtoolRingSetRequest};
 
 pub struct EthtoolRingHandle(EthtoolHandle);
 
@@ -14,4 +14,10 @@ impl EthtoolRingHandle {
     pub fn get(&mut self, iface_name: Option<&str>) -> EthtoolRingGetRequest {
         EthtoolRingGetRequest::new(self.0.clone(), iface_name)
     }
+
+    /// Set the ethtool rings of a interface (equivalent to `ethtool -G
+    /// eth1`)
+    pub fn set(&mut self, iface_name: &str) -> EthtoolRingSetRequest {
+        EthtoolRingSetRequest::new(self.0.clone(), iface_name)
+    }
 }
@@ -0,0 +1,264 @@
+// SPDX-License-Identifier: MIT
+
+use futures_util::StreamExt;
+use netlink_packet_core::{NetlinkMessage, NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    try_ethtool, EthtoolAttr, EthtoolError, EthtoolHandle, EthtoolMessage,
+    EthtoolRingAttr, EthtoolRingGetRequest,
+};
+
+pub struct EthtoolRingSetRequest {
+    handle: EthtoolHandle,
+    iface_name: String,
+    message: EthtoolMessage,
+    rx: Option<u32>,
+    rx_mini: Option<u32>,
+    rx_jumbo: Option<u32>,
+    tx: Option<u32>,
+    rx_buf_len: Option<u32>,
+    tcp_data_split: Option<u8>,
+    cqe_size: Option<u32>,
+    tx_push: Option<bool>,
+}
+
+impl EthtoolRingSetRequest {
+    pub(crate) fn new(handle: EthtoolHandle, iface_name: &str) -> Self {
+        EthtoolRingSetRequest {
+            handle,
+            iface_name: iface_name.to_string(),
+            message: EthtoolMessage::new_ring_set(iface_name),
+            rx: None,
+            rx_mini: None,
+            rx_jumbo: None,
+            tx: None,
+            rx_buf_len: None,
+            tcp_data_split: None,
+            cqe_size: None,
+            tx_push: None,
+        }
+    }
+
+    pub fn rx(mut self, size: u32) -> Self {
+        self.rx = Some(size);
+        self
+    }
+
+    pub fn rx_mini(mut self, size: u32) -> Self {
+        self.rx_mini = Some(size);
+        self
+    }
+
+    pub fn rx_jumbo(mut self, size: u32) -> Self {
+        self.rx_jumbo = Some(size);
+        self
+    }
+
+    pub fn tx(mut self, size: u32) -> Self {
+        self.tx = Some(size);
+        self
+    }
+
+    pub fn rx_buf_len(mut self, len: u32) -> Self {
+        self.rx_buf_len = Some(len);
+        self
+    }
+
+    /// TCP header/data split, using the kernel `ETHTOOL_TCP_DATA_SPLIT_*`
+    /// values: 0 for unknown(driver default), 1 for disabled and 2 for
+    /// enabled.
+    pub fn tcp_data_split(mut self, value: u8) -> Self {
+        self.tcp_data_split = Some(value);
+        self
+    }
+
+    pub fn cqe_size(mut self, size: u32) -> Self {
+        self.cqe_size = Some(size);
+        self
+    }
+
+    pub fn tx_push(mut self, enabled: bool) -> Self {
+        self.tx_push = Some(enabled);
+        self
+    }
+
+    /// The requested ring sizes are validated against the maximum sizes
+    /// reported by kernel before sending the change.
+    pub async fn execute(self) -> Result<(), EthtoolError> {
+        let EthtoolRingSetRequest {
+            mut handle,
+            iface_name,
+            mut message,
+            rx,
+            rx_mini,
+            rx_jumbo,
+            tx,
+            rx_buf_len,
+            tcp_data_split,
+            cqe_size,
+            tx_push,
+        } = self;
+
+        if rx.is_some()
+            || rx_mini.is_some()
+            || rx_jumbo.is_some()
+            || tx.is_some()
+        {
+            let max_attrs = get_ring_max(&handle, &iface_name).await?;
+            check_ring_max(&iface_name, rx, rx_mini, rx_jumbo, tx, &max_attrs)?;
+        }
+
+        if let Some(size) = rx {
+            message
+                .nlas
+                .push(EthtoolAttr::Ring(EthtoolRingAttr::Rx(size)));
+        }
+        if let Some(size) = rx_mini {
+            message
+                .nlas
+                .push(EthtoolAttr::Ring(EthtoolRingAttr::RxMini(size)));
+        }
+        if let Some(size) = rx_jumbo {
+            message
+                .nlas
+                .push(EthtoolAttr::Ring(EthtoolRingAttr::RxJumbo(size)));
+        }
+        if let Some(size) = tx {
+            message
+                .nlas
+                .push(EthtoolAttr::Ring(EthtoolRingAttr::Tx(size)));
+        }
+        if let Some(len) = rx_buf_len {
+            message
+                .nlas
+                .push(EthtoolAttr::Ring(EthtoolRingAttr::RxBufLen(len)));
+        }
+        if let Some(value) = tcp_data_split {
+            message
+                .nlas
+                .push(EthtoolAttr::Ring(EthtoolRingAttr::TcpDataSplit(value)));
+        }
+        if let Some(size) = cqe_size {
+            message
+                .nlas
+                .push(EthtoolAttr::Ring(EthtoolRingAttr::CqeSize(size)));
+        }
+        if let Some(enabled) = tx_push {
+            message
+                .nlas
+                .push(EthtoolAttr::Ring(EthtoolRingAttr::TxPush(enabled)));
+        }
+
+        let mut nl_msg =
+            NetlinkMessage::from(GenlMessage::from_payload(message));
+
+        nl_msg.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        let mut response = handle.request(nl_msg).await?;
+
+        while let Some(message) = response.next().await {
+            try_ethtool!(message);
+        }
+
+        Ok(())
+    }
+}
+
+// Fail when a requested ring size exceeds its maximum in `max_attrs`
+fn check_ring_max(
+    iface_name: &str,
+    rx: Option<u32>,
+    rx_mini: Option<u32>,
+    rx_jumbo: Option<u32>,
+    tx: Option<u32>,
+    max_attrs: &[EthtoolRingAttr],
+) -> Result<(), EthtoolError> {
+    for attr in max_attrs {
+        let (name, size, max) = match attr {
+            EthtoolRingAttr::RxMax(max) => ("rx", rx, max),
+            EthtoolRingAttr::RxMiniMax(max) => ("rx-mini", rx_mini, max),
+            EthtoolRingAttr::RxJumboMax(max) => ("rx-jumbo", rx_jumbo, max),
+            EthtoolRingAttr::TxMax(max) => ("tx", tx, max),
+            _ => continue,
+        };
+        if let Some(size) = size {
+            if size > *max {
+                return Err(EthtoolError::InvalidArgument(format!(
+                    "Requested {name} ring size {size} of interface \
+                    {iface_name} exceeds the maximum {max}"
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn get_ring_max(
+    handle: &EthtoolHandle,
+    iface_name: &str,
+) -> Result<Vec<EthtoolRingAttr>, EthtoolError> {
+    let mut response =
+        EthtoolRingGetRequest::new(handle.clone(), Some(iface_name))
+            .execute()
+            .await?;
+
+    let mut max_attrs = Vec::new();
+    while let Some(message) = response.next().await {
+        for nla in message?.payload.nlas {
+            if let EthtoolAttr::Ring(
+                attr @ (EthtoolRingAttr::RxMax(_)
+                | EthtoolRingAttr::RxMiniMax(_)
+                | EthtoolRingAttr::RxJumboMax(_)
+                | EthtoolRingAttr::TxMax(_)),
+            ) = nla
+            {
+                max_attrs.push(attr);
+            }
+        }
+    }
+    Ok(max_attrs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAX_ATTRS: [EthtoolRingAttr; 2] =
+        [EthtoolRingAttr::RxMax(4096), EthtoolRingAttr::TxMax(4096)];
+
+    #[test]
+    fn test_check_ring_max_exceeded() {
+        assert!(matches!(
+            check_ring_max("eth0", Some(8192), None, None, None, &MAX_ATTRS),
+            Err(EthtoolError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            check_ring_max("eth0", None, None, None, Some(4097), &MAX_ATTRS),
+            Err(EthtoolError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_check_ring_max_within() {
+        assert!(check_ring_max(
+            "eth0",
+            Some(4096),
+            None,
+            None,
+            Some(512),
+            &MAX_ATTRS
+        )
+        .is_ok());
+        // No maximum reported for rx-mini
+        assert!(check_ring_max(
+            "eth0",
+            None,
+            Some(8192),
+            None,
+            None,
+            &MAX_ATTRS
+        )
+        .is_ok());
+    }
+}
@@ -0,0 +1,199 @@
+// SPDX-License-Identifier: MIT
+
+use futures_util::StreamExt;
+use netlink_packet_core::{NetlinkMessage, NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use super::set::EthtoolRssIndir;
+use crate::{
+    try_ethtool, EthtoolAttr, EthtoolCmd, EthtoolError, EthtoolHandle,
+    EthtoolMessage, EthtoolRssAttr, EthtoolRssHashFunction,
+    EthtoolRssInputXfrm,
+};
+
+pub struct EthtoolRssCreateRequest {
+    handle: EthtoolHandle,
+    iface_name: String,
+    context: Option<u32>,
+    indir: Option<EthtoolRssIndir>,
+    hkey: Option<Vec<u8>>,
+    hfunc: Option<EthtoolRssHashFunction>,
+    input_xfrm: Option<EthtoolRssInputXfrm>,
+}
+
+impl EthtoolRssCreateRequest {
+    pub(crate) fn new(handle: EthtoolHandle, iface_name: &str) -> Self {
+        EthtoolRssCreateRequest {
+            handle,
+            iface_name: iface_name.to_string(),
+            context: None,
+            indir: None,
+            hkey: None,
+            hfunc: None,
+            input_xfrm: None,
+        }
+    }
+
+    /// Request this context ID instead of letting kernel allocate one
+    pub fn context(mut self, context: u32) -> Self {
+        self.context = Some(context);
+        self
+    }
+
+    /// Set the indirection table of the new context, see
+    /// `EthtoolRssSetRequest::indir()`
+    pub fn indir(mut self, table: &[u32]) -> Self {
+        self.indir = Some(EthtoolRssIndir::Table(table.to_vec()));
+        self
+    }
+
+    /// Spread the indirection table equally over the first `queue_count`
+    /// receive queues
+    pub fn equal(mut self, queue_count: u32) -> Self {
+        self.indir = Some(EthtoolRssIndir::Equal(queue_count));
+        self
+    }
+
+    /// Spread the indirection table over receive queues proportionally to
+    /// the weights, indexed by queue
+    pub fn weight(mut self, weights: &[u32]) -> Self {
+        self.indir = Some(EthtoolRssIndir::Weight(weights.to_vec()));
+        self
+    }
+
+    pub fn hkey(mut self, key: &[u8]) -> Self {
+        self.hkey = Some(key.to_vec());
+        self
+    }
+
+    pub fn hfunc(mut self, hfunc: EthtoolRssHashFunction) -> Self {
+        self.hfunc = Some(hfunc);
+        self
+    }
+
+    pub fn input_xfrm(mut self, input_xfrm: EthtoolRssInputXfrm) -> Self {
+        self.input_xfrm = Some(input_xfrm);
+        self
+    }
+
+    /// Return the ID of created RSS context
+    pub async fn execute(self) -> Result<u32, EthtoolError> {
+        let EthtoolRssCreateRequest {
+            mut handle,
+            iface_name,
+            context,
+            indir,
+            hkey,
+            hfunc,
+            input_xfrm,
+        } = self;
+
+        // The new context uses the table size of the default context.
+        let indir = match indir {
+            Some(indir) => {
+                Some(indir.into_table(&handle, &iface_name, None).await?)
+            }
+            None => None,
+        };
+
+        let mut message = EthtoolMessage::new_rss_create_act(&iface_name);
+        if let Some(context) = context {
+            message
+                .nlas
+                .push(EthtoolAttr::Rss(EthtoolRssAttr::Context(context)));
+        }
+        if let Some(hfunc) = hfunc {
+            message
+                .nlas
+                .push(EthtoolAttr::Rss(EthtoolRssAttr::Hfunc(hfunc)));
+        }
+        if let Some(indir) = indir {
+            message
+                .nlas
+                .push(EthtoolAttr::Rss(EthtoolRssAttr::Indir(indir)));
+        }
+        if let Some(hkey) = hkey {
+            message
+                .nlas
+                .push(EthtoolAttr::Rss(EthtoolRssAttr::Hkey(hkey)));
+        }
+        if let Some(input_xfrm) = input_xfrm {
+            message
+                .nlas
+                .push(EthtoolAttr::Rss(EthtoolRssAttr::InputXfrm(input_xfrm)));
+        }
+
+        let mut nl_msg =
+            NetlinkMessage::from(GenlMessage::from_payload(message));
+
+        nl_msg.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        let mut response = handle.request(nl_msg).await?;
+
+        let mut created = None;
+        while let Some(message) = response.next().await {
+            let reply = try_ethtool!(message).payload;
+            if reply.cmd != EthtoolCmd::RssCreateActReply {
+                continue;
+            }
+            for nla in reply.nlas {
+                if let EthtoolAttr::Rss(EthtoolRssAttr::Context(context)) = nla
+                {
+                    created = Some(context);
+                }
+            }
+        }
+
+        // Kernel always replies the ID of created context, even when the ID
+        // was requested.
+        created.ok_or_else(|| {
+            EthtoolError::RequestFailed(format!(
+                "Kernel replied no RSS context ID when creating RSS context \
+                on {iface_name}"
+            ))
+        })
+    }
+}
+
+pub struct EthtoolRssDeleteRequest {
+    handle: EthtoolHandle,
+    iface_name: String,
+    context: u32,
+}
+
+impl EthtoolRssDeleteRequest {
+    pub(crate) fn new(
+        handle: EthtoolHandle,
+        iface_name: &str,
+        context: u32,
+    ) -> Self {
+        EthtoolRssDeleteRequest {
+            handle,
+            iface_name: iface_name.to_string(),
+            context,
+        }
+    }
+
+    pub async fn execute(self) -> Result<(), EthtoolError> {
+        let EthtoolRssDeleteRequest {
+            mut handle,
+            iface_name,
+            context,
+        } = self;
+
+        let message = EthtoolMessage::new_rss_delete_act(&iface_name, context);
+
+        let mut nl_msg =
+            NetlinkMessage::from(GenlMessage::from_payload(message));
+
+        nl_msg.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        let mut response = handle.request(nl_msg).await?;
+
+        while let Some(message) = response.next().await {
+            try_ethtool!(message);
+        }
+
+        Ok(())
+    }
+}
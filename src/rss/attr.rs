@@ -0,0 +1,233 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32, parse_u32, DecodeError, DefaultNla, Emitable, ErrorContext, Nla,
+    NlaBuffer, NlasIterator, Parseable, NLA_F_NESTED,
+};
+
+use crate::{EthtoolAttr, EthtoolHeader};
+
+const ETHTOOL_A_RSS_HEADER: u16 = 1;
+const ETHTOOL_A_RSS_CONTEXT: u16 = 2;
+const ETHTOOL_A_RSS_HFUNC: u16 = 3;
+const ETHTOOL_A_RSS_INDIR: u16 = 4;
+const ETHTOOL_A_RSS_HKEY: u16 = 5;
+const ETHTOOL_A_RSS_INPUT_XFRM: u16 = 6;
+const ETHTOOL_A_RSS_START_CONTEXT: u16 = 7;
+
+const ETH_RSS_HASH_TOP: u32 = 1 << 0;
+const ETH_RSS_HASH_XOR: u32 = 1 << 1;
+const ETH_RSS_HASH_CRC32: u32 = 1 << 2;
+
+const RXH_XFRM_NONE: u32 = 0;
+const RXH_XFRM_SYM_XOR: u32 = 1 << 0;
+const RXH_XFRM_SYM_OR_XOR: u32 = 1 << 1;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EthtoolRssHashFunction {
+    Toeplitz,
+    Xor,
+    Crc32,
+    /// Hash function bit mask unknown to this crate
+    Other(u32),
+}
+
+impl std::fmt::Display for EthtoolRssHashFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Toeplitz => write!(f, "toeplitz"),
+            Self::Xor => write!(f, "xor"),
+            Self::Crc32 => write!(f, "crc32"),
+            Self::Other(d) => write!(f, "{d:#x}"),
+        }
+    }
+}
+
+impl From<u32> for EthtoolRssHashFunction {
+    fn from(d: u32) -> Self {
+        match d {
+            ETH_RSS_HASH_TOP => Self::Toeplitz,
+            ETH_RSS_HASH_XOR => Self::Xor,
+            ETH_RSS_HASH_CRC32 => Self::Crc32,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<EthtoolRssHashFunction> for u32 {
+    fn from(v: EthtoolRssHashFunction) -> u32 {
+        match v {
+            EthtoolRssHashFunction::Toeplitz => ETH_RSS_HASH_TOP,
+            EthtoolRssHashFunction::Xor => ETH_RSS_HASH_XOR,
+            EthtoolRssHashFunction::Crc32 => ETH_RSS_HASH_CRC32,
+            EthtoolRssHashFunction::Other(d) => d,
+        }
+    }
+}
+
+/// Transformation applied to the input fields before hashing
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EthtoolRssInputXfrm {
+    None,
+    /// Symmetric hash by XOR of source and destination fields
+    SymXor,
+    /// Symmetric hash by OR and XOR of source and destination fields
+    SymOrXor,
+    Other(u32),
+}
+
+impl From<u32> for EthtoolRssInputXfrm {
+    fn from(d: u32) -> Self {
+        match d {
+            RXH_XFRM_NONE => Self::None,
+            RXH_XFRM_SYM_XOR => Self::SymXor,
+            RXH_XFRM_SYM_OR_XOR => Self::SymOrXor,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<EthtoolRssInputXfrm> for u32 {
+    fn from(v: EthtoolRssInputXfrm) -> u32 {
+        match v {
+            EthtoolRssInputXfrm::None => RXH_XFRM_NONE,
+            EthtoolRssInputXfrm::SymXor => RXH_XFRM_SYM_XOR,
+            EthtoolRssInputXfrm::SymOrXor => RXH_XFRM_SYM_OR_XOR,
+            EthtoolRssInputXfrm::Other(d) => d,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum EthtoolRssAttr {
+    Header(Vec<EthtoolHeader>),
+    /// RSS context ID, 0 or absent for the default context
+    Context(u32),
+    Hfunc(EthtoolRssHashFunction),
+    /// Indirection table, mapping hash to receive queue. Empty table in set
+    /// request resets it to the default.
+    Indir(Vec<u32>),
+    Hkey(Vec<u8>),
+    InputXfrm(EthtoolRssInputXfrm),
+    /// Dump contexts starting from this ID
+    StartContext(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for EthtoolRssAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Header(hdrs) => hdrs.as_slice().buffer_len(),
+            Self::Context(_)
+            | Self::Hfunc(_)
+            | Self::InputXfrm(_)
+            | Self::StartContext(_) => 4,
+            Self::Indir(indir) => indir.len() * 4,
+            Self::Hkey(key) => key.len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Header(_) => ETHTOOL_A_RSS_HEADER | NLA_F_NESTED,
+            Self::Context(_) => ETHTOOL_A_RSS_CONTEXT,
+            Self::Hfunc(_) => ETHTOOL_A_RSS_HFUNC,
+            Self::Indir(_) => ETHTOOL_A_RSS_INDIR,
+            Self::Hkey(_) => ETHTOOL_A_RSS_HKEY,
+            Self::InputXfrm(_) => ETHTOOL_A_RSS_INPUT_XFRM,
+            Self::StartContext(_) => ETHTOOL_A_RSS_START_CONTEXT,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Header(ref nlas) => nlas.as_slice().emit(buffer),
+            Self::Context(d) | Self::StartContext(d) => {
+                emit_u32(buffer, *d).unwrap()
+            }
+            Self::Hfunc(d) => emit_u32(buffer, (*d).into()).unwrap(),
+            Self::InputXfrm(d) => emit_u32(buffer, (*d).into()).unwrap(),
+            Self::Indir(indir) => {
+                for (i, queue) in indir.iter().enumerate() {
+                    emit_u32(&mut buffer[i * 4..], *queue).unwrap();
+                }
+            }
+            Self::Hkey(key) => buffer.copy_from_slice(key),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for EthtoolRssAttr
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            ETHTOOL_A_RSS_HEADER => {
+                let mut nlas = Vec::new();
+                let error_msg = "failed to parse rss header attributes";
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    let parsed =
+                        EthtoolHeader::parse(nla).context(error_msg)?;
+                    nlas.push(parsed);
+                }
+                Self::Header(nlas)
+            }
+            ETHTOOL_A_RSS_CONTEXT => {
+                Self::Context(parse_u32(payload).context(format!(
+                    "Invalid ETHTOOL_A_RSS_CONTEXT {payload:?}"
+                ))?)
+            }
+            ETHTOOL_A_RSS_HFUNC => Self::Hfunc(
+                parse_u32(payload)
+                    .context(format!(
+                        "Invalid ETHTOOL_A_RSS_HFUNC {payload:?}"
+                    ))?
+                    .into(),
+            ),
+            ETHTOOL_A_RSS_INDIR => {
+                let mut indir = Vec::new();
+                for chunk in payload.chunks(4) {
+                    indir.push(parse_u32(chunk).context(format!(
+                        "Invalid ETHTOOL_A_RSS_INDIR {payload:?}"
+                    ))?);
+                }
+                Self::Indir(indir)
+            }
+            ETHTOOL_A_RSS_HKEY => Self::Hkey(payload.to_vec()),
+            ETHTOOL_A_RSS_INPUT_XFRM => Self::InputXfrm(
+                parse_u32(payload)
+                    .context(format!(
+                        "Invalid ETHTOOL_A_RSS_INPUT_XFRM {payload:?}"
+                    ))?
+                    .into(),
+            ),
+            ETHTOOL_A_RSS_START_CONTEXT => {
+                Self::StartContext(parse_u32(payload).context(format!(
+                    "Invalid ETHTOOL_A_RSS_START_CONTEXT {payload:?}"
+                ))?)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?,
+            ),
+        })
+    }
+}
+
+pub(crate) fn parse_rss_nlas(
+    buffer: &[u8],
+) -> Result<Vec<EthtoolAttr>, DecodeError> {
+    let mut nlas = Vec::new();
+    for nla in NlasIterator::new(buffer) {
+        let error_msg =
+            format!("Failed to parse ethtool rss message attribute {nla:?}");
+        let nla = &nla.context(error_msg.clone())?;
+        let parsed = EthtoolRssAttr::parse(nla).context(error_msg)?;
+        nlas.push(EthtoolAttr::Rss(parsed));
+    }
+    Ok(nlas)
+}
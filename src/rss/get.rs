@@ -0,0 +1,67 @@
+// SPDX-License-Identifier: MIT
+
+use futures_util::Stream;
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    ethtool_execute, EthtoolAttr, EthtoolError, EthtoolHandle, EthtoolMessage,
+    EthtoolRssAttr,
+};
+
+pub struct EthtoolRssGetRequest {
+    handle: EthtoolHandle,
+    iface_name: Option<String>,
+    context: Option<u32>,
+    start_context: Option<u32>,
+}
+
+impl EthtoolRssGetRequest {
+    pub(crate) fn new(handle: EthtoolHandle, iface_name: Option<&str>) -> Self {
+        EthtoolRssGetRequest {
+            handle,
+            iface_name: iface_name.map(|i| i.to_string()),
+            context: None,
+            start_context: None,
+        }
+    }
+
+    /// Query additional RSS context instead of the default one
+    pub fn context(mut self, context: u32) -> Self {
+        self.context = Some(context);
+        self
+    }
+
+    /// When dumping, skip the RSS contexts of ID lower than this
+    pub fn start_context(mut self, context: u32) -> Self {
+        self.start_context = Some(context);
+        self
+    }
+
+    pub async fn execute(
+        self,
+    ) -> Result<
+        impl Stream<Item = Result<GenlMessage<EthtoolMessage>, EthtoolError>>,
+        EthtoolError,
+    > {
+        let EthtoolRssGetRequest {
+            mut handle,
+            iface_name,
+            context,
+            start_context,
+        } = self;
+
+        let mut ethtool_msg =
+            EthtoolMessage::new_rss_get(iface_name.as_deref());
+        if let Some(context) = context {
+            ethtool_msg
+                .nlas
+                .push(EthtoolAttr::Rss(EthtoolRssAttr::Context(context)));
+        }
+        if let Some(context) = start_context {
+            ethtool_msg
+                .nlas
+                .push(EthtoolAttr::Rss(EthtoolRssAttr::StartContext(context)));
+        }
+        ethtool_execute(&mut handle, iface_name.is_none(), ethtool_msg).await
+    }
+}
@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    EthtoolHandle, EthtoolRssCreateRequest, EthtoolRssDeleteRequest,
+    EthtoolRssGetRequest, EthtoolRssSetRequest,
+};
+
+pub struct EthtoolRssHandle(EthtoolHandle);
+
+impl EthtoolRssHandle {
+    pub fn new(handle: EthtoolHandle) -> Self {
+        EthtoolRssHandle(handle)
+    }
+
+    /// Retrieve the RSS indirection table, hash key and hash function of a
+    /// interface (used by `ethtool -x eth1`). Without interface name, every
+    /// RSS context of every interface is dumped, with `EthtoolRssAttr::Context`
+    /// included for additional contexts.
+    pub fn get(&mut self, iface_name: Option<&str>) -> EthtoolRssGetRequest {
+        EthtoolRssGetRequest::new(self.0.clone(), iface_name)
+    }
+
+    /// Change the RSS configuration of a interface (used by
+    /// `ethtool -X eth1 equal 4 hfunc toeplitz`)
+    pub fn set(&mut self, iface_name: &str) -> EthtoolRssSetRequest {
+        EthtoolRssSetRequest::new(self.0.clone(), iface_name)
+    }
+
+    /// Create additional RSS context on a interface, returning its ID (used
+    /// by `ethtool -X eth1 context new equal 2`)
+    pub fn create(&mut self, iface_name: &str) -> EthtoolRssCreateRequest {
+        EthtoolRssCreateRequest::new(self.0.clone(), iface_name)
+    }
+
+    /// Delete additional RSS context of a interface (used by
+    /// `ethtool -X eth1 context 1 delete`)
+    pub fn delete(
+        &mut self,
+        iface_name: &str,
+        context: u32,
+    ) -> EthtoolRssDeleteRequest {
+        EthtoolRssDeleteRequest::new(self.0.clone(), iface_name, context)
+    }
+}
@@ -0,0 +1,15 @@
+// SPDX-License-Identifier: MIT
+
+mod act;
+mod attr;
+mod get;
+mod handle;
+mod set;
+
+pub(crate) use attr::parse_rss_nlas;
+
+pub use act::{EthtoolRssCreateRequest, EthtoolRssDeleteRequest};
+pub use attr::{EthtoolRssAttr, EthtoolRssHashFunction, EthtoolRssInputXfrm};
+pub use get::EthtoolRssGetRequest;
+pub use handle::EthtoolRssHandle;
+pub use set::EthtoolRssSetRequest;
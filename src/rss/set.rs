@@ -0,0 +1,281 @@
+// SPDX-License-Identifier: MIT
+
+use futures_util::StreamExt;
+use netlink_packet_core::{NetlinkMessage, NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    try_ethtool, EthtoolAttr, EthtoolError, EthtoolHandle, EthtoolMessage,
+    EthtoolRssAttr, EthtoolRssGetRequest, EthtoolRssHashFunction,
+    EthtoolRssInputXfrm,
+};
+
+#[derive(Debug, Clone)]
+pub(crate) enum EthtoolRssIndir {
+    Table(Vec<u32>),
+    Equal(u32),
+    Weight(Vec<u32>),
+}
+
+impl EthtoolRssIndir {
+    // The table size of `Equal` and `Weight` is the one of the given context
+    pub(crate) async fn into_table(
+        self,
+        handle: &EthtoolHandle,
+        iface_name: &str,
+        context: Option<u32>,
+    ) -> Result<Vec<u32>, EthtoolError> {
+        match self {
+            Self::Table(table) => Ok(table),
+            Self::Equal(queue_count) => {
+                if queue_count == 0 {
+                    return Err(EthtoolError::InvalidArgument(
+                        "RSS queue count should not be 0".to_string(),
+                    ));
+                }
+                let size = get_indir_size(handle, iface_name, context).await?;
+                Ok(indir_equal(size, queue_count))
+            }
+            Self::Weight(weights) => {
+                let size = get_indir_size(handle, iface_name, context).await?;
+                indir_by_weight(size, &weights)
+            }
+        }
+    }
+}
+
+pub struct EthtoolRssSetRequest {
+    handle: EthtoolHandle,
+    iface_name: String,
+    context: Option<u32>,
+    indir: Option<EthtoolRssIndir>,
+    hkey: Option<Vec<u8>>,
+    hfunc: Option<EthtoolRssHashFunction>,
+    input_xfrm: Option<EthtoolRssInputXfrm>,
+}
+
+impl EthtoolRssSetRequest {
+    pub(crate) fn new(handle: EthtoolHandle, iface_name: &str) -> Self {
+        EthtoolRssSetRequest {
+            handle,
+            iface_name: iface_name.to_string(),
+            context: None,
+            indir: None,
+            hkey: None,
+            hfunc: None,
+            input_xfrm: None,
+        }
+    }
+
+    /// Change additional RSS context instead of the default one
+    pub fn context(mut self, context: u32) -> Self {
+        self.context = Some(context);
+        self
+    }
+
+    /// Set the indirection table. The table size should match the one of
+    /// device, or be a divisor of it to be repeated.
+    pub fn indir(mut self, table: &[u32]) -> Self {
+        self.indir = Some(EthtoolRssIndir::Table(table.to_vec()));
+        self
+    }
+
+    /// Reset the indirection table to the driver default (used by
+    /// `ethtool -X eth1 default`)
+    pub fn indir_default(mut self) -> Self {
+        self.indir = Some(EthtoolRssIndir::Table(Vec::new()));
+        self
+    }
+
+    /// Spread the indirection table equally over the first `queue_count`
+    /// receive queues (used by `ethtool -X eth1 equal 4`)
+    pub fn equal(mut self, queue_count: u32) -> Self {
+        self.indir = Some(EthtoolRssIndir::Equal(queue_count));
+        self
+    }
+
+    /// Spread the indirection table over receive queues proportionally to
+    /// the weights, indexed by queue (used by `ethtool -X eth1 weight 6 2`)
+    pub fn weight(mut self, weights: &[u32]) -> Self {
+        self.indir = Some(EthtoolRssIndir::Weight(weights.to_vec()));
+        self
+    }
+
+    pub fn hkey(mut self, key: &[u8]) -> Self {
+        self.hkey = Some(key.to_vec());
+        self
+    }
+
+    pub fn hfunc(mut self, hfunc: EthtoolRssHashFunction) -> Self {
+        self.hfunc = Some(hfunc);
+        self
+    }
+
+    pub fn input_xfrm(mut self, input_xfrm: EthtoolRssInputXfrm) -> Self {
+        self.input_xfrm = Some(input_xfrm);
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), EthtoolError> {
+        let EthtoolRssSetRequest {
+            mut handle,
+            iface_name,
+            context,
+            indir,
+            hkey,
+            hfunc,
+            input_xfrm,
+        } = self;
+
+        let indir = match indir {
+            Some(indir) => {
+                Some(indir.into_table(&handle, &iface_name, context).await?)
+            }
+            None => None,
+        };
+
+        let mut message = EthtoolMessage::new_rss_set(&iface_name);
+        if let Some(context) = context {
+            message
+                .nlas
+                .push(EthtoolAttr::Rss(EthtoolRssAttr::Context(context)));
+        }
+        if let Some(hfunc) = hfunc {
+            message
+                .nlas
+                .push(EthtoolAttr::Rss(EthtoolRssAttr::Hfunc(hfunc)));
+        }
+        if let Some(indir) = indir {
+            message
+                .nlas
+                .push(EthtoolAttr::Rss(EthtoolRssAttr::Indir(indir)));
+        }
+        if let Some(hkey) = hkey {
+            message
+                .nlas
+                .push(EthtoolAttr::Rss(EthtoolRssAttr::Hkey(hkey)));
+        }
+        if let Some(input_xfrm) = input_xfrm {
+            message
+                .nlas
+                .push(EthtoolAttr::Rss(EthtoolRssAttr::InputXfrm(input_xfrm)));
+        }
+
+        let mut nl_msg =
+            NetlinkMessage::from(GenlMessage::from_payload(message));
+
+        nl_msg.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        let mut response = handle.request(nl_msg).await?;
+
+        while let Some(message) = response.next().await {
+            try_ethtool!(message);
+        }
+
+        Ok(())
+    }
+}
+
+async fn get_indir_size(
+    handle: &EthtoolHandle,
+    iface_name: &str,
+    context: Option<u32>,
+) -> Result<u32, EthtoolError> {
+    let mut request =
+        EthtoolRssGetRequest::new(handle.clone(), Some(iface_name));
+    if let Some(context) = context {
+        request = request.context(context);
+    }
+    let mut replies = request.execute().await?;
+    while let Some(reply) = replies.next().await {
+        for nla in reply?.payload.nlas {
+            if let EthtoolAttr::Rss(EthtoolRssAttr::Indir(indir)) = nla {
+                if !indir.is_empty() {
+                    return Ok(indir.len() as u32);
+                }
+            }
+        }
+    }
+    Err(EthtoolError::InvalidArgument(format!(
+        "Interface {iface_name} has no RSS indirection table"
+    )))
+}
+
+// Same distribution as `ethtool -X equal`: queues are used in turn.
+fn indir_equal(size: u32, queue_count: u32) -> Vec<u32> {
+    (0..size).map(|i| i % queue_count).collect()
+}
+
+// Same distribution as `ethtool -X weight`: queue `n` gets the table
+// entries proportional to the accumulated weights up to `n`.
+fn indir_by_weight(
+    size: u32,
+    weights: &[u32],
+) -> Result<Vec<u32>, EthtoolError> {
+    let sum: u64 = weights.iter().map(|w| u64::from(*w)).sum();
+    if sum == 0 {
+        return Err(EthtoolError::InvalidArgument(
+            "At least one RSS weight should be non-zero".to_string(),
+        ));
+    }
+    let size = u64::from(size);
+    let mut indir = Vec::new();
+    let mut partial = 0;
+    let mut queue_count = 0;
+    for i in 0..size {
+        while i >= size * partial / sum {
+            partial += u64::from(weights[queue_count]);
+            queue_count += 1;
+        }
+        indir.push(queue_count as u32 - 1);
+    }
+    Ok(indir)
+}
+
+// Expected tables are the ones set by `ethtool -X <iface> equal|weight ...`
+// on a device of the same table size.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_indir_equal() {
+        assert_eq!(indir_equal(8, 3), vec![0, 1, 2, 0, 1, 2, 0, 1]);
+    }
+
+    #[test]
+    fn test_indir_by_weight() {
+        assert_eq!(
+            indir_by_weight(8, &[6, 2]).unwrap(),
+            vec![0, 0, 0, 0, 0, 0, 1, 1]
+        );
+        assert_eq!(
+            indir_by_weight(16, &[1, 2, 3]).unwrap(),
+            vec![0, 0, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2, 2]
+        );
+    }
+
+    #[test]
+    fn test_indir_by_weight_with_zero() {
+        assert_eq!(
+            indir_by_weight(8, &[0, 1, 3]).unwrap(),
+            vec![1, 1, 2, 2, 2, 2, 2, 2]
+        );
+        assert_eq!(
+            indir_by_weight(7, &[3, 0, 4]).unwrap(),
+            vec![0, 0, 0, 2, 2, 2, 2]
+        );
+        assert_eq!(
+            indir_by_weight(8, &[1, 0, 1, 0, 0]).unwrap(),
+            vec![0, 0, 0, 0, 2, 2, 2, 2]
+        );
+    }
+
+    #[test]
+    fn test_indir_by_weight_zero_sum() {
+        assert!(matches!(
+            indir_by_weight(8, &[0, 0]),
+            Err(EthtoolError::InvalidArgument(_))
+        ));
+    }
+}
@@ -0,0 +1,178 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32, parse_u32, DecodeError, DefaultNla, Emitable, ErrorContext, Nla,
+    NlaBuffer, NlasIterator, Parseable, NLA_F_NESTED,
+};
+
+use crate::{
+    bitset_util::{
+        bitset_nlas_len, emit_bitset_nlas, parse_bitset_bits_nlas,
+        EthtoolBitSet,
+    },
+    EthtoolAttr, EthtoolHeader, EthtoolStatsGroup, EthtoolStatsGroupId,
+};
+
+const ETHTOOL_A_STATS_HEADER: u16 = 2;
+const ETHTOOL_A_STATS_GROUPS: u16 = 3;
+pub(crate) const ETHTOOL_A_STATS_GRP: u16 = 4;
+const ETHTOOL_A_STATS_SRC: u16 = 5;
+
+const ETHTOOL_MAC_STATS_SRC_AGGREGATE: u32 = 0;
+const ETHTOOL_MAC_STATS_SRC_EMAC: u32 = 1;
+const ETHTOOL_MAC_STATS_SRC_PMAC: u32 = 2;
+
+/// Source of MAC statistics on devices supporting MAC Merge
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum EthtoolStatsSrc {
+    /// Sum of eMAC and pMAC statistics
+    #[default]
+    Aggregate,
+    /// Express MAC
+    Emac,
+    /// Preemptible MAC
+    Pmac,
+    Other(u32),
+}
+
+impl From<u32> for EthtoolStatsSrc {
+    fn from(d: u32) -> Self {
+        match d {
+            ETHTOOL_MAC_STATS_SRC_AGGREGATE => Self::Aggregate,
+            ETHTOOL_MAC_STATS_SRC_EMAC => Self::Emac,
+            ETHTOOL_MAC_STATS_SRC_PMAC => Self::Pmac,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<EthtoolStatsSrc> for u32 {
+    fn from(v: EthtoolStatsSrc) -> u32 {
+        match v {
+            EthtoolStatsSrc::Aggregate => ETHTOOL_MAC_STATS_SRC_AGGREGATE,
+            EthtoolStatsSrc::Emac => ETHTOOL_MAC_STATS_SRC_EMAC,
+            EthtoolStatsSrc::Pmac => ETHTOOL_MAC_STATS_SRC_PMAC,
+            EthtoolStatsSrc::Other(d) => d,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum EthtoolStatsAttr {
+    Header(Vec<EthtoolHeader>),
+    /// Statistics groups to query
+    Groups(Vec<EthtoolStatsGroupId>),
+    Group(EthtoolStatsGroup),
+    Src(EthtoolStatsSrc),
+    Other(DefaultNla),
+}
+
+// The groups are sent as a list, so every group not included is not queried.
+fn stats_groups_to_bitset(
+    groups: &[EthtoolStatsGroupId],
+) -> Vec<EthtoolBitSet> {
+    groups
+        .iter()
+        .map(|group| EthtoolBitSet {
+            index: (*group).into(),
+            name: String::new(),
+            value: true,
+        })
+        .collect()
+}
+
+impl Nla for EthtoolStatsAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Header(hdrs) => hdrs.as_slice().buffer_len(),
+            Self::Groups(groups) => {
+                bitset_nlas_len(&stats_groups_to_bitset(groups), true)
+            }
+            Self::Group(group) => group.value_len(),
+            Self::Src(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Header(_) => ETHTOOL_A_STATS_HEADER | NLA_F_NESTED,
+            Self::Groups(_) => ETHTOOL_A_STATS_GROUPS | NLA_F_NESTED,
+            Self::Group(group) => group.kind(),
+            Self::Src(_) => ETHTOOL_A_STATS_SRC,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Header(ref nlas) => nlas.as_slice().emit(buffer),
+            Self::Groups(groups) => {
+                emit_bitset_nlas(&stats_groups_to_bitset(groups), true, buffer)
+            }
+            Self::Group(group) => group.emit_value(buffer),
+            Self::Src(d) => emit_u32(buffer, (*d).into()).unwrap(),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for EthtoolStatsAttr
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            ETHTOOL_A_STATS_HEADER => {
+                let mut nlas = Vec::new();
+                let error_msg = "failed to parse stats header attributes";
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    let parsed =
+                        EthtoolHeader::parse(nla).context(error_msg)?;
+                    nlas.push(parsed);
+                }
+                Self::Header(nlas)
+            }
+            ETHTOOL_A_STATS_GROUPS => {
+                let bits = parse_bitset_bits_nlas(payload).context(format!(
+                    "Invalid ETHTOOL_A_STATS_GROUPS {payload:?}"
+                ))?;
+                Self::Groups(
+                    bits.into_iter()
+                        .filter(|b| b.value)
+                        .map(|b| EthtoolStatsGroupId::from(b.index))
+                        .collect(),
+                )
+            }
+            ETHTOOL_A_STATS_GRP => Self::Group(
+                EthtoolStatsGroup::parse(buf)
+                    .context("Invalid ETHTOOL_A_STATS_GRP")?,
+            ),
+            ETHTOOL_A_STATS_SRC => Self::Src(
+                parse_u32(payload)
+                    .context("Invalid ETHTOOL_A_STATS_SRC value")?
+                    .into(),
+            ),
+            kind => {
+                Self::Other(DefaultNla::parse(buf).context(format!(
+                    "invalid ethtool stats NLA kind {kind}"
+                ))?)
+            }
+        })
+    }
+}
+
+pub(crate) fn parse_stats_nlas(
+    buffer: &[u8],
+) -> Result<Vec<EthtoolAttr>, DecodeError> {
+    let mut nlas = Vec::new();
+    for nla in NlasIterator::new(buffer) {
+        let error_msg =
+            format!("Failed to parse ethtool stats message attribute {nla:?}");
+        let nla = &nla.context(error_msg.clone())?;
+        let parsed = EthtoolStatsAttr::parse(nla).context(error_msg)?;
+        nlas.push(EthtoolAttr::Stats(parsed));
+    }
+    Ok(nlas)
+}
@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: MIT
+
+use futures_util::Stream;
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    ethtool_execute, EthtoolAttr, EthtoolError, EthtoolHandle, EthtoolMessage,
+    EthtoolStatsAttr, EthtoolStatsGroupId, EthtoolStatsSrc,
+};
+
+pub struct EthtoolStatsGetRequest {
+    handle: EthtoolHandle,
+    iface_name: Option<String>,
+    groups: Vec<EthtoolStatsGroupId>,
+    src: Option<EthtoolStatsSrc>,
+}
+
+impl EthtoolStatsGetRequest {
+    pub(crate) fn new(
+        handle: EthtoolHandle,
+        iface_name: Option<&str>,
+        groups: &[EthtoolStatsGroupId],
+    ) -> Self {
+        EthtoolStatsGetRequest {
+            handle,
+            iface_name: iface_name.map(|i| i.to_string()),
+            groups: groups.to_vec(),
+            src: None,
+        }
+    }
+
+    /// Query statistics of eMAC or pMAC only, instead of their sum, on
+    /// devices supporting MAC Merge
+    pub fn src(mut self, src: EthtoolStatsSrc) -> Self {
+        self.src = Some(src);
+        self
+    }
+
+    pub async fn execute(
+        self,
+    ) -> Result<
+        impl Stream<Item = Result<GenlMessage<EthtoolMessage>, EthtoolError>>,
+        EthtoolError,
+    > {
+        let EthtoolStatsGetRequest {
+            mut handle,
+            iface_name,
+            groups,
+            src,
+        } = self;
+
+        let mut ethtool_msg =
+            EthtoolMessage::new_stats_get(iface_name.as_deref(), &groups);
+        if let Some(src) = src {
+            ethtool_msg
+                .nlas
+                .push(EthtoolAttr::Stats(EthtoolStatsAttr::Src(src)));
+        }
+        ethtool_execute(&mut handle, iface_name.is_none(), ethtool_msg).await
+    }
+}
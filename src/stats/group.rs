@@ -0,0 +1,577 @@
+// SPDX-License-Identifier: MIT
+
+use log::warn;
+use netlink_packet_core::{
+    emit_u32, emit_u64, parse_u32, parse_u64, DecodeError, DefaultNla,
+    Emitable, ErrorContext, Nla, NlaBuffer, NlasIterator, Parseable,
+    NLA_F_NESTED,
+};
+
+const ETHTOOL_A_STATS_GRP_ID: u16 = 2;
+const ETHTOOL_A_STATS_GRP_SS_ID: u16 = 3;
+const ETHTOOL_A_STATS_GRP_STAT: u16 = 4;
+const ETHTOOL_A_STATS_GRP_HIST_RX: u16 = 5;
+const ETHTOOL_A_STATS_GRP_HIST_TX: u16 = 6;
+const ETHTOOL_A_STATS_GRP_HIST_BKT_LOW: u16 = 7;
+const ETHTOOL_A_STATS_GRP_HIST_BKT_HI: u16 = 8;
+const ETHTOOL_A_STATS_GRP_HIST_VAL: u16 = 9;
+
+const ETHTOOL_STATS_ETH_PHY: u32 = 0;
+const ETHTOOL_STATS_ETH_MAC: u32 = 1;
+const ETHTOOL_STATS_ETH_CTRL: u32 = 2;
+const ETHTOOL_STATS_RMON: u32 = 3;
+
+const ETH_SS_STATS_ETH_PHY: u32 = 17;
+const ETH_SS_STATS_ETH_MAC: u32 = 18;
+const ETH_SS_STATS_ETH_CTRL: u32 = 19;
+const ETH_SS_STATS_RMON: u32 = 20;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EthtoolStatsGroupId {
+    /// IEEE 802.3 PHY statistics
+    EthPhy,
+    /// IEEE 802.3 MAC statistics
+    EthMac,
+    /// IEEE 802.3 MAC Control statistics
+    EthCtrl,
+    /// RMON (RFC 2819) statistics
+    Rmon,
+    Other(u32),
+}
+
+impl std::fmt::Display for EthtoolStatsGroupId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EthPhy => write!(f, "eth-phy"),
+            Self::EthMac => write!(f, "eth-mac"),
+            Self::EthCtrl => write!(f, "eth-ctrl"),
+            Self::Rmon => write!(f, "rmon"),
+            Self::Other(d) => write!(f, "{d}"),
+        }
+    }
+}
+
+impl From<u32> for EthtoolStatsGroupId {
+    fn from(d: u32) -> Self {
+        match d {
+            ETHTOOL_STATS_ETH_PHY => Self::EthPhy,
+            ETHTOOL_STATS_ETH_MAC => Self::EthMac,
+            ETHTOOL_STATS_ETH_CTRL => Self::EthCtrl,
+            ETHTOOL_STATS_RMON => Self::Rmon,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<EthtoolStatsGroupId> for u32 {
+    fn from(v: EthtoolStatsGroupId) -> u32 {
+        match v {
+            EthtoolStatsGroupId::EthPhy => ETHTOOL_STATS_ETH_PHY,
+            EthtoolStatsGroupId::EthMac => ETHTOOL_STATS_ETH_MAC,
+            EthtoolStatsGroupId::EthCtrl => ETHTOOL_STATS_ETH_CTRL,
+            EthtoolStatsGroupId::Rmon => ETHTOOL_STATS_RMON,
+            EthtoolStatsGroupId::Other(d) => d,
+        }
+    }
+}
+
+/// IEEE 802.3 PHY statistics (30.3.2.1)
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct EthtoolEthPhyStats {
+    pub symbol_error_during_carrier: Option<u64>,
+}
+
+impl EthtoolEthPhyStats {
+    // Ordered by `ETHTOOL_A_STATS_ETH_PHY_*` index
+    fn fields(&self) -> [Option<u64>; 1] {
+        [self.symbol_error_during_carrier]
+    }
+
+    fn fields_mut(&mut self) -> [&mut Option<u64>; 1] {
+        [&mut self.symbol_error_during_carrier]
+    }
+}
+
+/// IEEE 802.3 MAC statistics (30.3.1.1)
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct EthtoolEthMacStats {
+    pub frames_transmitted_ok: Option<u64>,
+    pub single_collision_frames: Option<u64>,
+    pub multiple_collision_frames: Option<u64>,
+    pub frames_received_ok: Option<u64>,
+    pub frame_check_sequence_errors: Option<u64>,
+    pub alignment_errors: Option<u64>,
+    pub octets_transmitted_ok: Option<u64>,
+    pub frames_with_deferred_xmissions: Option<u64>,
+    pub late_collisions: Option<u64>,
+    pub frames_aborted_due_to_xs_colls: Option<u64>,
+    pub frames_lost_due_to_int_mac_xmit_error: Option<u64>,
+    pub carrier_sense_errors: Option<u64>,
+    pub octets_received_ok: Option<u64>,
+    pub frames_lost_due_to_int_mac_rcv_error: Option<u64>,
+    pub multicast_frames_xmitted_ok: Option<u64>,
+    pub broadcast_frames_xmitted_ok: Option<u64>,
+    pub frames_with_excessive_deferral: Option<u64>,
+    pub multicast_frames_received_ok: Option<u64>,
+    pub broadcast_frames_received_ok: Option<u64>,
+    pub in_range_length_errors: Option<u64>,
+    pub out_of_range_length_field: Option<u64>,
+    pub frame_too_long_errors: Option<u64>,
+}
+
+impl EthtoolEthMacStats {
+    // Ordered by `ETHTOOL_A_STATS_ETH_MAC_*` index
+    fn fields(&self) -> [Option<u64>; 22] {
+        [
+            self.frames_transmitted_ok,
+            self.single_collision_frames,
+            self.multiple_collision_frames,
+            self.frames_received_ok,
+            self.frame_check_sequence_errors,
+            self.alignment_errors,
+            self.octets_transmitted_ok,
+            self.frames_with_deferred_xmissions,
+            self.late_collisions,
+            self.frames_aborted_due_to_xs_colls,
+            self.frames_lost_due_to_int_mac_xmit_error,
+            self.carrier_sense_errors,
+            self.octets_received_ok,
+            self.frames_lost_due_to_int_mac_rcv_error,
+            self.multicast_frames_xmitted_ok,
+            self.broadcast_frames_xmitted_ok,
+            self.frames_with_excessive_deferral,
+            self.multicast_frames_received_ok,
+            self.broadcast_frames_received_ok,
+            self.in_range_length_errors,
+            self.out_of_range_length_field,
+            self.frame_too_long_errors,
+        ]
+    }
+
+    fn fields_mut(&mut self) -> [&mut Option<u64>; 22] {
+        [
+            &mut self.frames_transmitted_ok,
+            &mut self.single_collision_frames,
+            &mut self.multiple_collision_frames,
+            &mut self.frames_received_ok,
+            &mut self.frame_check_sequence_errors,
+            &mut self.alignment_errors,
+            &mut self.octets_transmitted_ok,
+            &mut self.frames_with_deferred_xmissions,
+            &mut self.late_collisions,
+            &mut self.frames_aborted_due_to_xs_colls,
+            &mut self.frames_lost_due_to_int_mac_xmit_error,
+            &mut self.carrier_sense_errors,
+            &mut self.octets_received_ok,
+            &mut self.frames_lost_due_to_int_mac_rcv_error,
+            &mut self.multicast_frames_xmitted_ok,
+            &mut self.broadcast_frames_xmitted_ok,
+            &mut self.frames_with_excessive_deferral,
+            &mut self.multicast_frames_received_ok,
+            &mut self.broadcast_frames_received_ok,
+            &mut self.in_range_length_errors,
+            &mut self.out_of_range_length_field,
+            &mut self.frame_too_long_errors,
+        ]
+    }
+}
+
+/// IEEE 802.3 MAC Control statistics (30.3.3)
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct EthtoolEthCtrlStats {
+    pub mac_control_frames_transmitted: Option<u64>,
+    pub mac_control_frames_received: Option<u64>,
+    pub unsupported_opcodes_received: Option<u64>,
+}
+
+impl EthtoolEthCtrlStats {
+    // Ordered by `ETHTOOL_A_STATS_ETH_CTRL_*` index
+    fn fields(&self) -> [Option<u64>; 3] {
+        [
+            self.mac_control_frames_transmitted,
+            self.mac_control_frames_received,
+            self.unsupported_opcodes_received,
+        ]
+    }
+
+    fn fields_mut(&mut self) -> [&mut Option<u64>; 3] {
+        [
+            &mut self.mac_control_frames_transmitted,
+            &mut self.mac_control_frames_received,
+            &mut self.unsupported_opcodes_received,
+        ]
+    }
+}
+
+/// Packet counter of a RMON histogram bucket, covering packet sizes from
+/// `low` to `high` (inclusive).
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct EthtoolStatsHistBucket {
+    pub low: u32,
+    pub high: u32,
+    pub value: u64,
+}
+
+/// RMON (RFC 2819) statistics
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct EthtoolRmonStats {
+    pub undersize_pkts: Option<u64>,
+    pub oversize_pkts: Option<u64>,
+    pub fragments: Option<u64>,
+    pub jabbers: Option<u64>,
+    pub hist_rx: Vec<EthtoolStatsHistBucket>,
+    pub hist_tx: Vec<EthtoolStatsHistBucket>,
+}
+
+impl EthtoolRmonStats {
+    // Ordered by `ETHTOOL_A_STATS_RMON_*` index
+    fn fields(&self) -> [Option<u64>; 4] {
+        [
+            self.undersize_pkts,
+            self.oversize_pkts,
+            self.fragments,
+            self.jabbers,
+        ]
+    }
+
+    fn fields_mut(&mut self) -> [&mut Option<u64>; 4] {
+        [
+            &mut self.undersize_pkts,
+            &mut self.oversize_pkts,
+            &mut self.fragments,
+            &mut self.jabbers,
+        ]
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum EthtoolStatsGroup {
+    EthPhy(EthtoolEthPhyStats),
+    EthMac(Box<EthtoolEthMacStats>),
+    EthCtrl(EthtoolEthCtrlStats),
+    Rmon(EthtoolRmonStats),
+    /// Group unknown to this crate with its statistics as index and value
+    Other(u32, Vec<(u16, u64)>),
+}
+
+impl EthtoolStatsGroup {
+    pub fn id(&self) -> EthtoolStatsGroupId {
+        match self {
+            Self::EthPhy(_) => EthtoolStatsGroupId::EthPhy,
+            Self::EthMac(_) => EthtoolStatsGroupId::EthMac,
+            Self::EthCtrl(_) => EthtoolStatsGroupId::EthCtrl,
+            Self::Rmon(_) => EthtoolStatsGroupId::Rmon,
+            Self::Other(id, _) => EthtoolStatsGroupId::Other(*id),
+        }
+    }
+
+    fn to_nlas(&self) -> Vec<EthtoolStatsGroupAttr> {
+        let (ss_id, stats, hist_rx, hist_tx) = match self {
+            Self::EthPhy(s) => (
+                ETH_SS_STATS_ETH_PHY,
+                fields_to_stats(&s.fields()),
+                &[][..],
+                &[][..],
+            ),
+            Self::EthMac(s) => (
+                ETH_SS_STATS_ETH_MAC,
+                fields_to_stats(&s.fields()),
+                &[][..],
+                &[][..],
+            ),
+            Self::EthCtrl(s) => (
+                ETH_SS_STATS_ETH_CTRL,
+                fields_to_stats(&s.fields()),
+                &[][..],
+                &[][..],
+            ),
+            Self::Rmon(s) => (
+                ETH_SS_STATS_RMON,
+                fields_to_stats(&s.fields()),
+                s.hist_rx.as_slice(),
+                s.hist_tx.as_slice(),
+            ),
+            Self::Other(_, stats) => (0, stats.clone(), &[][..], &[][..]),
+        };
+        let mut nlas = vec![
+            EthtoolStatsGroupAttr::Id(self.id().into()),
+            EthtoolStatsGroupAttr::SsId(ss_id),
+        ];
+        nlas.extend(
+            stats.into_iter().map(|(index, value)| {
+                EthtoolStatsGroupAttr::Stat(index, value)
+            }),
+        );
+        nlas.extend(hist_rx.iter().cloned().map(EthtoolStatsGroupAttr::HistRx));
+        nlas.extend(hist_tx.iter().cloned().map(EthtoolStatsGroupAttr::HistTx));
+        nlas
+    }
+
+    fn from_nlas(
+        nlas: Vec<EthtoolStatsGroupAttr>,
+    ) -> Result<Self, DecodeError> {
+        let mut id = None;
+        let mut stats = Vec::new();
+        let mut hist_rx = Vec::new();
+        let mut hist_tx = Vec::new();
+        for nla in nlas {
+            match nla {
+                EthtoolStatsGroupAttr::Id(d) => id = Some(d),
+                EthtoolStatsGroupAttr::Stat(index, value) => {
+                    stats.push((index, value))
+                }
+                EthtoolStatsGroupAttr::HistRx(b) => hist_rx.push(b),
+                EthtoolStatsGroupAttr::HistTx(b) => hist_tx.push(b),
+                EthtoolStatsGroupAttr::SsId(_)
+                | EthtoolStatsGroupAttr::Other(_) => (),
+            }
+        }
+        let id = id.ok_or("No ETHTOOL_A_STATS_GRP_ID in stats group")?;
+        Ok(match EthtoolStatsGroupId::from(id) {
+            EthtoolStatsGroupId::EthPhy => {
+                let mut s = EthtoolEthPhyStats::default();
+                stats_to_fields(&stats, s.fields_mut());
+                Self::EthPhy(s)
+            }
+            EthtoolStatsGroupId::EthMac => {
+                let mut s = EthtoolEthMacStats::default();
+                stats_to_fields(&stats, s.fields_mut());
+                Self::EthMac(Box::new(s))
+            }
+            EthtoolStatsGroupId::EthCtrl => {
+                let mut s = EthtoolEthCtrlStats::default();
+                stats_to_fields(&stats, s.fields_mut());
+                Self::EthCtrl(s)
+            }
+            EthtoolStatsGroupId::Rmon => {
+                let mut s = EthtoolRmonStats {
+                    hist_rx,
+                    hist_tx,
+                    ..Default::default()
+                };
+                stats_to_fields(&stats, s.fields_mut());
+                Self::Rmon(s)
+            }
+            EthtoolStatsGroupId::Other(id) => Self::Other(id, stats),
+        })
+    }
+}
+
+fn fields_to_stats(fields: &[Option<u64>]) -> Vec<(u16, u64)> {
+    fields
+        .iter()
+        .enumerate()
+        .filter_map(|(i, v)| v.map(|v| (i as u16, v)))
+        .collect()
+}
+
+fn stats_to_fields<const N: usize>(
+    stats: &[(u16, u64)],
+    mut fields: [&mut Option<u64>; N],
+) {
+    for (index, value) in stats {
+        match fields.get_mut(*index as usize) {
+            Some(field) => **field = Some(*value),
+            None => warn!("Unknown ethtool stats index {index} {value}"),
+        }
+    }
+}
+
+impl Nla for EthtoolStatsGroup {
+    fn value_len(&self) -> usize {
+        self.to_nlas().as_slice().buffer_len()
+    }
+
+    fn kind(&self) -> u16 {
+        super::attr::ETHTOOL_A_STATS_GRP | NLA_F_NESTED
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        self.to_nlas().as_slice().emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for EthtoolStatsGroup
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut nlas = Vec::new();
+        let error_msg = "failed to parse ETHTOOL_A_STATS_GRP attributes";
+        for nla in NlasIterator::new(buf.value()) {
+            let nla = &nla.context(error_msg)?;
+            nlas.push(EthtoolStatsGroupAttr::parse(nla).context(error_msg)?);
+        }
+        Self::from_nlas(nlas)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum EthtoolStatsGroupAttr {
+    Id(u32),
+    SsId(u32),
+    /// Index of statistic in the group and its value
+    Stat(u16, u64),
+    HistRx(EthtoolStatsHistBucket),
+    HistTx(EthtoolStatsHistBucket),
+    Other(DefaultNla),
+}
+
+impl Nla for EthtoolStatsGroupAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Id(_) | Self::SsId(_) => 4,
+            Self::Stat(_, _) => 12,
+            Self::HistRx(b) | Self::HistTx(b) => {
+                hist_bucket_to_nlas(b).as_slice().buffer_len()
+            }
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Id(_) => ETHTOOL_A_STATS_GRP_ID,
+            Self::SsId(_) => ETHTOOL_A_STATS_GRP_SS_ID,
+            Self::Stat(_, _) => ETHTOOL_A_STATS_GRP_STAT | NLA_F_NESTED,
+            Self::HistRx(_) => ETHTOOL_A_STATS_GRP_HIST_RX | NLA_F_NESTED,
+            Self::HistTx(_) => ETHTOOL_A_STATS_GRP_HIST_TX | NLA_F_NESTED,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Id(d) | Self::SsId(d) => emit_u32(buffer, *d).unwrap(),
+            Self::Stat(index, value) => {
+                [EthtoolStatsValue(*index, *value)].as_slice().emit(buffer)
+            }
+            Self::HistRx(b) | Self::HistTx(b) => {
+                hist_bucket_to_nlas(b).as_slice().emit(buffer)
+            }
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for EthtoolStatsGroupAttr
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            ETHTOOL_A_STATS_GRP_ID => Self::Id(
+                parse_u32(payload)
+                    .context("Invalid ETHTOOL_A_STATS_GRP_ID value")?,
+            ),
+            ETHTOOL_A_STATS_GRP_SS_ID => Self::SsId(
+                parse_u32(payload)
+                    .context("Invalid ETHTOOL_A_STATS_GRP_SS_ID value")?,
+            ),
+            ETHTOOL_A_STATS_GRP_STAT => {
+                // The nest holds a single u64 attribute with the index of the
+                // statistic as its kind.
+                let nla = NlasIterator::new(payload)
+                    .next()
+                    .ok_or("Empty ETHTOOL_A_STATS_GRP_STAT")?
+                    .context("Invalid ETHTOOL_A_STATS_GRP_STAT")?;
+                Self::Stat(
+                    nla.kind(),
+                    parse_u64(nla.value())
+                        .context("Invalid ETHTOOL_A_STATS_GRP_STAT value")?,
+                )
+            }
+            ETHTOOL_A_STATS_GRP_HIST_RX => {
+                Self::HistRx(parse_hist_bucket(payload)?)
+            }
+            ETHTOOL_A_STATS_GRP_HIST_TX => {
+                Self::HistTx(parse_hist_bucket(payload)?)
+            }
+            kind => Self::Other(DefaultNla::parse(buf).context(format!(
+                "invalid ethtool stats group NLA kind {kind}"
+            ))?),
+        })
+    }
+}
+
+struct EthtoolStatsValue(u16, u64);
+
+impl Nla for EthtoolStatsValue {
+    fn value_len(&self) -> usize {
+        8
+    }
+
+    fn kind(&self) -> u16 {
+        self.0
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        emit_u64(buffer, self.1).unwrap()
+    }
+}
+
+enum EthtoolStatsHistBucketAttr {
+    Low(u32),
+    High(u32),
+    Value(u64),
+}
+
+impl Nla for EthtoolStatsHistBucketAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Low(_) | Self::High(_) => 4,
+            Self::Value(_) => 8,
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Low(_) => ETHTOOL_A_STATS_GRP_HIST_BKT_LOW,
+            Self::High(_) => ETHTOOL_A_STATS_GRP_HIST_BKT_HI,
+            Self::Value(_) => ETHTOOL_A_STATS_GRP_HIST_VAL,
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Low(d) | Self::High(d) => emit_u32(buffer, *d).unwrap(),
+            Self::Value(d) => emit_u64(buffer, *d).unwrap(),
+        }
+    }
+}
+
+fn hist_bucket_to_nlas(
+    bucket: &EthtoolStatsHistBucket,
+) -> [EthtoolStatsHistBucketAttr; 3] {
+    [
+        EthtoolStatsHistBucketAttr::Low(bucket.low),
+        EthtoolStatsHistBucketAttr::High(bucket.high),
+        EthtoolStatsHistBucketAttr::Value(bucket.value),
+    ]
+}
+
+fn parse_hist_bucket(
+    payload: &[u8],
+) -> Result<EthtoolStatsHistBucket, DecodeError> {
+    let mut bucket = EthtoolStatsHistBucket::default();
+    let error_msg = "failed to parse ethtool stats histogram bucket";
+    for nla in NlasIterator::new(payload) {
+        let nla = &nla.context(error_msg)?;
+        let value = nla.value();
+        match nla.kind() {
+            ETHTOOL_A_STATS_GRP_HIST_BKT_LOW => {
+                bucket.low = parse_u32(value)
+                    .context("Invalid ETHTOOL_A_STATS_GRP_HIST_BKT_LOW")?
+            }
+            ETHTOOL_A_STATS_GRP_HIST_BKT_HI => {
+                bucket.high = parse_u32(value)
+                    .context("Invalid ETHTOOL_A_STATS_GRP_HIST_BKT_HI")?
+            }
+            ETHTOOL_A_STATS_GRP_HIST_VAL => {
+                bucket.value = parse_u64(value)
+                    .context("Invalid ETHTOOL_A_STATS_GRP_HIST_VAL")?
+            }
+            _ => (),
+        }
+    }
+    Ok(bucket)
+}
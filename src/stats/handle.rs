@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{EthtoolHandle, EthtoolStatsGetRequest, EthtoolStatsGroupId};
+
+pub struct EthtoolStatsHandle(EthtoolHandle);
+
+impl EthtoolStatsHandle {
+    pub fn new(handle: EthtoolHandle) -> Self {
+        EthtoolStatsHandle(handle)
+    }
+
+    /// Retrieve the standard statistics groups of a interface (used by
+    /// `ethtool -S eth1 --groups eth-mac rmon`)
+    pub fn get(
+        &mut self,
+        iface_name: Option<&str>,
+        groups: &[EthtoolStatsGroupId],
+    ) -> EthtoolStatsGetRequest {
+        EthtoolStatsGetRequest::new(self.0.clone(), iface_name, groups)
+    }
+}
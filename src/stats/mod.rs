@@ -0,0 +1,17 @@
+// SPDX-License-Identifier: MIT
+
+mod attr;
+mod get;
+mod group;
+mod handle;
+
+pub(crate) use attr::parse_stats_nlas;
+
+pub use attr::{EthtoolStatsAttr, EthtoolStatsSrc};
+pub use get::EthtoolStatsGetRequest;
+pub use group::{
+    EthtoolEthCtrlStats, EthtoolEthMacStats, EthtoolEthPhyStats,
+    EthtoolRmonStats, EthtoolStatsGroup, EthtoolStatsGroupId,
+    EthtoolStatsHistBucket,
+};
+pub use handle::EthtoolStatsHandle;
@@ -0,0 +1,436 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u32, parse_string, parse_u32, DecodeError, DefaultNla, Emitable,
+    ErrorContext, Nla, NlaBuffer, NlasIterator, Parseable, NLA_F_NESTED,
+};
+
+use crate::{EthtoolAttr, EthtoolHeader};
+
+const ETHTOOL_A_STRSET_HEADER: u16 = 1;
+const ETHTOOL_A_STRSET_STRINGSETS: u16 = 2;
+const ETHTOOL_A_STRSET_COUNTS_ONLY: u16 = 3;
+
+const ETHTOOL_A_STRINGSETS_STRINGSET: u16 = 1;
+
+const ETHTOOL_A_STRINGSET_ID: u16 = 1;
+const ETHTOOL_A_STRINGSET_COUNT: u16 = 2;
+const ETHTOOL_A_STRINGSET_STRINGS: u16 = 3;
+
+const ETHTOOL_A_STRINGS_STRING: u16 = 1;
+
+const ETHTOOL_A_STRING_INDEX: u16 = 1;
+const ETHTOOL_A_STRING_VALUE: u16 = 2;
+
+const ETH_SS_TEST: u32 = 0;
+const ETH_SS_STATS: u32 = 1;
+const ETH_SS_PRIV_FLAGS: u32 = 2;
+const ETH_SS_NTUPLE_FILTERS: u32 = 3;
+const ETH_SS_FEATURES: u32 = 4;
+const ETH_SS_RSS_HASH_FUNCS: u32 = 5;
+const ETH_SS_TUNABLES: u32 = 6;
+const ETH_SS_PHY_STATS: u32 = 7;
+const ETH_SS_PHY_TUNABLES: u32 = 8;
+const ETH_SS_LINK_MODES: u32 = 9;
+const ETH_SS_MSG_CLASSES: u32 = 10;
+const ETH_SS_WOL_MODES: u32 = 11;
+const ETH_SS_SOF_TIMESTAMPING: u32 = 12;
+const ETH_SS_TS_TX_TYPES: u32 = 13;
+const ETH_SS_TS_RX_FILTERS: u32 = 14;
+const ETH_SS_UDP_TUNNEL_TYPES: u32 = 15;
+const ETH_SS_STATS_STD: u32 = 16;
+const ETH_SS_STATS_ETH_PHY: u32 = 17;
+const ETH_SS_STATS_ETH_MAC: u32 = 18;
+const ETH_SS_STATS_ETH_CTRL: u32 = 19;
+const ETH_SS_STATS_RMON: u32 = 20;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum EthtoolStringSetId {
+    /// Self-test result names (per device)
+    Test,
+    /// Driver statistic names, as shown by `ethtool -S` (per device)
+    Stats,
+    /// Driver private flag names (per device)
+    PrivFlags,
+    NtupleFilters,
+    Features,
+    RssHashFuncs,
+    Tunables,
+    /// PHY statistic names (per device)
+    PhyStats,
+    PhyTunables,
+    LinkModes,
+    MsgClasses,
+    WolModes,
+    SofTimestamping,
+    TsTxTypes,
+    TsRxFilters,
+    UdpTunnelTypes,
+    StatsStd,
+    StatsEthPhy,
+    StatsEthMac,
+    StatsEthCtrl,
+    StatsRmon,
+    Other(u32),
+}
+
+impl From<u32> for EthtoolStringSetId {
+    fn from(d: u32) -> Self {
+        match d {
+            ETH_SS_TEST => Self::Test,
+            ETH_SS_STATS => Self::Stats,
+            ETH_SS_PRIV_FLAGS => Self::PrivFlags,
+            ETH_SS_NTUPLE_FILTERS => Self::NtupleFilters,
+            ETH_SS_FEATURES => Self::Features,
+            ETH_SS_RSS_HASH_FUNCS => Self::RssHashFuncs,
+            ETH_SS_TUNABLES => Self::Tunables,
+            ETH_SS_PHY_STATS => Self::PhyStats,
+            ETH_SS_PHY_TUNABLES => Self::PhyTunables,
+            ETH_SS_LINK_MODES => Self::LinkModes,
+            ETH_SS_MSG_CLASSES => Self::MsgClasses,
+            ETH_SS_WOL_MODES => Self::WolModes,
+            ETH_SS_SOF_TIMESTAMPING => Self::SofTimestamping,
+            ETH_SS_TS_TX_TYPES => Self::TsTxTypes,
+            ETH_SS_TS_RX_FILTERS => Self::TsRxFilters,
+            ETH_SS_UDP_TUNNEL_TYPES => Self::UdpTunnelTypes,
+            ETH_SS_STATS_STD => Self::StatsStd,
+            ETH_SS_STATS_ETH_PHY => Self::StatsEthPhy,
+            ETH_SS_STATS_ETH_MAC => Self::StatsEthMac,
+            ETH_SS_STATS_ETH_CTRL => Self::StatsEthCtrl,
+            ETH_SS_STATS_RMON => Self::StatsRmon,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<EthtoolStringSetId> for u32 {
+    fn from(v: EthtoolStringSetId) -> u32 {
+        match v {
+            EthtoolStringSetId::Test => ETH_SS_TEST,
+            EthtoolStringSetId::Stats => ETH_SS_STATS,
+            EthtoolStringSetId::PrivFlags => ETH_SS_PRIV_FLAGS,
+            EthtoolStringSetId::NtupleFilters => ETH_SS_NTUPLE_FILTERS,
+            EthtoolStringSetId::Features => ETH_SS_FEATURES,
+            EthtoolStringSetId::RssHashFuncs => ETH_SS_RSS_HASH_FUNCS,
+            EthtoolStringSetId::Tunables => ETH_SS_TUNABLES,
+            EthtoolStringSetId::PhyStats => ETH_SS_PHY_STATS,
+            EthtoolStringSetId::PhyTunables => ETH_SS_PHY_TUNABLES,
+            EthtoolStringSetId::LinkModes => ETH_SS_LINK_MODES,
+            EthtoolStringSetId::MsgClasses => ETH_SS_MSG_CLASSES,
+            EthtoolStringSetId::WolModes => ETH_SS_WOL_MODES,
+            EthtoolStringSetId::SofTimestamping => ETH_SS_SOF_TIMESTAMPING,
+            EthtoolStringSetId::TsTxTypes => ETH_SS_TS_TX_TYPES,
+            EthtoolStringSetId::TsRxFilters => ETH_SS_TS_RX_FILTERS,
+            EthtoolStringSetId::UdpTunnelTypes => ETH_SS_UDP_TUNNEL_TYPES,
+            EthtoolStringSetId::StatsStd => ETH_SS_STATS_STD,
+            EthtoolStringSetId::StatsEthPhy => ETH_SS_STATS_ETH_PHY,
+            EthtoolStringSetId::StatsEthMac => ETH_SS_STATS_ETH_MAC,
+            EthtoolStringSetId::StatsEthCtrl => ETH_SS_STATS_ETH_CTRL,
+            EthtoolStringSetId::StatsRmon => ETH_SS_STATS_RMON,
+            EthtoolStringSetId::Other(d) => d,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct EthtoolStringSet {
+    pub id: EthtoolStringSetId,
+    /// Number of strings, only set in kernel reply
+    pub count: Option<u32>,
+    /// Strings ordered by their index, empty in kernel reply to
+    /// count-only request
+    pub strings: Vec<String>,
+}
+
+impl EthtoolStringSet {
+    pub fn new(id: EthtoolStringSetId) -> Self {
+        Self {
+            id,
+            count: None,
+            strings: Vec::new(),
+        }
+    }
+
+    fn to_nlas(&self) -> Vec<EthtoolStringSetNla> {
+        let mut nlas = vec![EthtoolStringSetNla::Id(self.id.into())];
+        if let Some(count) = self.count {
+            nlas.push(EthtoolStringSetNla::Count(count));
+        }
+        if !self.strings.is_empty() {
+            nlas.push(EthtoolStringSetNla::Strings(
+                self.strings
+                    .iter()
+                    .enumerate()
+                    .map(|(i, s)| EthtoolStringNla(i as u32, s.to_string()))
+                    .collect(),
+            ));
+        }
+        nlas
+    }
+}
+
+impl Nla for EthtoolStringSet {
+    fn value_len(&self) -> usize {
+        self.to_nlas().as_slice().buffer_len()
+    }
+
+    fn kind(&self) -> u16 {
+        ETHTOOL_A_STRINGSETS_STRINGSET | NLA_F_NESTED
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        self.to_nlas().as_slice().emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for EthtoolStringSet
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut string_set = Self::new(EthtoolStringSetId::Other(0));
+        let error_msg = "failed to parse ETHTOOL_A_STRINGSETS_STRINGSET";
+        let mut strings = Vec::new();
+        for nla in NlasIterator::new(buf.value()) {
+            let nla = &nla.context(error_msg)?;
+            let payload = nla.value();
+            match nla.kind() {
+                ETHTOOL_A_STRINGSET_ID => {
+                    string_set.id = parse_u32(payload)
+                        .context("Invalid ETHTOOL_A_STRINGSET_ID value")?
+                        .into();
+                }
+                ETHTOOL_A_STRINGSET_COUNT => {
+                    string_set.count =
+                        Some(parse_u32(payload).context(
+                            "Invalid ETHTOOL_A_STRINGSET_COUNT value",
+                        )?);
+                }
+                ETHTOOL_A_STRINGSET_STRINGS => {
+                    for nla in NlasIterator::new(payload) {
+                        let nla = &nla.context(error_msg)?;
+                        strings.push(parse_string_nla(nla.value())?);
+                    }
+                }
+                _ => (),
+            }
+        }
+        // The string index is only trusted within the count of the set.
+        // Without count, strings are expected to be indexed contiguously.
+        let count = string_set
+            .count
+            .unwrap_or(u32::try_from(strings.len()).unwrap_or(u32::MAX));
+        for (index, value) in strings {
+            if index >= count {
+                return Err(DecodeError::from(format!(
+                    "ETHTOOL_A_STRING_INDEX {index} out of range of string \
+                    set of {count} strings"
+                )));
+            }
+            let index = index as usize;
+            if string_set.strings.len() <= index {
+                string_set.strings.resize(index + 1, String::new());
+            }
+            string_set.strings[index] = value;
+        }
+        Ok(string_set)
+    }
+}
+
+fn parse_string_nla(payload: &[u8]) -> Result<(u32, String), DecodeError> {
+    let mut index = 0;
+    let mut value = String::new();
+    let error_msg = "failed to parse ETHTOOL_A_STRINGS_STRING";
+    for nla in NlasIterator::new(payload) {
+        let nla = &nla.context(error_msg)?;
+        match nla.kind() {
+            ETHTOOL_A_STRING_INDEX => {
+                index = parse_u32(nla.value())
+                    .context("Invalid ETHTOOL_A_STRING_INDEX value")?;
+            }
+            ETHTOOL_A_STRING_VALUE => {
+                value = parse_string(nla.value())
+                    .context("Invalid ETHTOOL_A_STRING_VALUE value")?;
+            }
+            _ => (),
+        }
+    }
+    Ok((index, value))
+}
+
+enum EthtoolStringSetNla {
+    Id(u32),
+    Count(u32),
+    Strings(Vec<EthtoolStringNla>),
+}
+
+impl Nla for EthtoolStringSetNla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Id(_) | Self::Count(_) => 4,
+            Self::Strings(strings) => strings.as_slice().buffer_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Id(_) => ETHTOOL_A_STRINGSET_ID,
+            Self::Count(_) => ETHTOOL_A_STRINGSET_COUNT,
+            Self::Strings(_) => ETHTOOL_A_STRINGSET_STRINGS | NLA_F_NESTED,
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Id(d) | Self::Count(d) => emit_u32(buffer, *d).unwrap(),
+            Self::Strings(strings) => strings.as_slice().emit(buffer),
+        }
+    }
+}
+
+struct EthtoolStringNla(u32, String);
+
+impl EthtoolStringNla {
+    fn to_nlas(&self) -> [EthtoolStringAttrNla<'_>; 2] {
+        [
+            EthtoolStringAttrNla::Index(self.0),
+            EthtoolStringAttrNla::Value(&self.1),
+        ]
+    }
+}
+
+impl Nla for EthtoolStringNla {
+    fn value_len(&self) -> usize {
+        self.to_nlas().as_slice().buffer_len()
+    }
+
+    fn kind(&self) -> u16 {
+        ETHTOOL_A_STRINGS_STRING | NLA_F_NESTED
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        self.to_nlas().as_slice().emit(buffer)
+    }
+}
+
+enum EthtoolStringAttrNla<'a> {
+    Index(u32),
+    Value(&'a str),
+}
+
+impl Nla for EthtoolStringAttrNla<'_> {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Index(_) => 4,
+            Self::Value(s) => s.len() + 1,
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Index(_) => ETHTOOL_A_STRING_INDEX,
+            Self::Value(_) => ETHTOOL_A_STRING_VALUE,
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Index(d) => emit_u32(buffer, *d).unwrap(),
+            Self::Value(s) => {
+                buffer[..s.len()].copy_from_slice(s.as_bytes());
+                buffer[s.len()] = 0;
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum EthtoolStringSetAttr {
+    Header(Vec<EthtoolHeader>),
+    StringSets(Vec<EthtoolStringSet>),
+    /// Only request the number of strings in each string set
+    CountsOnly,
+    Other(DefaultNla),
+}
+
+impl Nla for EthtoolStringSetAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Header(hdrs) => hdrs.as_slice().buffer_len(),
+            Self::StringSets(sets) => sets.as_slice().buffer_len(),
+            Self::CountsOnly => 0,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Header(_) => ETHTOOL_A_STRSET_HEADER | NLA_F_NESTED,
+            Self::StringSets(_) => ETHTOOL_A_STRSET_STRINGSETS | NLA_F_NESTED,
+            Self::CountsOnly => ETHTOOL_A_STRSET_COUNTS_ONLY,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Header(ref nlas) => nlas.as_slice().emit(buffer),
+            Self::StringSets(sets) => sets.as_slice().emit(buffer),
+            Self::CountsOnly => (),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for EthtoolStringSetAttr
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            ETHTOOL_A_STRSET_HEADER => {
+                let mut nlas = Vec::new();
+                let error_msg = "failed to parse strset header attributes";
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    let parsed =
+                        EthtoolHeader::parse(nla).context(error_msg)?;
+                    nlas.push(parsed);
+                }
+                Self::Header(nlas)
+            }
+            ETHTOOL_A_STRSET_STRINGSETS => {
+                let mut sets = Vec::new();
+                let error_msg = "failed to parse ETHTOOL_A_STRSET_STRINGSETS";
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    if nla.kind() == ETHTOOL_A_STRINGSETS_STRINGSET {
+                        sets.push(
+                            EthtoolStringSet::parse(nla).context(error_msg)?,
+                        );
+                    }
+                }
+                Self::StringSets(sets)
+            }
+            ETHTOOL_A_STRSET_COUNTS_ONLY => Self::CountsOnly,
+            kind => {
+                Self::Other(DefaultNla::parse(buf).context(format!(
+                    "invalid ethtool strset NLA kind {kind}"
+                ))?)
+            }
+        })
+    }
+}
+
+pub(crate) fn parse_strset_nlas(
+    buffer: &[u8],
+) -> Result<Vec<EthtoolAttr>, DecodeError> {
+    let mut nlas = Vec::new();
+    for nla in NlasIterator::new(buffer) {
+        let error_msg =
+            format!("Failed to parse ethtool strset message attribute {nla:?}");
+        let nla = &nla.context(error_msg.clone())?;
+        let parsed = EthtoolStringSetAttr::parse(nla).context(error_msg)?;
+        nlas.push(EthtoolAttr::StringSet(parsed));
+    }
+    Ok(nlas)
+}
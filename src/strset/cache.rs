@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: MIT
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crate::EthtoolStringSetId;
+
+type EthtoolStringSetCacheKey = (Option<String>, EthtoolStringSetId);
+
+/// String sets shared by all clones of a `EthtoolHandle`, indexed by
+/// interface name (`None` for global string sets) and string set ID.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct EthtoolStringSetCache(
+    Arc<Mutex<HashMap<EthtoolStringSetCacheKey, Vec<String>>>>,
+);
+
+impl EthtoolStringSetCache {
+    pub(crate) fn get(
+        &self,
+        iface_name: Option<&str>,
+        id: EthtoolStringSetId,
+    ) -> Option<Vec<String>> {
+        self.0
+            .lock()
+            .ok()?
+            .get(&(iface_name.map(|i| i.to_string()), id))
+            .cloned()
+    }
+
+    pub(crate) fn insert(
+        &self,
+        iface_name: Option<&str>,
+        id: EthtoolStringSetId,
+        strings: Vec<String>,
+    ) {
+        if let Ok(mut cache) = self.0.lock() {
+            cache.insert((iface_name.map(|i| i.to_string()), id), strings);
+        }
+    }
+
+    pub(crate) fn clear(&self) {
+        if let Ok(mut cache) = self.0.lock() {
+            cache.clear();
+        }
+    }
+}
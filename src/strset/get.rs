@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: MIT
+
+use futures_util::Stream;
+use netlink_packet_generic::GenlMessage;
+
+use crate::{
+    ethtool_execute, EthtoolAttr, EthtoolError, EthtoolHandle, EthtoolMessage,
+    EthtoolStringSet, EthtoolStringSetAttr, EthtoolStringSetId,
+};
+
+pub struct EthtoolStringSetGetRequest {
+    handle: EthtoolHandle,
+    iface_name: Option<String>,
+    global: bool,
+    sets: Vec<EthtoolStringSetId>,
+    counts_only: bool,
+}
+
+impl EthtoolStringSetGetRequest {
+    pub(crate) fn new(
+        handle: EthtoolHandle,
+        iface_name: Option<&str>,
+        global: bool,
+    ) -> Self {
+        EthtoolStringSetGetRequest {
+            handle,
+            iface_name: iface_name.map(|i| i.to_string()),
+            global,
+            sets: Vec::new(),
+            counts_only: false,
+        }
+    }
+
+    /// String sets to retrieve, all string sets are retrieved by default.
+    pub fn sets(mut self, sets: &[EthtoolStringSetId]) -> Self {
+        self.sets = sets.to_vec();
+        self
+    }
+
+    /// Only retrieve the number of strings in each string set
+    pub fn counts_only(mut self) -> Self {
+        self.counts_only = true;
+        self
+    }
+
+    pub async fn execute(
+        self,
+    ) -> Result<
+        impl Stream<Item = Result<GenlMessage<EthtoolMessage>, EthtoolError>>,
+        EthtoolError,
+    > {
+        let EthtoolStringSetGetRequest {
+            mut handle,
+            iface_name,
+            global,
+            sets,
+            counts_only,
+        } = self;
+
+        let mut ethtool_msg =
+            EthtoolMessage::new_strset_get(iface_name.as_deref());
+        if !sets.is_empty() {
+            ethtool_msg.nlas.push(EthtoolAttr::StringSet(
+                EthtoolStringSetAttr::StringSets(
+                    sets.into_iter().map(EthtoolStringSet::new).collect(),
+                ),
+            ));
+        }
+        if counts_only {
+            ethtool_msg
+                .nlas
+                .push(EthtoolAttr::StringSet(EthtoolStringSetAttr::CountsOnly));
+        }
+        ethtool_execute(
+            &mut handle,
+            iface_name.is_none() && !global,
+            ethtool_msg,
+        )
+        .await
+    }
+}
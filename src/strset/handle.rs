@@ -0,0 +1,87 @@
+// SPDX-License-Identifier: MIT
+
+use futures_util::StreamExt;
+
+use super::resolve::{set_bit_names, unnamed_bit_sets};
+use crate::{
+    EthtoolAttr, EthtoolError, EthtoolHandle, EthtoolMessage,
+    EthtoolStringSetAttr, EthtoolStringSetGetRequest, EthtoolStringSetId,
+};
+
+pub struct EthtoolStringSetHandle(EthtoolHandle);
+
+impl EthtoolStringSetHandle {
+    pub fn new(handle: EthtoolHandle) -> Self {
+        EthtoolStringSetHandle(handle)
+    }
+
+    /// Retrieve the string sets of a interface, or of all interfaces when
+    /// `iface_name` is `None`
+    pub fn get(
+        &mut self,
+        iface_name: Option<&str>,
+    ) -> EthtoolStringSetGetRequest {
+        EthtoolStringSetGetRequest::new(self.0.clone(), iface_name, false)
+    }
+
+    /// Retrieve the string sets not specific to any interface
+    pub fn get_global(&mut self) -> EthtoolStringSetGetRequest {
+        EthtoolStringSetGetRequest::new(self.0.clone(), None, true)
+    }
+
+    /// Retrieve the strings of a single string set of a interface, or the
+    /// global one when `iface_name` is `None`.
+    /// When the string set cache is enabled on `EthtoolHandle`, the kernel is
+    /// only queried the first time.
+    pub async fn strings(
+        &mut self,
+        iface_name: Option<&str>,
+        id: EthtoolStringSetId,
+    ) -> Result<Vec<String>, EthtoolError> {
+        let cache = self.0.strset_cache.clone();
+        if let Some(strings) =
+            cache.as_ref().and_then(|c| c.get(iface_name, id))
+        {
+            return Ok(strings);
+        }
+
+        let mut response =
+            EthtoolStringSetGetRequest::new(self.0.clone(), iface_name, true)
+                .sets(&[id])
+                .execute()
+                .await?;
+
+        let mut strings = Vec::new();
+        while let Some(genl_msg) = response.next().await {
+            for nla in genl_msg?.payload.nlas {
+                if let EthtoolAttr::StringSet(
+                    EthtoolStringSetAttr::StringSets(sets),
+                ) = nla
+                {
+                    if let Some(set) = sets.into_iter().find(|s| s.id == id) {
+                        strings = set.strings;
+                    }
+                }
+            }
+        }
+
+        if let Some(cache) = cache {
+            cache.insert(iface_name, id, strings.clone());
+        }
+        Ok(strings)
+    }
+
+    /// Name the link mode, feature and private flag bits only identified by
+    /// index, as in notifications, using the string sets retrieved by
+    /// `strings()`. Bits out of the string sets are left unnamed.
+    pub async fn resolve_names(
+        &mut self,
+        message: &mut EthtoolMessage,
+    ) -> Result<(), EthtoolError> {
+        for (iface_name, id) in unnamed_bit_sets(message) {
+            let strings = self.strings(iface_name.as_deref(), id).await?;
+            set_bit_names(message, id, &strings);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,14 @@
+// SPDX-License-Identifier: MIT
+
+mod attr;
+mod cache;
+mod get;
+mod handle;
+mod resolve;
+
+pub(crate) use attr::parse_strset_nlas;
+pub(crate) use cache::EthtoolStringSetCache;
+
+pub use attr::{EthtoolStringSet, EthtoolStringSetAttr, EthtoolStringSetId};
+pub use get::EthtoolStringSetGetRequest;
+pub use handle::EthtoolStringSetHandle;
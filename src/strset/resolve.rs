@@ -0,0 +1,208 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    EthtoolAttr, EthtoolBit, EthtoolEeeAttr, EthtoolFeatureAttr,
+    EthtoolFeatureBit, EthtoolHeader, EthtoolLinkModeAttr, EthtoolMessage,
+    EthtoolPrivFlagsAttr, EthtoolStringSetId,
+};
+
+// String sets, by interface name (`None` for global string sets) and ID,
+// holding the names of bits only identified by index in the message.
+pub(crate) fn unnamed_bit_sets(
+    message: &EthtoolMessage,
+) -> Vec<(Option<String>, EthtoolStringSetId)> {
+    let mut sets = Vec::new();
+    for nla in &message.nlas {
+        let set = match nla {
+            EthtoolAttr::LinkMode(
+                EthtoolLinkModeAttr::Ours(bits)
+                | EthtoolLinkModeAttr::Peer(bits),
+            )
+            | EthtoolAttr::Eee(
+                EthtoolEeeAttr::ModesOurs(bits)
+                | EthtoolEeeAttr::ModesPeer(bits),
+            ) if bits.iter().any(|b| b.name.is_none()) => {
+                (None, EthtoolStringSetId::LinkModes)
+            }
+            EthtoolAttr::Feature(
+                EthtoolFeatureAttr::Hw(bits)
+                | EthtoolFeatureAttr::Wanted(bits)
+                | EthtoolFeatureAttr::Active(bits)
+                | EthtoolFeatureAttr::NoChange(bits),
+            ) if bits.iter().any(|b| b.name.is_empty()) => {
+                (None, EthtoolStringSetId::Features)
+            }
+            EthtoolAttr::PrivFlags(EthtoolPrivFlagsAttr::Flags(flags))
+                if flags.iter().any(|(b, _)| b.name.is_none()) =>
+            {
+                // Private flags are specific to the interface
+                match privflags_iface_name(message) {
+                    Some(iface_name) => {
+                        (Some(iface_name), EthtoolStringSetId::PrivFlags)
+                    }
+                    None => continue,
+                }
+            }
+            _ => continue,
+        };
+        if !sets.contains(&set) {
+            sets.push(set);
+        }
+    }
+    sets
+}
+
+fn privflags_iface_name(message: &EthtoolMessage) -> Option<String> {
+    message.nlas.iter().find_map(|nla| match nla {
+        EthtoolAttr::PrivFlags(EthtoolPrivFlagsAttr::Header(hdrs)) => {
+            hdrs.iter().find_map(|hdr| match hdr {
+                EthtoolHeader::DevName(name) => Some(name.clone()),
+                _ => None,
+            })
+        }
+        _ => None,
+    })
+}
+
+// Name the bits only identified by index using the strings of string set
+// `id`, indexed the same way.
+pub(crate) fn set_bit_names(
+    message: &mut EthtoolMessage,
+    id: EthtoolStringSetId,
+    strings: &[String],
+) {
+    let name_bit = |bit: &mut EthtoolBit| {
+        if bit.name.is_none() {
+            bit.name = strings.get(bit.index as usize).cloned();
+        }
+    };
+    let name_feature_bit = |bit: &mut EthtoolFeatureBit| {
+        if bit.name.is_empty() {
+            if let Some(name) = strings.get(bit.index as usize) {
+                bit.name = name.clone();
+            }
+        }
+    };
+    for nla in message.nlas.iter_mut() {
+        match (id, nla) {
+            (
+                EthtoolStringSetId::LinkModes,
+                EthtoolAttr::LinkMode(
+                    EthtoolLinkModeAttr::Ours(bits)
+                    | EthtoolLinkModeAttr::Peer(bits),
+                )
+                | EthtoolAttr::Eee(
+                    EthtoolEeeAttr::ModesOurs(bits)
+                    | EthtoolEeeAttr::ModesPeer(bits),
+                ),
+            ) => bits.iter_mut().for_each(name_bit),
+            (
+                EthtoolStringSetId::Features,
+                EthtoolAttr::Feature(
+                    EthtoolFeatureAttr::Hw(bits)
+                    | EthtoolFeatureAttr::Wanted(bits)
+                    | EthtoolFeatureAttr::Active(bits)
+                    | EthtoolFeatureAttr::NoChange(bits),
+                ),
+            ) => bits.iter_mut().for_each(name_feature_bit),
+            (
+                EthtoolStringSetId::PrivFlags,
+                EthtoolAttr::PrivFlags(EthtoolPrivFlagsAttr::Flags(flags)),
+            ) => flags.iter_mut().for_each(|(bit, _)| name_bit(bit)),
+            _ => (),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EthtoolCmd;
+
+    #[test]
+    fn test_resolve_link_mode_ntf_names() {
+        let mut message = EthtoolMessage {
+            cmd: EthtoolCmd::LinkModeNtf,
+            nlas: vec![
+                EthtoolAttr::LinkMode(EthtoolLinkModeAttr::Header(vec![
+                    EthtoolHeader::DevName("eth0".to_string()),
+                ])),
+                EthtoolAttr::LinkMode(EthtoolLinkModeAttr::Ours(vec![
+                    EthtoolBit {
+                        index: 1,
+                        name: None,
+                    },
+                    EthtoolBit {
+                        index: 7,
+                        name: None,
+                    },
+                ])),
+            ],
+        };
+
+        assert_eq!(
+            unnamed_bit_sets(&message),
+            vec![(None, EthtoolStringSetId::LinkModes)]
+        );
+
+        let strings: Vec<String> = ["10baseT/Half", "10baseT/Full"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        set_bit_names(&mut message, EthtoolStringSetId::LinkModes, &strings);
+
+        // Index out of the string set is left unnamed
+        assert_eq!(
+            message.nlas[1],
+            EthtoolAttr::LinkMode(EthtoolLinkModeAttr::Ours(vec![
+                EthtoolBit {
+                    index: 1,
+                    name: Some("10baseT/Full".to_string()),
+                },
+                EthtoolBit {
+                    index: 7,
+                    name: None,
+                },
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_resolve_privflags_ntf_names() {
+        let mut message = EthtoolMessage {
+            cmd: EthtoolCmd::PrivFlagsNtf,
+            nlas: vec![
+                EthtoolAttr::PrivFlags(EthtoolPrivFlagsAttr::Header(vec![
+                    EthtoolHeader::DevName("eth0".to_string()),
+                ])),
+                EthtoolAttr::PrivFlags(EthtoolPrivFlagsAttr::Flags(vec![(
+                    EthtoolBit {
+                        index: 0,
+                        name: None,
+                    },
+                    true,
+                )])),
+            ],
+        };
+
+        assert_eq!(
+            unnamed_bit_sets(&message),
+            vec![(Some("eth0".to_string()), EthtoolStringSetId::PrivFlags)]
+        );
+
+        set_bit_names(
+            &mut message,
+            EthtoolStringSetId::PrivFlags,
+            &["rx_cqe_compress".to_string()],
+        );
+
+        assert_eq!(
+            message.nlas[1],
+            EthtoolAttr::PrivFlags(EthtoolPrivFlagsAttr::Flags(vec![(
+                EthtoolBit::from("rx_cqe_compress"),
+                true
+            )]))
+        );
+        assert!(unnamed_bit_sets(&message).is_empty());
+    }
+}
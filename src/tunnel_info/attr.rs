@@ -0,0 +1,371 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    emit_u16_be, emit_u32, parse_u16_be, parse_u32, DecodeError, DefaultNla,
+    Emitable, ErrorContext, Nla, NlaBuffer, NlasIterator, Parseable,
+    NLA_F_NESTED,
+};
+
+use crate::{
+    bitset_util::{
+        bitset_nlas_len, emit_bitset_nlas, parse_bitset_bits_nlas,
+        EthtoolBitSet,
+    },
+    EthtoolAttr, EthtoolHeader,
+};
+
+const ETHTOOL_A_TUNNEL_INFO_HEADER: u16 = 1;
+const ETHTOOL_A_TUNNEL_INFO_UDP_PORTS: u16 = 2;
+
+const ETHTOOL_A_TUNNEL_UDP_TABLE: u16 = 1;
+
+const ETHTOOL_A_TUNNEL_UDP_TABLE_SIZE: u16 = 1;
+const ETHTOOL_A_TUNNEL_UDP_TABLE_TYPES: u16 = 2;
+const ETHTOOL_A_TUNNEL_UDP_TABLE_ENTRY: u16 = 3;
+
+const ETHTOOL_A_TUNNEL_UDP_ENTRY_PORT: u16 = 1;
+const ETHTOOL_A_TUNNEL_UDP_ENTRY_TYPE: u16 = 2;
+
+const ETHTOOL_UDP_TUNNEL_TYPE_VXLAN: u32 = 0;
+const ETHTOOL_UDP_TUNNEL_TYPE_GENEVE: u32 = 1;
+const ETHTOOL_UDP_TUNNEL_TYPE_VXLAN_GPE: u32 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EthtoolUdpTunnelType {
+    Vxlan,
+    Geneve,
+    VxlanGpe,
+    Other(u32),
+}
+
+impl std::fmt::Display for EthtoolUdpTunnelType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Vxlan => write!(f, "vxlan"),
+            Self::Geneve => write!(f, "geneve"),
+            Self::VxlanGpe => write!(f, "vxlan-gpe"),
+            Self::Other(d) => write!(f, "{d}"),
+        }
+    }
+}
+
+impl From<u32> for EthtoolUdpTunnelType {
+    fn from(d: u32) -> Self {
+        match d {
+            ETHTOOL_UDP_TUNNEL_TYPE_VXLAN => Self::Vxlan,
+            ETHTOOL_UDP_TUNNEL_TYPE_GENEVE => Self::Geneve,
+            ETHTOOL_UDP_TUNNEL_TYPE_VXLAN_GPE => Self::VxlanGpe,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<EthtoolUdpTunnelType> for u32 {
+    fn from(v: EthtoolUdpTunnelType) -> u32 {
+        match v {
+            EthtoolUdpTunnelType::Vxlan => ETHTOOL_UDP_TUNNEL_TYPE_VXLAN,
+            EthtoolUdpTunnelType::Geneve => ETHTOOL_UDP_TUNNEL_TYPE_GENEVE,
+            EthtoolUdpTunnelType::VxlanGpe => ETHTOOL_UDP_TUNNEL_TYPE_VXLAN_GPE,
+            EthtoolUdpTunnelType::Other(d) => d,
+        }
+    }
+}
+
+/// UDP port offloaded to the NIC
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct EthtoolUdpTunnelEntry {
+    pub port: u16,
+    pub tunnel_type: EthtoolUdpTunnelType,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum EthtoolUdpTunnelEntryAttr {
+    Port(u16),
+    Type(EthtoolUdpTunnelType),
+}
+
+impl Nla for EthtoolUdpTunnelEntryAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Port(_) => 2,
+            Self::Type(_) => 4,
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Port(_) => ETHTOOL_A_TUNNEL_UDP_ENTRY_PORT,
+            Self::Type(_) => ETHTOOL_A_TUNNEL_UDP_ENTRY_TYPE,
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Port(d) => emit_u16_be(buffer, *d).unwrap(),
+            Self::Type(d) => emit_u32(buffer, (*d).into()).unwrap(),
+        }
+    }
+}
+
+impl EthtoolUdpTunnelEntry {
+    fn to_nlas(self) -> [EthtoolUdpTunnelEntryAttr; 2] {
+        [
+            EthtoolUdpTunnelEntryAttr::Port(self.port),
+            EthtoolUdpTunnelEntryAttr::Type(self.tunnel_type),
+        ]
+    }
+}
+
+impl Nla for EthtoolUdpTunnelEntry {
+    fn value_len(&self) -> usize {
+        self.to_nlas().as_slice().buffer_len()
+    }
+
+    fn kind(&self) -> u16 {
+        ETHTOOL_A_TUNNEL_UDP_TABLE_ENTRY | NLA_F_NESTED
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        self.to_nlas().as_slice().emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for EthtoolUdpTunnelEntry
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut port = None;
+        let mut tunnel_type = None;
+        for nla in NlasIterator::new(buf.value()) {
+            let nla = &nla.context("failed to parse udp tunnel entry")?;
+            let payload = nla.value();
+            match nla.kind() {
+                ETHTOOL_A_TUNNEL_UDP_ENTRY_PORT => {
+                    port = Some(parse_u16_be(payload).context(format!(
+                        "Invalid ETHTOOL_A_TUNNEL_UDP_ENTRY_PORT {payload:?}"
+                    ))?)
+                }
+                ETHTOOL_A_TUNNEL_UDP_ENTRY_TYPE => {
+                    tunnel_type = Some(
+                        parse_u32(payload)
+                            .context(format!(
+                                "Invalid ETHTOOL_A_TUNNEL_UDP_ENTRY_TYPE \
+                                {payload:?}"
+                            ))?
+                            .into(),
+                    )
+                }
+                _ => (),
+            }
+        }
+        match (port, tunnel_type) {
+            (Some(port), Some(tunnel_type)) => Ok(Self { port, tunnel_type }),
+            _ => Err(DecodeError::from(
+                "Missing port or type in ETHTOOL_A_TUNNEL_UDP_TABLE_ENTRY",
+            )),
+        }
+    }
+}
+
+/// UDP port table of the NIC
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct EthtoolUdpTunnelTable {
+    /// Maximum number of entries
+    pub size: u32,
+    /// Tunnel types the table can hold
+    pub types: Vec<EthtoolUdpTunnelType>,
+    pub entries: Vec<EthtoolUdpTunnelEntry>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum EthtoolUdpTunnelTableAttr {
+    Size(u32),
+    Types(Vec<EthtoolBitSet>),
+    Entry(EthtoolUdpTunnelEntry),
+}
+
+impl Nla for EthtoolUdpTunnelTableAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Size(_) => 4,
+            Self::Types(bits) => bitset_nlas_len(bits, true),
+            Self::Entry(entry) => entry.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Size(_) => ETHTOOL_A_TUNNEL_UDP_TABLE_SIZE,
+            Self::Types(_) => ETHTOOL_A_TUNNEL_UDP_TABLE_TYPES | NLA_F_NESTED,
+            Self::Entry(entry) => entry.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Size(d) => emit_u32(buffer, *d).unwrap(),
+            Self::Types(bits) => emit_bitset_nlas(bits, true, buffer),
+            Self::Entry(entry) => entry.emit_value(buffer),
+        }
+    }
+}
+
+impl EthtoolUdpTunnelTable {
+    fn to_nlas(&self) -> Vec<EthtoolUdpTunnelTableAttr> {
+        let mut nlas = vec![
+            EthtoolUdpTunnelTableAttr::Size(self.size),
+            EthtoolUdpTunnelTableAttr::Types(
+                self.types
+                    .iter()
+                    .map(|t| EthtoolBitSet {
+                        index: (*t).into(),
+                        name: String::new(),
+                        value: true,
+                    })
+                    .collect(),
+            ),
+        ];
+        nlas.extend(
+            self.entries
+                .iter()
+                .map(|e| EthtoolUdpTunnelTableAttr::Entry(*e)),
+        );
+        nlas
+    }
+}
+
+impl Nla for EthtoolUdpTunnelTable {
+    fn value_len(&self) -> usize {
+        self.to_nlas().as_slice().buffer_len()
+    }
+
+    fn kind(&self) -> u16 {
+        ETHTOOL_A_TUNNEL_UDP_TABLE | NLA_F_NESTED
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        self.to_nlas().as_slice().emit(buffer)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for EthtoolUdpTunnelTable
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut table = Self::default();
+        for nla in NlasIterator::new(buf.value()) {
+            let nla = &nla.context("failed to parse udp tunnel table")?;
+            let payload = nla.value();
+            match nla.kind() {
+                ETHTOOL_A_TUNNEL_UDP_TABLE_SIZE => {
+                    table.size = parse_u32(payload).context(format!(
+                        "Invalid ETHTOOL_A_TUNNEL_UDP_TABLE_SIZE {payload:?}"
+                    ))?
+                }
+                ETHTOOL_A_TUNNEL_UDP_TABLE_TYPES => {
+                    table.types = parse_bitset_bits_nlas(payload)
+                        .context(format!(
+                            "Invalid ETHTOOL_A_TUNNEL_UDP_TABLE_TYPES \
+                            {payload:?}"
+                        ))?
+                        .into_iter()
+                        .filter(|b| b.value)
+                        .map(|b| EthtoolUdpTunnelType::from(b.index))
+                        .collect()
+                }
+                ETHTOOL_A_TUNNEL_UDP_TABLE_ENTRY => table.entries.push(
+                    EthtoolUdpTunnelEntry::parse(nla)
+                        .context("Invalid ETHTOOL_A_TUNNEL_UDP_TABLE_ENTRY")?,
+                ),
+                _ => (),
+            }
+        }
+        Ok(table)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum EthtoolTunnelInfoAttr {
+    Header(Vec<EthtoolHeader>),
+    UdpPorts(Vec<EthtoolUdpTunnelTable>),
+    Other(DefaultNla),
+}
+
+impl Nla for EthtoolTunnelInfoAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Header(hdrs) => hdrs.as_slice().buffer_len(),
+            Self::UdpPorts(tables) => tables.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Header(_) => ETHTOOL_A_TUNNEL_INFO_HEADER | NLA_F_NESTED,
+            Self::UdpPorts(_) => ETHTOOL_A_TUNNEL_INFO_UDP_PORTS | NLA_F_NESTED,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Header(ref nlas) => nlas.as_slice().emit(buffer),
+            Self::UdpPorts(tables) => tables.as_slice().emit(buffer),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for EthtoolTunnelInfoAttr
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            ETHTOOL_A_TUNNEL_INFO_HEADER => {
+                let mut nlas = Vec::new();
+                let error_msg = "failed to parse tunnel info header attributes";
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    let parsed =
+                        EthtoolHeader::parse(nla).context(error_msg)?;
+                    nlas.push(parsed);
+                }
+                Self::Header(nlas)
+            }
+            ETHTOOL_A_TUNNEL_INFO_UDP_PORTS => {
+                let mut tables = Vec::new();
+                let error_msg = "Invalid ETHTOOL_A_TUNNEL_INFO_UDP_PORTS";
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    if nla.kind() == ETHTOOL_A_TUNNEL_UDP_TABLE {
+                        tables.push(
+                            EthtoolUdpTunnelTable::parse(nla)
+                                .context(error_msg)?,
+                        );
+                    }
+                }
+                Self::UdpPorts(tables)
+            }
+            kind => Self::Other(DefaultNla::parse(buf).context(format!(
+                "invalid ethtool tunnel info NLA kind {kind}"
+            ))?),
+        })
+    }
+}
+
+pub(crate) fn parse_tunnel_info_nlas(
+    buffer: &[u8],
+) -> Result<Vec<EthtoolAttr>, DecodeError> {
+    let mut nlas = Vec::new();
+    for nla in NlasIterator::new(buffer) {
+        let error_msg = format!(
+            "Failed to parse ethtool tunnel info message attribute {nla:?}"
+        );
+        let nla = &nla.context(error_msg.clone())?;
+        let parsed = EthtoolTunnelInfoAttr::parse(nla).context(error_msg)?;
+        nlas.push(EthtoolAttr::TunnelInfo(parsed));
+    }
+    Ok(nlas)
+}
@@ -0,0 +1,20 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{EthtoolHandle, EthtoolTunnelInfoGetRequest};
+
+pub struct EthtoolTunnelInfoHandle(EthtoolHandle);
+
+impl EthtoolTunnelInfoHandle {
+    pub fn new(handle: EthtoolHandle) -> Self {
+        EthtoolTunnelInfoHandle(handle)
+    }
+
+    /// Retrieve the UDP tunnel port offload tables of an interface (used by
+    /// `ethtool --show-tunnels eth1`)
+    pub fn get(
+        &mut self,
+        iface_name: Option<&str>,
+    ) -> EthtoolTunnelInfoGetRequest {
+        EthtoolTunnelInfoGetRequest::new(self.0.clone(), iface_name)
+    }
+}
@@ -0,0 +1,13 @@
+// SPDX-License-Identifier: MIT
+
+mod attr;
+mod get;
+mod handle;
+
+pub(crate) use attr::parse_tunnel_info_nlas;
+pub use attr::{
+    EthtoolTunnelInfoAttr, EthtoolUdpTunnelEntry, EthtoolUdpTunnelTable,
+    EthtoolUdpTunnelType,
+};
+pub use get::EthtoolTunnelInfoGetRequest;
+pub use handle::EthtoolTunnelInfoHandle;
@@ -0,0 +1,198 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_core::{
+    DecodeError, DefaultNla, Emitable, ErrorContext, Nla, NlaBuffer,
+    NlasIterator, Parseable, NLA_F_NESTED,
+};
+
+use crate::{
+    bitset_util::{
+        bitset_nlas_len, emit_bitset_nlas, parse_bitset_bits_nlas,
+        EthtoolBitSet,
+    },
+    EthtoolAttr, EthtoolHeader,
+};
+
+const ETHTOOL_A_WOL_HEADER: u16 = 1;
+const ETHTOOL_A_WOL_MODES: u16 = 2;
+const ETHTOOL_A_WOL_SOPASS: u16 = 3;
+
+const WAKE_PHY_BIT: u32 = 0;
+const WAKE_UCAST_BIT: u32 = 1;
+const WAKE_MCAST_BIT: u32 = 2;
+const WAKE_BCAST_BIT: u32 = 3;
+const WAKE_ARP_BIT: u32 = 4;
+const WAKE_MAGIC_BIT: u32 = 5;
+const WAKE_MAGICSECURE_BIT: u32 = 6;
+const WAKE_FILTER_BIT: u32 = 7;
+
+pub(crate) const WOL_MODE_COUNT: u32 = 8;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EthtoolWolMode {
+    Phy,
+    Unicast,
+    Multicast,
+    Broadcast,
+    Arp,
+    Magic,
+    /// Magic packet with SecureOn password, only meaningful with `Magic`
+    MagicSecure,
+    Filter,
+    /// Bit index of WoL mode unknown to this crate
+    Other(u32),
+}
+
+impl std::fmt::Display for EthtoolWolMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Phy => write!(f, "phy"),
+            Self::Unicast => write!(f, "ucast"),
+            Self::Multicast => write!(f, "mcast"),
+            Self::Broadcast => write!(f, "bcast"),
+            Self::Arp => write!(f, "arp"),
+            Self::Magic => write!(f, "magic"),
+            Self::MagicSecure => write!(f, "magicsecure"),
+            Self::Filter => write!(f, "filter"),
+            Self::Other(d) => write!(f, "{d}"),
+        }
+    }
+}
+
+impl From<u32> for EthtoolWolMode {
+    fn from(d: u32) -> Self {
+        match d {
+            WAKE_PHY_BIT => Self::Phy,
+            WAKE_UCAST_BIT => Self::Unicast,
+            WAKE_MCAST_BIT => Self::Multicast,
+            WAKE_BCAST_BIT => Self::Broadcast,
+            WAKE_ARP_BIT => Self::Arp,
+            WAKE_MAGIC_BIT => Self::Magic,
+            WAKE_MAGICSECURE_BIT => Self::MagicSecure,
+            WAKE_FILTER_BIT => Self::Filter,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<EthtoolWolMode> for u32 {
+    fn from(v: EthtoolWolMode) -> u32 {
+        match v {
+            EthtoolWolMode::Phy => WAKE_PHY_BIT,
+            EthtoolWolMode::Unicast => WAKE_UCAST_BIT,
+            EthtoolWolMode::Multicast => WAKE_MCAST_BIT,
+            EthtoolWolMode::Broadcast => WAKE_BCAST_BIT,
+            EthtoolWolMode::Arp => WAKE_ARP_BIT,
+            EthtoolWolMode::Magic => WAKE_MAGIC_BIT,
+            EthtoolWolMode::MagicSecure => WAKE_MAGICSECURE_BIT,
+            EthtoolWolMode::Filter => WAKE_FILTER_BIT,
+            EthtoolWolMode::Other(d) => d,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum EthtoolWolAttr {
+    Header(Vec<EthtoolHeader>),
+    /// Supported modes and whether they are enabled.
+    /// When setting, only listed modes are changed.
+    Modes(Vec<(EthtoolWolMode, bool)>),
+    /// SecureOn password, 6 bytes
+    Sopass(Vec<u8>),
+    Other(DefaultNla),
+}
+
+// WoL modes are emitted with mask, so modes not listed are left unchanged.
+fn wol_modes_to_bitset(modes: &[(EthtoolWolMode, bool)]) -> Vec<EthtoolBitSet> {
+    modes
+        .iter()
+        .map(|(mode, enabled)| EthtoolBitSet {
+            index: (*mode).into(),
+            name: String::new(),
+            value: *enabled,
+        })
+        .collect()
+}
+
+impl Nla for EthtoolWolAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Header(hdrs) => hdrs.as_slice().buffer_len(),
+            Self::Modes(modes) => {
+                bitset_nlas_len(&wol_modes_to_bitset(modes), false)
+            }
+            Self::Sopass(d) => d.len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Header(_) => ETHTOOL_A_WOL_HEADER | NLA_F_NESTED,
+            Self::Modes(_) => ETHTOOL_A_WOL_MODES | NLA_F_NESTED,
+            Self::Sopass(_) => ETHTOOL_A_WOL_SOPASS,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Header(ref nlas) => nlas.as_slice().emit(buffer),
+            Self::Modes(modes) => {
+                emit_bitset_nlas(&wol_modes_to_bitset(modes), false, buffer)
+            }
+            Self::Sopass(d) => buffer[..d.len()].copy_from_slice(d),
+            Self::Other(ref attr) => attr.emit(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for EthtoolWolAttr
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            ETHTOOL_A_WOL_HEADER => {
+                let mut nlas = Vec::new();
+                let error_msg = "failed to parse wol header attributes";
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    let parsed =
+                        EthtoolHeader::parse(nla).context(error_msg)?;
+                    nlas.push(parsed);
+                }
+                Self::Header(nlas)
+            }
+            ETHTOOL_A_WOL_MODES => {
+                let bits = parse_bitset_bits_nlas(payload).context(format!(
+                    "Invalid ETHTOOL_A_WOL_MODES {payload:?}"
+                ))?;
+                Self::Modes(
+                    bits.into_iter()
+                        .map(|b| (EthtoolWolMode::from(b.index), b.value))
+                        .collect(),
+                )
+            }
+            ETHTOOL_A_WOL_SOPASS => Self::Sopass(payload.to_vec()),
+            kind => Self::Other(
+                DefaultNla::parse(buf)
+                    .context(format!("invalid ethtool wol NLA kind {kind}"))?,
+            ),
+        })
+    }
+}
+
+pub(crate) fn parse_wol_nlas(
+    buffer: &[u8],
+) -> Result<Vec<EthtoolAttr>, DecodeError> {
+    let mut nlas = Vec::new();
+    for nla in NlasIterator::new(buffer) {
+        let error_msg =
+            format!("Failed to parse ethtool wol message attribute {nla:?}");
+        let nla = &nla.context(error_msg.clone())?;
+        let parsed = EthtoolWolAttr::parse(nla).context(error_msg)?;
+        nlas.push(EthtoolAttr::Wol(parsed));
+    }
+    Ok(nlas)
+}
@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{EthtoolHandle, EthtoolWolGetRequest, EthtoolWolSetRequest};
+
+pub struct EthtoolWolHandle(EthtoolHandle);
+
+impl EthtoolWolHandle {
+    pub fn new(handle: EthtoolHandle) -> Self {
+        EthtoolWolHandle(handle)
+    }
+
+    /// Retrieve the Wake-on-LAN settings of a interface (used by
+    /// `ethtool eth1`)
+    pub fn get(&mut self, iface_name: Option<&str>) -> EthtoolWolGetRequest {
+        EthtoolWolGetRequest::new(self.0.clone(), iface_name)
+    }
+
+    /// Set the Wake-on-LAN settings of a interface (equivalent to
+    /// `ethtool -s eth1 wol g`)
+    pub fn set(&mut self, iface_name: &str) -> EthtoolWolSetRequest {
+        EthtoolWolSetRequest::new(self.0.clone(), iface_name)
+    }
+}
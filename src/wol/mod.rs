@@ -0,0 +1,13 @@
+// SPDX-License-Identifier: MIT
+
+mod attr;
+mod get;
+mod handle;
+mod set;
+
+pub(crate) use attr::parse_wol_nlas;
+
+pub use attr::{EthtoolWolAttr, EthtoolWolMode};
+pub use get::EthtoolWolGetRequest;
+pub use handle::EthtoolWolHandle;
+pub use set::EthtoolWolSetRequest;
@@ -0,0 +1,84 @@
+// SPDX-License-Identifier: MIT
+
+use futures_util::StreamExt;
+use netlink_packet_core::{NetlinkMessage, NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+
+use super::attr::WOL_MODE_COUNT;
+use crate::{
+    try_ethtool, EthtoolAttr, EthtoolError, EthtoolHandle, EthtoolMessage,
+    EthtoolWolAttr, EthtoolWolMode,
+};
+
+pub struct EthtoolWolSetRequest {
+    handle: EthtoolHandle,
+    message: EthtoolMessage,
+    modes: Vec<(EthtoolWolMode, bool)>,
+    sopass: Option<[u8; 6]>,
+}
+
+impl EthtoolWolSetRequest {
+    pub(crate) fn new(handle: EthtoolHandle, iface_name: &str) -> Self {
+        EthtoolWolSetRequest {
+            handle,
+            message: EthtoolMessage::new_wol_set(iface_name),
+            modes: Vec::new(),
+            sopass: None,
+        }
+    }
+
+    /// Enable or disable single WoL mode, other modes are left unchanged.
+    pub fn mode(mut self, mode: EthtoolWolMode, enabled: bool) -> Self {
+        self.modes.retain(|(m, _)| *m != mode);
+        self.modes.push((mode, enabled));
+        self
+    }
+
+    /// WoL modes to enable, modes not listed will be disabled.
+    pub fn modes(mut self, modes: &[EthtoolWolMode]) -> Self {
+        self.modes = (0..WOL_MODE_COUNT)
+            .map(EthtoolWolMode::from)
+            .map(|mode| (mode, modes.contains(&mode)))
+            .collect();
+        self
+    }
+
+    /// SecureOn password used by `EthtoolWolMode::MagicSecure`
+    pub fn sopass(mut self, sopass: [u8; 6]) -> Self {
+        self.sopass = Some(sopass);
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), EthtoolError> {
+        let EthtoolWolSetRequest {
+            mut handle,
+            mut message,
+            modes,
+            sopass,
+        } = self;
+
+        if !modes.is_empty() {
+            message
+                .nlas
+                .push(EthtoolAttr::Wol(EthtoolWolAttr::Modes(modes)));
+        }
+        if let Some(sopass) = sopass {
+            message.nlas.push(EthtoolAttr::Wol(EthtoolWolAttr::Sopass(
+                sopass.to_vec(),
+            )));
+        }
+
+        let mut nl_msg =
+            NetlinkMessage::from(GenlMessage::from_payload(message));
+
+        nl_msg.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        let mut response = handle.request(nl_msg).await?;
+
+        while let Some(message) = response.next().await {
+            try_ethtool!(message);
+        }
+
+        Ok(())
+    }
+}
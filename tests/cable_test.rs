@@ -0,0 +1,104 @@
+// SPDX-License-Identifier: MIT
+
+use ethtool::{
+    EthtoolAttr, EthtoolCablePair, EthtoolCableResultCode, EthtoolCableTdrNest,
+    EthtoolCableTdrStep, EthtoolCableTestAttr, EthtoolCableTestNest,
+    EthtoolCableTestStatus, EthtoolCableTestTdrAttr, EthtoolCmd, EthtoolHeader,
+    EthtoolMessage,
+};
+use netlink_packet_core::{Emitable, ParseableParametrized};
+use netlink_packet_generic::GenlHeader;
+
+#[test]
+fn test_cable_test_ntf() {
+    let raw: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x02, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x2c, 0x00, 0x03, 0x80, 0x14, 0x00, 0x01, 0x80, 0x05, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x02, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x14, 0x00, 0x02, 0x80, 0x05, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x02, 0x00, 0xdc, 0x05, 0x00, 0x00,
+    ];
+
+    let expected = EthtoolMessage {
+        cmd: EthtoolCmd::CableTestNtf,
+        nlas: vec![
+            EthtoolAttr::CableTest(EthtoolCableTestAttr::Header(vec![
+                EthtoolHeader::DevName("eth0".to_string()),
+            ])),
+            EthtoolAttr::CableTest(EthtoolCableTestAttr::Status(
+                EthtoolCableTestStatus::Completed,
+            )),
+            EthtoolAttr::CableTest(EthtoolCableTestAttr::Nest(vec![
+                EthtoolCableTestNest::Result(
+                    EthtoolCablePair::A,
+                    EthtoolCableResultCode::Open,
+                ),
+                EthtoolCableTestNest::FaultLength(EthtoolCablePair::A, 1500),
+            ])),
+        ],
+    };
+
+    let header = GenlHeader {
+        cmd: 27,
+        version: 1,
+    };
+    assert_eq!(
+        expected,
+        EthtoolMessage::parse_with_param(&raw, header).unwrap()
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+    assert_eq!(buffer, raw);
+}
+
+#[test]
+fn test_cable_test_tdr_ntf() {
+    let raw: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x02, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x54, 0x00, 0x03, 0x80, 0x0c, 0x00, 0x03, 0x80, 0x06, 0x00, 0x01, 0x00,
+        0xe8, 0x03, 0x00, 0x00, 0x1c, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x64, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00, 0x2c, 0x01, 0x00, 0x00,
+        0x08, 0x00, 0x03, 0x00, 0x64, 0x00, 0x00, 0x00, 0x14, 0x00, 0x02, 0x80,
+        0x05, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00,
+        0xd6, 0xff, 0x00, 0x00, 0x14, 0x00, 0x02, 0x80, 0x05, 0x00, 0x01, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00, 0x07, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = EthtoolMessage {
+        cmd: EthtoolCmd::CableTestTdrNtf,
+        nlas: vec![
+            EthtoolAttr::CableTestTdr(EthtoolCableTestTdrAttr::Header(vec![
+                EthtoolHeader::DevName("eth0".to_string()),
+            ])),
+            EthtoolAttr::CableTestTdr(EthtoolCableTestTdrAttr::Status(
+                EthtoolCableTestStatus::Completed,
+            )),
+            EthtoolAttr::CableTestTdr(EthtoolCableTestTdrAttr::Nest(vec![
+                EthtoolCableTdrNest::Pulse(1000),
+                EthtoolCableTdrNest::Step(EthtoolCableTdrStep {
+                    first: 100,
+                    last: 300,
+                    step: 100,
+                }),
+                EthtoolCableTdrNest::Amplitude(EthtoolCablePair::B, -42),
+                EthtoolCableTdrNest::Amplitude(EthtoolCablePair::B, 7),
+            ])),
+        ],
+    };
+
+    let header = GenlHeader {
+        cmd: 28,
+        version: 1,
+    };
+    assert_eq!(
+        expected,
+        EthtoolMessage::parse_with_param(&raw, header).unwrap()
+    );
+
+    let mut buffer = vec![0; expected.buffer_len()];
+    expected.emit(&mut buffer);
+    assert_eq!(buffer, raw);
+}
@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: MIT
+
+use ethtool::{
+    EthtoolAttr, EthtoolCmd, EthtoolDebugAttr, EthtoolHeader, EthtoolMessage,
+    EthtoolMsgLevel,
+};
+use netlink_packet_core::{Emitable, ParseableParametrized};
+use netlink_packet_generic::GenlHeader;
+
+#[test]
+fn test_debug_get_reply() {
+    let raw: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x02, 0x80, 0x04, 0x00, 0x01, 0x00,
+        0x08, 0x00, 0x02, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x03, 0x80,
+        0x18, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x09, 0x00, 0x02, 0x00, 0x6c, 0x69, 0x6e, 0x6b, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = EthtoolMessage {
+        cmd: EthtoolCmd::DebugGetReply,
+        nlas: vec![
+            EthtoolAttr::Debug(EthtoolDebugAttr::Header(vec![
+                EthtoolHeader::DevName("eth0".to_string()),
+            ])),
+            EthtoolAttr::Debug(EthtoolDebugAttr::MsgMask(vec![(
+                EthtoolMsgLevel::Link,
+                true,
+            )])),
+        ],
+    };
+
+    let header = GenlHeader { cmd: 7, version: 1 };
+
+    assert_eq!(
+        expected,
+        EthtoolMessage::parse_with_param(&raw, header).unwrap(),
+    );
+}
+
+#[test]
+fn test_debug_set() {
+    let expected: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x24, 0x00, 0x02, 0x80, 0x20, 0x00, 0x03, 0x80,
+        0x10, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x04, 0x00, 0x03, 0x00, 0x0c, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let mut msg = EthtoolMessage::new_debug_set("eth0");
+    msg.nlas
+        .push(EthtoolAttr::Debug(EthtoolDebugAttr::MsgMask(vec![
+            (EthtoolMsgLevel::Link, true),
+            (EthtoolMsgLevel::Drv, false),
+        ])));
+
+    let mut raw = vec![0; msg.buffer_len()];
+    msg.emit(&mut raw);
+
+    assert_eq!(expected, raw);
+}
+
+#[test]
+fn test_msg_level_from_str() {
+    for index in 0..16 {
+        let level = EthtoolMsgLevel::from(index);
+        assert_eq!(level, level.to_string().parse().unwrap());
+    }
+    assert_eq!(
+        EthtoolMsgLevel::RxErr,
+        "rx_err".parse::<EthtoolMsgLevel>().unwrap()
+    );
+    assert!("rx-err".parse::<EthtoolMsgLevel>().is_err());
+}
@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: MIT
+
+use ethtool::{
+    EthtoolAttr, EthtoolBit, EthtoolCmd, EthtoolEeeAttr, EthtoolHeader,
+    EthtoolMessage,
+};
+use netlink_packet_core::ParseableParametrized;
+use netlink_packet_generic::GenlHeader;
+
+#[test]
+fn test_eee_get_reply() {
+    let raw: Vec<u8> = vec![
+        0x18, 0x01, 0x00, 0x00, 0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00,
+        0x65, 0x74, 0x68, 0x30, 0x00, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x03, 0x80,
+        0x04, 0x00, 0x01, 0x00, 0x24, 0x00, 0x03, 0x80, 0x20, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x05, 0x00, 0x00, 0x00, 0x13, 0x00, 0x02, 0x00,
+        0x31, 0x30, 0x30, 0x30, 0x62, 0x61, 0x73, 0x65, 0x54, 0x2f, 0x46, 0x75,
+        0x6c, 0x6c, 0x00, 0x00, 0x05, 0x00, 0x04, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x05, 0x00, 0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x07, 0x00,
+        0x0a, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = EthtoolMessage {
+        cmd: EthtoolCmd::EeeGetReply,
+        nlas: vec![
+            EthtoolAttr::Eee(EthtoolEeeAttr::Header(vec![
+                EthtoolHeader::DevName("eth0".to_string()),
+            ])),
+            EthtoolAttr::Eee(EthtoolEeeAttr::ModesPeer(vec![EthtoolBit {
+                index: 5,
+                name: Some("1000baseT/Full".to_string()),
+            }])),
+            EthtoolAttr::Eee(EthtoolEeeAttr::Active(true)),
+            EthtoolAttr::Eee(EthtoolEeeAttr::Enabled(true)),
+            EthtoolAttr::Eee(EthtoolEeeAttr::TxLpiTimer(10)),
+        ],
+    };
+
+    let header = GenlHeader::parse(&raw[..]).unwrap();
+
+    assert_eq!(
+        expected,
+        EthtoolMessage::parse_with_param(&raw[4..], header).unwrap(),
+    );
+}
@@ -0,0 +1,150 @@
+// SPDX-License-Identifier: MIT
+
+use ethtool::{
+    EthtoolAttr, EthtoolCmd, EthtoolFeatureAttr, EthtoolFeatureBit,
+    EthtoolHeader, EthtoolMessage,
+};
+use netlink_packet_core::{Emitable, ParseableParametrized};
+use netlink_packet_generic::GenlHeader;
+
+#[test]
+fn test_features_set() {
+    let expected: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x38, 0x00, 0x03, 0x80, 0x34, 0x00, 0x03, 0x80,
+        0x10, 0x00, 0x01, 0x80, 0x0b, 0x00, 0x02, 0x00, 0x72, 0x78, 0x2d, 0x67,
+        0x72, 0x6f, 0x00, 0x00, 0x20, 0x00, 0x01, 0x80, 0x18, 0x00, 0x02, 0x00,
+        0x74, 0x78, 0x2d, 0x74, 0x63, 0x70, 0x2d, 0x73, 0x65, 0x67, 0x6d, 0x65,
+        0x6e, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x00, 0x04, 0x00, 0x03, 0x00,
+    ];
+
+    let msg = EthtoolMessage {
+        cmd: EthtoolCmd::FeatureSet,
+        nlas: vec![
+            EthtoolAttr::Feature(EthtoolFeatureAttr::Header(vec![
+                EthtoolHeader::DevName("eth0".to_string()),
+            ])),
+            EthtoolAttr::Feature(EthtoolFeatureAttr::Wanted(vec![
+                EthtoolFeatureBit {
+                    index: 0,
+                    name: "rx-gro".to_string(),
+                    value: false,
+                },
+                EthtoolFeatureBit {
+                    index: 0,
+                    name: "tx-tcp-segmentation".to_string(),
+                    value: true,
+                },
+            ])),
+        ],
+    };
+
+    let mut raw = vec![0; msg.buffer_len()];
+    msg.emit(&mut raw);
+
+    assert_eq!(expected, raw);
+}
+
+#[test]
+fn test_features_set_reply() {
+    // Wanted and active features differing after the request: wanted
+    // `tx-scatter-gather` on and `rx-gro` off, but `tx-scatter-gather` is
+    // still off.
+    let raw: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x48, 0x00, 0x03, 0x80, 0x44, 0x00, 0x03, 0x80,
+        0x28, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x16, 0x00, 0x02, 0x00, 0x74, 0x78, 0x2d, 0x73, 0x63, 0x61, 0x74, 0x74,
+        0x65, 0x72, 0x2d, 0x67, 0x61, 0x74, 0x68, 0x65, 0x72, 0x00, 0x00, 0x00,
+        0x04, 0x00, 0x03, 0x00, 0x18, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x0e, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x02, 0x00, 0x72, 0x78, 0x2d, 0x67,
+        0x72, 0x6f, 0x00, 0x00, 0x2c, 0x00, 0x04, 0x80, 0x28, 0x00, 0x03, 0x80,
+        0x24, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x16, 0x00, 0x02, 0x00, 0x74, 0x78, 0x2d, 0x73, 0x63, 0x61, 0x74, 0x74,
+        0x65, 0x72, 0x2d, 0x67, 0x61, 0x74, 0x68, 0x65, 0x72, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = EthtoolMessage {
+        cmd: EthtoolCmd::FeatureSetReply,
+        nlas: vec![
+            EthtoolAttr::Feature(EthtoolFeatureAttr::Header(vec![
+                EthtoolHeader::DevName("eth0".to_string()),
+            ])),
+            EthtoolAttr::Feature(EthtoolFeatureAttr::Wanted(vec![
+                EthtoolFeatureBit {
+                    index: 0,
+                    name: "tx-scatter-gather".to_string(),
+                    value: true,
+                },
+                EthtoolFeatureBit {
+                    index: 14,
+                    name: "rx-gro".to_string(),
+                    value: false,
+                },
+            ])),
+            EthtoolAttr::Feature(EthtoolFeatureAttr::Active(vec![
+                EthtoolFeatureBit {
+                    index: 0,
+                    name: "tx-scatter-gather".to_string(),
+                    value: false,
+                },
+            ])),
+        ],
+    };
+
+    let header = GenlHeader {
+        cmd: 12,
+        version: 1,
+    };
+
+    assert_eq!(
+        expected,
+        EthtoolMessage::parse_with_param(&raw, header).unwrap(),
+    );
+}
+
+#[test]
+fn test_features_get_reply_nomask() {
+    // Bitset without mask lists enabled features only
+    let raw: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x48, 0x00, 0x04, 0x80, 0x04, 0x00, 0x01, 0x00,
+        0x40, 0x00, 0x03, 0x80, 0x24, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x16, 0x00, 0x02, 0x00, 0x74, 0x78, 0x2d, 0x73,
+        0x63, 0x61, 0x74, 0x74, 0x65, 0x72, 0x2d, 0x67, 0x61, 0x74, 0x68, 0x65,
+        0x72, 0x00, 0x00, 0x00, 0x18, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x0e, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x02, 0x00, 0x72, 0x78, 0x2d, 0x67,
+        0x72, 0x6f, 0x00, 0x00,
+    ];
+
+    let expected = EthtoolMessage {
+        cmd: EthtoolCmd::FeatureGetReply,
+        nlas: vec![
+            EthtoolAttr::Feature(EthtoolFeatureAttr::Header(vec![
+                EthtoolHeader::DevName("eth0".to_string()),
+            ])),
+            EthtoolAttr::Feature(EthtoolFeatureAttr::Active(vec![
+                EthtoolFeatureBit {
+                    index: 0,
+                    name: "tx-scatter-gather".to_string(),
+                    value: true,
+                },
+                EthtoolFeatureBit {
+                    index: 14,
+                    name: "rx-gro".to_string(),
+                    value: true,
+                },
+            ])),
+        ],
+    };
+
+    let header = GenlHeader {
+        cmd: 11,
+        version: 1,
+    };
+
+    assert_eq!(
+        expected,
+        EthtoolMessage::parse_with_param(&raw, header).unwrap(),
+    );
+}
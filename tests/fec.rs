@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: MIT
+
+use ethtool::{
+    EthtoolAttr, EthtoolCmd, EthtoolFecAttr, EthtoolFecMode, EthtoolHeader,
+    EthtoolMessage,
+};
+use netlink_packet_core::Emitable;
+
+#[test]
+fn test_fec_set() {
+    let expected: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x18, 0x00, 0x02, 0x80, 0x04, 0x00, 0x01, 0x00,
+        0x10, 0x00, 0x03, 0x80, 0x0c, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x32, 0x00, 0x00, 0x00, 0x05, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let msg = EthtoolMessage {
+        cmd: EthtoolCmd::FecSet,
+        nlas: vec![
+            EthtoolAttr::Fec(EthtoolFecAttr::Header(vec![
+                EthtoolHeader::DevName("eth0".to_string()),
+            ])),
+            EthtoolAttr::Fec(EthtoolFecAttr::Modes(vec![EthtoolFecMode::Rs])),
+            EthtoolAttr::Fec(EthtoolFecAttr::Auto(false)),
+        ],
+    };
+
+    let mut raw = vec![0; msg.buffer_len()];
+    msg.emit(&mut raw);
+
+    assert_eq!(expected, raw);
+}
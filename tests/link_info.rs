@@ -0,0 +1,84 @@
+// SPDX-License-Identifier: MIT
+
+use ethtool::{
+    EthtoolAttr, EthtoolCmd, EthtoolHeader, EthtoolLinkInfoAttr,
+    EthtoolLinkInfoMdix, EthtoolLinkInfoPort, EthtoolMessage,
+};
+use netlink_packet_core::{Emitable, ParseableParametrized};
+use netlink_packet_generic::GenlHeader;
+
+#[test]
+fn test_link_info_get_reply() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x01, 0x00, 0x00, 0x18, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x02, 0x00, 0x76, 0x65, 0x74, 0x68,
+        0x30, 0x00, 0x00, 0x00, 0x05, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x04, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x05, 0x00, 0x03, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = EthtoolMessage {
+        cmd: EthtoolCmd::LinkInfoGetReply,
+        nlas: vec![
+            EthtoolAttr::LinkInfo(EthtoolLinkInfoAttr::Header(vec![
+                EthtoolHeader::DevIndex(6),
+                EthtoolHeader::DevName("veth0".to_string()),
+            ])),
+            EthtoolAttr::LinkInfo(EthtoolLinkInfoAttr::Port(
+                EthtoolLinkInfoPort::Tp,
+            )),
+            EthtoolAttr::LinkInfo(EthtoolLinkInfoAttr::PhyAddr(0)),
+            EthtoolAttr::LinkInfo(EthtoolLinkInfoAttr::TpMdix(
+                EthtoolLinkInfoMdix::Mdi,
+            )),
+            EthtoolAttr::LinkInfo(EthtoolLinkInfoAttr::TpMdixCtrl(
+                EthtoolLinkInfoMdix::Auto,
+            )),
+            EthtoolAttr::LinkInfo(EthtoolLinkInfoAttr::Transceiver(0)),
+        ],
+    };
+
+    let header = GenlHeader::parse(&raw[..]).unwrap();
+
+    assert_eq!(
+        expected,
+        EthtoolMessage::parse_with_param(&raw[4..], header).unwrap(),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len() + header.buffer_len()];
+    header.emit(&mut buffer);
+    expected.emit(&mut buffer[4..]);
+    assert_eq!(&buffer, &raw);
+}
+
+#[test]
+fn test_link_info_set() {
+    let expected: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x02, 0x00, 0x04, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x05, 0x00,
+        0x02, 0x00, 0x00, 0x00,
+    ];
+
+    let msg = EthtoolMessage {
+        cmd: EthtoolCmd::LinkInfoSet,
+        nlas: vec![
+            EthtoolAttr::LinkInfo(EthtoolLinkInfoAttr::Header(vec![
+                EthtoolHeader::DevName("eth0".to_string()),
+            ])),
+            EthtoolAttr::LinkInfo(EthtoolLinkInfoAttr::Port(
+                EthtoolLinkInfoPort::Fibre,
+            )),
+            EthtoolAttr::LinkInfo(EthtoolLinkInfoAttr::PhyAddr(1)),
+            EthtoolAttr::LinkInfo(EthtoolLinkInfoAttr::TpMdixCtrl(
+                EthtoolLinkInfoMdix::MdiX,
+            )),
+        ],
+    };
+
+    let mut raw = vec![0; msg.buffer_len()];
+    msg.emit(&mut raw);
+
+    assert_eq!(expected, raw);
+}
@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: MIT
+
+use ethtool::{
+    EthtoolAttr, EthtoolBit, EthtoolCmd, EthtoolHeader, EthtoolLinkModeAttr,
+    EthtoolLinkModeDuplex, EthtoolMessage,
+};
+use netlink_packet_core::{Emitable, ParseableParametrized};
+use netlink_packet_generic::GenlHeader;
+
+#[test]
+fn test_link_modes_set_speed_duplex() {
+    let expected: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x05, 0x00, 0xe8, 0x03, 0x00, 0x00, 0x05, 0x00, 0x06, 0x00,
+        0x01, 0x00, 0x00, 0x00,
+    ];
+
+    let msg = EthtoolMessage {
+        cmd: EthtoolCmd::LinkModeSet,
+        nlas: vec![
+            EthtoolAttr::LinkMode(EthtoolLinkModeAttr::Header(vec![
+                EthtoolHeader::DevName("eth0".to_string()),
+            ])),
+            EthtoolAttr::LinkMode(EthtoolLinkModeAttr::Autoneg(false)),
+            EthtoolAttr::LinkMode(EthtoolLinkModeAttr::Speed(1000)),
+            EthtoolAttr::LinkMode(EthtoolLinkModeAttr::Duplex(
+                EthtoolLinkModeDuplex::Full,
+            )),
+        ],
+    };
+
+    let mut raw = vec![0; msg.buffer_len()];
+    msg.emit(&mut raw);
+
+    assert_eq!(expected, raw);
+}
+
+#[test]
+fn test_link_modes_set_advertise() {
+    let expected: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x24, 0x00, 0x03, 0x80, 0x04, 0x00, 0x01, 0x00,
+        0x1c, 0x00, 0x03, 0x80, 0x18, 0x00, 0x01, 0x80, 0x13, 0x00, 0x02, 0x00,
+        0x31, 0x30, 0x30, 0x30, 0x62, 0x61, 0x73, 0x65, 0x54, 0x2f, 0x46, 0x75,
+        0x6c, 0x6c, 0x00, 0x00,
+    ];
+
+    let msg = EthtoolMessage {
+        cmd: EthtoolCmd::LinkModeSet,
+        nlas: vec![
+            EthtoolAttr::LinkMode(EthtoolLinkModeAttr::Header(vec![
+                EthtoolHeader::DevName("eth0".to_string()),
+            ])),
+            EthtoolAttr::LinkMode(EthtoolLinkModeAttr::Ours(vec![
+                EthtoolBit::from("1000baseT/Full"),
+            ])),
+        ],
+    };
+
+    let mut raw = vec![0; msg.buffer_len()];
+    msg.emit(&mut raw);
+
+    assert_eq!(expected, raw);
+}
+
+#[test]
+fn test_link_modes_get_reply_peer_nomask() {
+    let raw: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x4c, 0x00, 0x04, 0x80, 0x04, 0x00, 0x01, 0x00,
+        0x44, 0x00, 0x03, 0x80, 0x20, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x03, 0x00, 0x00, 0x00, 0x12, 0x00, 0x02, 0x00, 0x31, 0x30, 0x30, 0x62,
+        0x61, 0x73, 0x65, 0x54, 0x2f, 0x46, 0x75, 0x6c, 0x6c, 0x00, 0x00, 0x00,
+        0x20, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00, 0x05, 0x00, 0x00, 0x00,
+        0x13, 0x00, 0x02, 0x00, 0x31, 0x30, 0x30, 0x30, 0x62, 0x61, 0x73, 0x65,
+        0x54, 0x2f, 0x46, 0x75, 0x6c, 0x6c, 0x00, 0x00,
+    ];
+
+    let expected = EthtoolMessage {
+        cmd: EthtoolCmd::LinkModeGetReply,
+        nlas: vec![
+            EthtoolAttr::LinkMode(EthtoolLinkModeAttr::Header(vec![
+                EthtoolHeader::DevName("eth0".to_string()),
+            ])),
+            EthtoolAttr::LinkMode(EthtoolLinkModeAttr::Peer(vec![
+                EthtoolBit {
+                    index: 3,
+                    name: Some("100baseT/Full".to_string()),
+                },
+                EthtoolBit {
+                    index: 5,
+                    name: Some("1000baseT/Full".to_string()),
+                },
+            ])),
+        ],
+    };
+
+    let header = GenlHeader { cmd: 4, version: 1 };
+
+    assert_eq!(
+        expected,
+        EthtoolMessage::parse_with_param(&raw, header).unwrap(),
+    );
+}
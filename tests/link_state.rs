@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: MIT
+
+use ethtool::{
+    EthtoolAttr, EthtoolCmd, EthtoolHeader, EthtoolLinkExtState,
+    EthtoolLinkExtSubstate, EthtoolLinkStateAttr, EthtoolMessage,
+};
+use netlink_packet_core::{Emitable, ParseableParametrized};
+use netlink_packet_generic::GenlHeader;
+
+#[test]
+fn test_link_state_get_reply() {
+    let raw: Vec<u8> = vec![
+        0x06, 0x01, 0x00, 0x00, 0x18, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x06, 0x00,
+        0x01, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = EthtoolMessage {
+        cmd: EthtoolCmd::LinkStateGetReply,
+        nlas: vec![
+            EthtoolAttr::LinkState(EthtoolLinkStateAttr::Header(vec![
+                EthtoolHeader::DevIndex(2),
+                EthtoolHeader::DevName("eth0".to_string()),
+            ])),
+            EthtoolAttr::LinkState(EthtoolLinkStateAttr::Link(false)),
+            EthtoolAttr::LinkState(EthtoolLinkStateAttr::ExtState(
+                EthtoolLinkExtState::Autoneg,
+            )),
+            EthtoolAttr::LinkState(EthtoolLinkStateAttr::ExtSubstate(
+                EthtoolLinkExtSubstate::AnNoPartnerDetected,
+            )),
+        ],
+    };
+
+    let header = GenlHeader::parse(&raw[..]).unwrap();
+
+    assert_eq!(
+        expected,
+        EthtoolMessage::parse_with_param(&raw[4..], header).unwrap(),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len() + header.buffer_len()];
+    header.emit(&mut buffer);
+    expected.emit(&mut buffer[4..]);
+    assert_eq!(&buffer, &raw);
+}
@@ -0,0 +1,83 @@
+// SPDX-License-Identifier: MIT
+
+use ethtool::{
+    EthtoolAttr, EthtoolCmd, EthtoolHeader, EthtoolMessage, EthtoolMmAttr,
+    EthtoolMmStatAttr, EthtoolMmVerifyStatus,
+};
+use netlink_packet_core::{Emitable, ParseableParametrized};
+use netlink_packet_generic::GenlHeader;
+
+#[test]
+fn test_mm_get_reply() {
+    let raw: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x05, 0x00, 0x3c, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x06, 0x00, 0x3c, 0x00, 0x00, 0x00, 0x05, 0x00, 0x07, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x08, 0x00, 0x03, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x09, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x08, 0x00, 0x0a, 0x00,
+        0x80, 0x00, 0x00, 0x00, 0x28, 0x00, 0x0b, 0x80, 0x0c, 0x00, 0x02, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x04, 0x00,
+        0x2c, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x07, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = EthtoolMessage {
+        cmd: EthtoolCmd::MmGetReply,
+        nlas: vec![
+            EthtoolAttr::Mm(EthtoolMmAttr::Header(vec![
+                EthtoolHeader::DevName("eth0".to_string()),
+            ])),
+            EthtoolAttr::Mm(EthtoolMmAttr::PmacEnabled(true)),
+            EthtoolAttr::Mm(EthtoolMmAttr::TxEnabled(true)),
+            EthtoolAttr::Mm(EthtoolMmAttr::TxActive(false)),
+            EthtoolAttr::Mm(EthtoolMmAttr::TxMinFragSize(60)),
+            EthtoolAttr::Mm(EthtoolMmAttr::RxMinFragSize(60)),
+            EthtoolAttr::Mm(EthtoolMmAttr::VerifyEnabled(true)),
+            EthtoolAttr::Mm(EthtoolMmAttr::VerifyStatus(
+                EthtoolMmVerifyStatus::Succeeded,
+            )),
+            EthtoolAttr::Mm(EthtoolMmAttr::VerifyTime(10)),
+            EthtoolAttr::Mm(EthtoolMmAttr::MaxVerifyTime(128)),
+            EthtoolAttr::Mm(EthtoolMmAttr::Stats(vec![
+                EthtoolMmStatAttr::ReassemblyErrors(1),
+                EthtoolMmStatAttr::ReassemblyOk(300),
+                EthtoolMmStatAttr::HoldCount(2),
+            ])),
+        ],
+    };
+
+    let header = GenlHeader {
+        cmd: 42,
+        version: 1,
+    };
+
+    assert_eq!(
+        expected,
+        EthtoolMessage::parse_with_param(&raw, header).unwrap(),
+    );
+}
+
+#[test]
+fn test_mm_set() {
+    let expected: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x07, 0x00, 0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x09, 0x00,
+        0x64, 0x00, 0x00, 0x00,
+    ];
+
+    let mut msg = EthtoolMessage::new_mm_set("eth0");
+    msg.nlas
+        .push(EthtoolAttr::Mm(EthtoolMmAttr::TxEnabled(true)));
+    msg.nlas
+        .push(EthtoolAttr::Mm(EthtoolMmAttr::VerifyEnabled(true)));
+    msg.nlas
+        .push(EthtoolAttr::Mm(EthtoolMmAttr::VerifyTime(100)));
+
+    let mut raw = vec![0; msg.buffer_len()];
+    msg.emit(&mut raw);
+
+    assert_eq!(expected, raw);
+}
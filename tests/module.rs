@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: MIT
+
+use ethtool::{
+    EthtoolAttr, EthtoolCmd, EthtoolHeader, EthtoolMessage, EthtoolModuleAttr,
+    EthtoolModulePowerMode, EthtoolModulePowerModePolicy,
+};
+use netlink_packet_core::{Emitable, ParseableParametrized};
+use netlink_packet_generic::GenlHeader;
+
+#[test]
+fn test_module_get_reply() {
+    let raw: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x02, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = EthtoolMessage {
+        cmd: EthtoolCmd::ModuleGetReply,
+        nlas: vec![
+            EthtoolAttr::Module(EthtoolModuleAttr::Header(vec![
+                EthtoolHeader::DevName("eth0".to_string()),
+            ])),
+            EthtoolAttr::Module(EthtoolModuleAttr::PowerModePolicy(
+                EthtoolModulePowerModePolicy::Auto,
+            )),
+            EthtoolAttr::Module(EthtoolModuleAttr::PowerMode(
+                EthtoolModulePowerMode::Low,
+            )),
+        ],
+    };
+
+    let header = GenlHeader {
+        cmd: 35,
+        version: 1,
+    };
+
+    assert_eq!(
+        expected,
+        EthtoolMessage::parse_with_param(&raw, header).unwrap(),
+    );
+}
+
+#[test]
+fn test_module_set() {
+    let expected: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00,
+    ];
+
+    let mut msg = EthtoolMessage::new_module_set("eth0");
+    msg.nlas
+        .push(EthtoolAttr::Module(EthtoolModuleAttr::PowerModePolicy(
+            EthtoolModulePowerModePolicy::High,
+        )));
+
+    let mut raw = vec![0; msg.buffer_len()];
+    msg.emit(&mut raw);
+
+    assert_eq!(expected, raw);
+}
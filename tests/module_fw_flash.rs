@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: MIT
+
+use ethtool::{
+    EthtoolAttr, EthtoolCmd, EthtoolHeader, EthtoolMessage,
+    EthtoolModuleFwFlashAttr, EthtoolModuleFwFlashStatus,
+};
+use netlink_packet_core::{Emitable, ParseableParametrized};
+use netlink_packet_generic::GenlHeader;
+
+#[test]
+fn test_module_fw_flash_ntf() {
+    // ETHTOOL_A_MODULE_FW_FLASH_DONE in u32 and
+    // ETHTOOL_A_MODULE_FW_FLASH_TOTAL in u64
+    let raw: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x04, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x06, 0x00, 0x00, 0x10, 0x00, 0x00, 0x0c, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = EthtoolMessage {
+        cmd: EthtoolCmd::ModuleFwFlashNtf,
+        nlas: vec![
+            EthtoolAttr::ModuleFwFlash(EthtoolModuleFwFlashAttr::Header(vec![
+                EthtoolHeader::DevName("eth0".to_string()),
+            ])),
+            EthtoolAttr::ModuleFwFlash(EthtoolModuleFwFlashAttr::Status(
+                EthtoolModuleFwFlashStatus::InProgress,
+            )),
+            EthtoolAttr::ModuleFwFlash(EthtoolModuleFwFlashAttr::Done(4096)),
+            EthtoolAttr::ModuleFwFlash(EthtoolModuleFwFlashAttr::Total(65536)),
+        ],
+    };
+
+    let header = GenlHeader {
+        cmd: 44,
+        version: 1,
+    };
+
+    assert_eq!(
+        expected,
+        EthtoolMessage::parse_with_param(&raw, header).unwrap(),
+    );
+}
+
+#[test]
+fn test_module_fw_flash_act() {
+    let expected: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x02, 0x00, 0x66, 0x77, 0x2e, 0x62,
+        0x69, 0x6e, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00, 0x78, 0x56, 0x34, 0x12,
+    ];
+
+    let mut msg = EthtoolMessage::new_module_fw_flash_act("eth0", "fw.bin");
+    msg.nlas.push(EthtoolAttr::ModuleFwFlash(
+        EthtoolModuleFwFlashAttr::Password(0x12345678),
+    ));
+
+    let mut raw = vec![0; msg.buffer_len()];
+    msg.emit(&mut raw);
+
+    assert_eq!(expected, raw);
+}
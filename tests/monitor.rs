@@ -0,0 +1,247 @@
+// SPDX-License-Identifier: MIT
+
+use ethtool::{
+    ethtool_monitor, EthtoolAttr, EthtoolBit, EthtoolCmd, EthtoolFeatureAttr,
+    EthtoolFeatureBit, EthtoolHeader, EthtoolLinkInfoAttr, EthtoolLinkInfoPort,
+    EthtoolLinkModeAttr, EthtoolMessage, EthtoolPrivFlagsAttr,
+};
+use futures_util::{stream, StreamExt};
+use genetlink::message::RawGenlMessage;
+use netlink_packet_core::{
+    NetlinkHeader, NetlinkMessage, NetlinkPayload, ParseableParametrized,
+};
+use netlink_packet_generic::GenlHeader;
+use netlink_sys::SocketAddr;
+
+#[tokio::test]
+async fn test_monitor_link_info_ntf() {
+    let payload: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+    let raw =
+        RawGenlMessage::new(GenlHeader { cmd: 3, version: 1 }, payload, 0);
+    let nl_msg = NetlinkMessage::new(
+        NetlinkHeader::default(),
+        NetlinkPayload::InnerMessage(raw),
+    );
+
+    let mut notifications =
+        ethtool_monitor(stream::iter(vec![(nl_msg, SocketAddr::new(0, 0))]));
+
+    let msg = notifications.next().await.unwrap().unwrap();
+    assert_eq!(
+        msg.payload,
+        EthtoolMessage {
+            cmd: EthtoolCmd::LinkInfoNtf,
+            nlas: vec![
+                EthtoolAttr::LinkInfo(EthtoolLinkInfoAttr::Header(vec![
+                    EthtoolHeader::DevName("eth0".to_string()),
+                ])),
+                EthtoolAttr::LinkInfo(EthtoolLinkInfoAttr::Port(
+                    EthtoolLinkInfoPort::Tp
+                )),
+            ],
+        }
+    );
+    assert!(notifications.next().await.is_none());
+}
+
+#[tokio::test]
+async fn test_monitor_skip_unknown_ntf() {
+    let payload: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+    // ETHTOOL_MSG_PHY_NTF and ETHTOOL_MSG_RSS_DELETE_NTF around a known
+    // ETHTOOL_MSG_LINKINFO_NTF
+    let nl_msgs = [46, 3, 53].into_iter().map(|cmd| {
+        let raw = RawGenlMessage::new(
+            GenlHeader { cmd, version: 1 },
+            payload.clone(),
+            0,
+        );
+        (
+            NetlinkMessage::new(
+                NetlinkHeader::default(),
+                NetlinkPayload::InnerMessage(raw),
+            ),
+            SocketAddr::new(0, 0),
+        )
+    });
+
+    let mut notifications = ethtool_monitor(stream::iter(nl_msgs));
+
+    let msg = notifications.next().await.unwrap().unwrap();
+    assert_eq!(msg.payload.cmd, EthtoolCmd::LinkInfoNtf);
+    assert!(notifications.next().await.is_none());
+}
+
+#[test]
+fn test_feature_ntf_compact_bitset() {
+    let raw: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x18, 0x00, 0x03, 0x80, 0x04, 0x00, 0x01, 0x00,
+        0x08, 0x00, 0x02, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x04, 0x00,
+        0x05, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = EthtoolMessage {
+        cmd: EthtoolCmd::FeatureNtf,
+        nlas: vec![
+            EthtoolAttr::Feature(EthtoolFeatureAttr::Header(vec![
+                EthtoolHeader::DevName("eth0".to_string()),
+            ])),
+            EthtoolAttr::Feature(EthtoolFeatureAttr::Wanted(vec![
+                EthtoolFeatureBit {
+                    index: 0,
+                    name: String::new(),
+                    value: true,
+                },
+                EthtoolFeatureBit {
+                    index: 2,
+                    name: String::new(),
+                    value: true,
+                },
+            ])),
+        ],
+    };
+
+    let header = GenlHeader {
+        cmd: 13,
+        version: 1,
+    };
+    assert_eq!(
+        expected,
+        EthtoolMessage::parse_with_param(&raw, header).unwrap()
+    );
+}
+
+#[test]
+fn test_feature_ntf_compact_bitset_oversized() {
+    // ETHTOOL_A_BITSET_SIZE 0xffffffff with single u32 of value
+    let raw: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x18, 0x00, 0x03, 0x80, 0x04, 0x00, 0x01, 0x00,
+        0x08, 0x00, 0x02, 0x00, 0xff, 0xff, 0xff, 0xff, 0x08, 0x00, 0x04, 0x00,
+        0x01, 0x00, 0x00, 0x80,
+    ];
+
+    let expected = EthtoolMessage {
+        cmd: EthtoolCmd::FeatureNtf,
+        nlas: vec![
+            EthtoolAttr::Feature(EthtoolFeatureAttr::Header(vec![
+                EthtoolHeader::DevName("eth0".to_string()),
+            ])),
+            EthtoolAttr::Feature(EthtoolFeatureAttr::Wanted(vec![
+                EthtoolFeatureBit {
+                    index: 0,
+                    name: String::new(),
+                    value: true,
+                },
+                EthtoolFeatureBit {
+                    index: 31,
+                    name: String::new(),
+                    value: true,
+                },
+            ])),
+        ],
+    };
+
+    let header = GenlHeader {
+        cmd: 13,
+        version: 1,
+    };
+    assert_eq!(
+        expected,
+        EthtoolMessage::parse_with_param(&raw, header).unwrap()
+    );
+}
+
+#[test]
+fn test_link_modes_ntf_compact_bitset() {
+    let raw: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x24, 0x00, 0x03, 0x80, 0x08, 0x00, 0x02, 0x00,
+        0x40, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x04, 0x00, 0x60, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x05, 0x00, 0x7f, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x04, 0x80, 0x04, 0x00, 0x01, 0x00,
+        0x08, 0x00, 0x02, 0x00, 0x40, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x04, 0x00,
+        0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = EthtoolMessage {
+        cmd: EthtoolCmd::LinkModeNtf,
+        nlas: vec![
+            EthtoolAttr::LinkMode(EthtoolLinkModeAttr::Header(vec![
+                EthtoolHeader::DevName("eth0".to_string()),
+            ])),
+            EthtoolAttr::LinkMode(EthtoolLinkModeAttr::Ours(vec![
+                EthtoolBit {
+                    index: 5,
+                    name: None,
+                },
+                EthtoolBit {
+                    index: 6,
+                    name: None,
+                },
+            ])),
+            EthtoolAttr::LinkMode(EthtoolLinkModeAttr::Peer(vec![
+                EthtoolBit {
+                    index: 3,
+                    name: None,
+                },
+            ])),
+        ],
+    };
+
+    let header = GenlHeader { cmd: 5, version: 1 };
+    assert_eq!(
+        expected,
+        EthtoolMessage::parse_with_param(&raw, header).unwrap()
+    );
+}
+
+#[test]
+fn test_privflags_ntf_compact_bitset() {
+    let raw: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x02, 0x80, 0x08, 0x00, 0x02, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x08, 0x00, 0x04, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x05, 0x00, 0x03, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = EthtoolMessage {
+        cmd: EthtoolCmd::PrivFlagsNtf,
+        nlas: vec![
+            EthtoolAttr::PrivFlags(EthtoolPrivFlagsAttr::Header(vec![
+                EthtoolHeader::DevName("eth0".to_string()),
+            ])),
+            EthtoolAttr::PrivFlags(EthtoolPrivFlagsAttr::Flags(vec![
+                (
+                    EthtoolBit {
+                        index: 0,
+                        name: None,
+                    },
+                    true,
+                ),
+                (
+                    EthtoolBit {
+                        index: 1,
+                        name: None,
+                    },
+                    false,
+                ),
+            ])),
+        ],
+    };
+
+    let header = GenlHeader {
+        cmd: 15,
+        version: 1,
+    };
+    assert_eq!(
+        expected,
+        EthtoolMessage::parse_with_param(&raw, header).unwrap()
+    );
+}
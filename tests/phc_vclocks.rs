@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT
+
+use ethtool::{
+    EthtoolAttr, EthtoolCmd, EthtoolHeader, EthtoolMessage,
+    EthtoolPhcVclocksAttr,
+};
+use netlink_packet_core::{Emitable, ParseableParametrized};
+use netlink_packet_generic::GenlHeader;
+
+#[test]
+fn test_phc_vclocks_get_reply() {
+    let raw: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = EthtoolMessage {
+        cmd: EthtoolCmd::PhcVclocksGetReply,
+        nlas: vec![
+            EthtoolAttr::PhcVclocks(EthtoolPhcVclocksAttr::Header(vec![
+                EthtoolHeader::DevName("eth0".to_string()),
+            ])),
+            EthtoolAttr::PhcVclocks(EthtoolPhcVclocksAttr::Num(2)),
+            EthtoolAttr::PhcVclocks(EthtoolPhcVclocksAttr::Index(vec![1, 3])),
+        ],
+    };
+
+    let header = GenlHeader {
+        cmd: 34,
+        version: 1,
+    };
+
+    assert_eq!(
+        expected,
+        EthtoolMessage::parse_with_param(&raw, header).unwrap(),
+    );
+
+    let mut emitted = vec![0; expected.buffer_len()];
+    expected.emit(&mut emitted);
+    assert_eq!(raw, emitted);
+}
@@ -0,0 +1,92 @@
+// SPDX-License-Identifier: MIT
+
+use ethtool::{
+    EthtoolAttr, EthtoolCmd, EthtoolHeader, EthtoolMessage, EthtoolPlcaAttr,
+};
+use netlink_packet_core::{Emitable, ParseableParametrized};
+use netlink_packet_generic::GenlHeader;
+
+#[test]
+fn test_plca_get_cfg_reply() {
+    let raw: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00, 0x0a, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x05, 0x00,
+        0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x07, 0x00, 0x20, 0x00, 0x00, 0x00, 0x08, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x09, 0x00, 0x80, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = EthtoolMessage {
+        cmd: EthtoolCmd::PlcaGetCfgReply,
+        nlas: vec![
+            EthtoolAttr::Plca(EthtoolPlcaAttr::Header(vec![
+                EthtoolHeader::DevName("eth0".to_string()),
+            ])),
+            EthtoolAttr::Plca(EthtoolPlcaAttr::Version(0x0a)),
+            EthtoolAttr::Plca(EthtoolPlcaAttr::Enabled(true)),
+            EthtoolAttr::Plca(EthtoolPlcaAttr::NodeCount(8)),
+            EthtoolAttr::Plca(EthtoolPlcaAttr::NodeId(0)),
+            EthtoolAttr::Plca(EthtoolPlcaAttr::ToTimer(32)),
+            EthtoolAttr::Plca(EthtoolPlcaAttr::BurstCount(0)),
+            EthtoolAttr::Plca(EthtoolPlcaAttr::BurstTimer(128)),
+        ],
+    };
+
+    let header = GenlHeader {
+        cmd: 39,
+        version: 1,
+    };
+
+    assert_eq!(
+        expected,
+        EthtoolMessage::parse_with_param(&raw, header).unwrap(),
+    );
+}
+
+#[test]
+fn test_plca_get_status_reply() {
+    let raw: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x04, 0x00, 0x01, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = EthtoolMessage {
+        cmd: EthtoolCmd::PlcaGetStatusReply,
+        nlas: vec![
+            EthtoolAttr::Plca(EthtoolPlcaAttr::Header(vec![
+                EthtoolHeader::DevName("eth0".to_string()),
+            ])),
+            EthtoolAttr::Plca(EthtoolPlcaAttr::Status(true)),
+        ],
+    };
+
+    let header = GenlHeader {
+        cmd: 40,
+        version: 1,
+    };
+
+    assert_eq!(
+        expected,
+        EthtoolMessage::parse_with_param(&raw, header).unwrap(),
+    );
+}
+
+#[test]
+fn test_plca_set_cfg() {
+    let expected: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x06, 0x00, 0x01, 0x00, 0x00, 0x00,
+    ];
+
+    let mut msg = EthtoolMessage::new_plca_set_cfg("eth0");
+    msg.nlas
+        .push(EthtoolAttr::Plca(EthtoolPlcaAttr::Enabled(true)));
+    msg.nlas.push(EthtoolAttr::Plca(EthtoolPlcaAttr::NodeId(1)));
+
+    let mut raw = vec![0; msg.buffer_len()];
+    msg.emit(&mut raw);
+
+    assert_eq!(expected, raw);
+}
@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: MIT
+
+use ethtool::{
+    EthtoolAttr, EthtoolBit, EthtoolCmd, EthtoolHeader, EthtoolMessage,
+    EthtoolPrivFlagsAttr,
+};
+use netlink_packet_core::{Emitable, ParseableParametrized};
+use netlink_packet_generic::GenlHeader;
+
+#[test]
+fn test_privflags_get_reply() {
+    let raw: Vec<u8> = vec![
+        0x0e, 0x01, 0x00, 0x00, 0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00,
+        0x65, 0x74, 0x68, 0x30, 0x00, 0x00, 0x00, 0x00, 0x34, 0x00, 0x02, 0x80,
+        0x30, 0x00, 0x03, 0x80, 0x1c, 0x00, 0x01, 0x80, 0x14, 0x00, 0x02, 0x00,
+        0x72, 0x78, 0x5f, 0x63, 0x71, 0x65, 0x5f, 0x63, 0x6f, 0x6d, 0x70, 0x72,
+        0x65, 0x73, 0x73, 0x00, 0x04, 0x00, 0x03, 0x00, 0x10, 0x00, 0x01, 0x80,
+        0x0b, 0x00, 0x02, 0x00, 0x6c, 0x65, 0x67, 0x61, 0x63, 0x79, 0x00, 0x00,
+    ];
+
+    let expected = EthtoolMessage {
+        cmd: EthtoolCmd::PrivFlagsGetReply,
+        nlas: vec![
+            EthtoolAttr::PrivFlags(EthtoolPrivFlagsAttr::Header(vec![
+                EthtoolHeader::DevName("eth0".to_string()),
+            ])),
+            EthtoolAttr::PrivFlags(EthtoolPrivFlagsAttr::Flags(vec![
+                (EthtoolBit::from("rx_cqe_compress"), true),
+                (EthtoolBit::from("legacy"), false),
+            ])),
+        ],
+    };
+
+    let header = GenlHeader::parse(&raw[..]).unwrap();
+
+    assert_eq!(
+        expected,
+        EthtoolMessage::parse_with_param(&raw[4..], header).unwrap(),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len() + header.buffer_len()];
+    header.emit(&mut buffer);
+    expected.emit(&mut buffer[4..]);
+    assert_eq!(&buffer, &raw);
+}
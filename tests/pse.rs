@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: MIT
+
+use ethtool::{
+    EthtoolAttr, EthtoolC33PsePwDStatus, EthtoolCmd, EthtoolHeader,
+    EthtoolMessage, EthtoolPseAdminState, EthtoolPseAttr,
+    EthtoolPsePwLimitRange,
+};
+use netlink_packet_core::{Emitable, ParseableParametrized};
+use netlink_packet_generic::GenlHeader;
+
+#[test]
+fn test_pse_get_reply() {
+    let raw: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x05, 0x00, 0x03, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x07, 0x00, 0x04, 0x00, 0x00, 0x00, 0x08, 0x00, 0x08, 0x00,
+        0x04, 0x00, 0x00, 0x00, 0x08, 0x00, 0x09, 0x00, 0xa0, 0x0f, 0x00, 0x00,
+        0x08, 0x00, 0x0c, 0x00, 0xc0, 0x5d, 0x00, 0x00, 0x14, 0x00, 0x0d, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0xd0, 0x07, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00,
+        0xc0, 0x5d, 0x00, 0x00,
+    ];
+
+    let expected = EthtoolMessage {
+        cmd: EthtoolCmd::PseGetReply,
+        nlas: vec![
+            EthtoolAttr::Pse(EthtoolPseAttr::Header(vec![
+                EthtoolHeader::DevName("eth0".to_string()),
+            ])),
+            EthtoolAttr::Pse(EthtoolPseAttr::C33AdminState(
+                EthtoolPseAdminState::Enabled,
+            )),
+            EthtoolAttr::Pse(EthtoolPseAttr::C33PwDStatus(
+                EthtoolC33PsePwDStatus::Delivering,
+            )),
+            EthtoolAttr::Pse(EthtoolPseAttr::C33PwClass(4)),
+            EthtoolAttr::Pse(EthtoolPseAttr::C33ActualPw(4000)),
+            EthtoolAttr::Pse(EthtoolPseAttr::C33AvailPwLimit(24000)),
+            EthtoolAttr::Pse(EthtoolPseAttr::C33PwLimitRange(
+                EthtoolPsePwLimitRange {
+                    min: 2000,
+                    max: 24000,
+                },
+            )),
+        ],
+    };
+
+    let header = GenlHeader {
+        cmd: 37,
+        version: 1,
+    };
+
+    assert_eq!(
+        expected,
+        EthtoolMessage::parse_with_param(&raw, header).unwrap(),
+    );
+
+    let mut emitted = vec![0; expected.buffer_len()];
+    expected.emit(&mut emitted);
+    assert_eq!(raw, emitted);
+}
+
+#[test]
+fn test_pse_set() {
+    let expected: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x06, 0x00, 0x03, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x0c, 0x00, 0xc0, 0x5d, 0x00, 0x00,
+    ];
+
+    let mut msg = EthtoolMessage::new_pse_set("eth0");
+    msg.nlas
+        .push(EthtoolAttr::Pse(EthtoolPseAttr::C33AdminControl(
+            EthtoolPseAdminState::Enabled,
+        )));
+    msg.nlas
+        .push(EthtoolAttr::Pse(EthtoolPseAttr::C33AvailPwLimit(24000)));
+
+    let mut raw = vec![0; msg.buffer_len()];
+    msg.emit(&mut raw);
+
+    assert_eq!(expected, raw);
+}
@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: MIT
+
+use ethtool::{
+    EthtoolAttr, EthtoolCmd, EthtoolHeader, EthtoolMessage, EthtoolRingAttr,
+};
+use netlink_packet_core::Emitable;
+
+#[test]
+fn test_rings_set() {
+    let expected: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x06, 0x00, 0x00, 0x04, 0x00, 0x00,
+        0x08, 0x00, 0x09, 0x00, 0x00, 0x02, 0x00, 0x00, 0x05, 0x00, 0x0d, 0x00,
+        0x01, 0x00, 0x00, 0x00,
+    ];
+
+    let msg = EthtoolMessage {
+        cmd: EthtoolCmd::RingSet,
+        nlas: vec![
+            EthtoolAttr::Ring(EthtoolRingAttr::Header(vec![
+                EthtoolHeader::DevName("eth0".to_string()),
+            ])),
+            EthtoolAttr::Ring(EthtoolRingAttr::Rx(1024)),
+            EthtoolAttr::Ring(EthtoolRingAttr::Tx(512)),
+            EthtoolAttr::Ring(EthtoolRingAttr::TxPush(true)),
+        ],
+    };
+
+    let mut raw = vec![0; msg.buffer_len()];
+    msg.emit(&mut raw);
+
+    assert_eq!(expected, raw);
+}
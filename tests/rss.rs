@@ -0,0 +1,65 @@
+// SPDX-License-Identifier: MIT
+
+use ethtool::{
+    EthtoolAttr, EthtoolCmd, EthtoolHeader, EthtoolMessage, EthtoolRssAttr,
+    EthtoolRssHashFunction, EthtoolRssInputXfrm,
+};
+use netlink_packet_core::{Emitable, ParseableParametrized};
+use netlink_packet_generic::GenlHeader;
+
+#[test]
+fn test_rss_get_reply() {
+    let raw: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0x14, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x05, 0x00, 0x6d, 0x5a, 0x56, 0xda,
+        0x08, 0x00, 0x06, 0x00, 0x01, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = EthtoolMessage {
+        cmd: EthtoolCmd::RssGetReply,
+        nlas: vec![
+            EthtoolAttr::Rss(EthtoolRssAttr::Header(vec![
+                EthtoolHeader::DevName("eth0".to_string()),
+            ])),
+            EthtoolAttr::Rss(EthtoolRssAttr::Context(1)),
+            EthtoolAttr::Rss(EthtoolRssAttr::Hfunc(
+                EthtoolRssHashFunction::Toeplitz,
+            )),
+            EthtoolAttr::Rss(EthtoolRssAttr::Indir(vec![0, 1, 0, 1])),
+            EthtoolAttr::Rss(EthtoolRssAttr::Hkey(vec![
+                0x6d, 0x5a, 0x56, 0xda,
+            ])),
+            EthtoolAttr::Rss(EthtoolRssAttr::InputXfrm(
+                EthtoolRssInputXfrm::SymXor,
+            )),
+        ],
+    };
+
+    let header = GenlHeader {
+        cmd: 38,
+        version: 1,
+    };
+
+    assert_eq!(
+        expected,
+        EthtoolMessage::parse_with_param(&raw, header).unwrap(),
+    );
+}
+
+#[test]
+fn test_rss_delete_act() {
+    let expected: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00, 0x03, 0x00, 0x00, 0x00,
+    ];
+
+    let msg = EthtoolMessage::new_rss_delete_act("eth0", 3);
+
+    let mut raw = vec![0; msg.buffer_len()];
+    msg.emit(&mut raw);
+
+    assert_eq!(expected, raw);
+}
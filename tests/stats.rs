@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: MIT
+
+use ethtool::{
+    EthtoolAttr, EthtoolCmd, EthtoolHeader, EthtoolMessage, EthtoolRmonStats,
+    EthtoolStatsAttr, EthtoolStatsGroup, EthtoolStatsHistBucket,
+    EthtoolStatsSrc,
+};
+use netlink_packet_core::{Emitable, ParseableParametrized};
+use netlink_packet_generic::GenlHeader;
+
+#[test]
+fn test_stats_get_reply_rmon() {
+    let raw: Vec<u8> = vec![
+        0x21, 0x01, 0x00, 0x00, 0x10, 0x00, 0x02, 0x80, 0x09, 0x00, 0x02, 0x00,
+        0x65, 0x74, 0x68, 0x30, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x54, 0x00, 0x04, 0x80, 0x08, 0x00, 0x02, 0x00,
+        0x03, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00, 0x14, 0x00, 0x00, 0x00,
+        0x10, 0x00, 0x04, 0x80, 0x0c, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x04, 0x80, 0x0c, 0x00, 0x03, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x05, 0x80,
+        0x08, 0x00, 0x07, 0x00, 0x40, 0x00, 0x00, 0x00, 0x08, 0x00, 0x08, 0x00,
+        0x40, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x09, 0x00, 0x05, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = EthtoolMessage {
+        cmd: EthtoolCmd::StatsGetReply,
+        nlas: vec![
+            EthtoolAttr::Stats(EthtoolStatsAttr::Header(vec![
+                EthtoolHeader::DevName("eth0".to_string()),
+            ])),
+            EthtoolAttr::Stats(EthtoolStatsAttr::Src(
+                EthtoolStatsSrc::Aggregate,
+            )),
+            EthtoolAttr::Stats(EthtoolStatsAttr::Group(
+                EthtoolStatsGroup::Rmon(EthtoolRmonStats {
+                    undersize_pkts: Some(7),
+                    jabbers: Some(2),
+                    hist_rx: vec![EthtoolStatsHistBucket {
+                        low: 64,
+                        high: 64,
+                        value: 5,
+                    }],
+                    ..Default::default()
+                }),
+            )),
+        ],
+    };
+
+    let header = GenlHeader::parse(&raw[..]).unwrap();
+
+    assert_eq!(
+        expected,
+        EthtoolMessage::parse_with_param(&raw[4..], header).unwrap(),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len() + header.buffer_len()];
+    header.emit(&mut buffer);
+    expected.emit(&mut buffer[4..]);
+    assert_eq!(&buffer, &raw);
+}
@@ -0,0 +1,104 @@
+// SPDX-License-Identifier: MIT
+
+use ethtool::{
+    EthtoolAttr, EthtoolCmd, EthtoolHeader, EthtoolMessage, EthtoolStringSet,
+    EthtoolStringSetAttr, EthtoolStringSetId,
+};
+use netlink_packet_core::{Emitable, ParseableParametrized};
+use netlink_packet_generic::GenlHeader;
+
+#[test]
+fn test_strset_get_reply() {
+    let raw: Vec<u8> = vec![
+        0x01, 0x01, 0x00, 0x00, 0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00,
+        0x65, 0x74, 0x68, 0x30, 0x00, 0x00, 0x00, 0x00, 0x44, 0x00, 0x02, 0x80,
+        0x40, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x02, 0x00, 0x02, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x03, 0x80,
+        0x14, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x07, 0x00, 0x02, 0x00, 0x72, 0x78, 0x00, 0x00, 0x14, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x07, 0x00, 0x02, 0x00,
+        0x74, 0x78, 0x00, 0x00,
+    ];
+
+    let expected = EthtoolMessage {
+        cmd: EthtoolCmd::StringSetGetReply,
+        nlas: vec![
+            EthtoolAttr::StringSet(EthtoolStringSetAttr::Header(vec![
+                EthtoolHeader::DevName("eth0".to_string()),
+            ])),
+            EthtoolAttr::StringSet(EthtoolStringSetAttr::StringSets(vec![
+                EthtoolStringSet {
+                    id: EthtoolStringSetId::Stats,
+                    count: Some(2),
+                    strings: vec!["rx".to_string(), "tx".to_string()],
+                },
+            ])),
+        ],
+    };
+
+    let header = GenlHeader::parse(&raw[..]).unwrap();
+
+    assert_eq!(
+        expected,
+        EthtoolMessage::parse_with_param(&raw[4..], header).unwrap(),
+    );
+
+    let mut buffer = vec![0; expected.buffer_len() + header.buffer_len()];
+    header.emit(&mut buffer);
+    expected.emit(&mut buffer[4..]);
+    assert_eq!(&buffer, &raw);
+}
+
+#[test]
+fn test_strset_get_reply_index_out_of_range() {
+    // Same as above but second string has index 0x7fffffff
+    let raw: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x44, 0x00, 0x02, 0x80, 0x40, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x03, 0x80, 0x14, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0x00, 0x02, 0x00,
+        0x72, 0x78, 0x00, 0x00, 0x14, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0xff, 0xff, 0xff, 0x7f, 0x07, 0x00, 0x02, 0x00, 0x74, 0x78, 0x00, 0x00,
+    ];
+
+    let header = GenlHeader { cmd: 1, version: 1 };
+
+    assert!(EthtoolMessage::parse_with_param(&raw, header).is_err());
+}
+
+#[test]
+fn test_strset_get_reply_without_count() {
+    let raw: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x3c, 0x00, 0x02, 0x80, 0x38, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x03, 0x80,
+        0x14, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x07, 0x00, 0x02, 0x00, 0x72, 0x78, 0x00, 0x00, 0x14, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x07, 0x00, 0x02, 0x00,
+        0x74, 0x78, 0x00, 0x00,
+    ];
+
+    let expected = EthtoolMessage {
+        cmd: EthtoolCmd::StringSetGetReply,
+        nlas: vec![
+            EthtoolAttr::StringSet(EthtoolStringSetAttr::Header(vec![
+                EthtoolHeader::DevName("eth0".to_string()),
+            ])),
+            EthtoolAttr::StringSet(EthtoolStringSetAttr::StringSets(vec![
+                EthtoolStringSet {
+                    id: EthtoolStringSetId::Stats,
+                    count: None,
+                    strings: vec!["rx".to_string(), "tx".to_string()],
+                },
+            ])),
+        ],
+    };
+
+    let header = GenlHeader { cmd: 1, version: 1 };
+
+    assert_eq!(
+        expected,
+        EthtoolMessage::parse_with_param(&raw, header).unwrap(),
+    );
+}
@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: MIT
+
+use ethtool::{
+    EthtoolAttr, EthtoolCmd, EthtoolHeader, EthtoolMessage,
+    EthtoolTunnelInfoAttr, EthtoolUdpTunnelEntry, EthtoolUdpTunnelTable,
+    EthtoolUdpTunnelType,
+};
+use netlink_packet_core::{Emitable, ParseableParametrized};
+use netlink_packet_generic::GenlHeader;
+
+#[test]
+fn test_tunnel_info_get_reply() {
+    let raw: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x50, 0x00, 0x02, 0x80, 0x4c, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x04, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x02, 0x80,
+        0x04, 0x00, 0x01, 0x00, 0x08, 0x00, 0x02, 0x00, 0x03, 0x00, 0x00, 0x00,
+        0x1c, 0x00, 0x03, 0x80, 0x18, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x02, 0x00, 0x76, 0x78, 0x6c, 0x61,
+        0x6e, 0x00, 0x00, 0x00, 0x14, 0x00, 0x03, 0x80, 0x06, 0x00, 0x01, 0x00,
+        0x12, 0xb5, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = EthtoolMessage {
+        cmd: EthtoolCmd::TunnelInfoGetReply,
+        nlas: vec![
+            EthtoolAttr::TunnelInfo(EthtoolTunnelInfoAttr::Header(vec![
+                EthtoolHeader::DevName("eth0".to_string()),
+            ])),
+            EthtoolAttr::TunnelInfo(EthtoolTunnelInfoAttr::UdpPorts(vec![
+                EthtoolUdpTunnelTable {
+                    size: 4,
+                    types: vec![EthtoolUdpTunnelType::Vxlan],
+                    entries: vec![EthtoolUdpTunnelEntry {
+                        port: 4789,
+                        tunnel_type: EthtoolUdpTunnelType::Vxlan,
+                    }],
+                },
+            ])),
+        ],
+    };
+
+    let header = GenlHeader {
+        cmd: 29,
+        version: 1,
+    };
+
+    assert_eq!(
+        expected,
+        EthtoolMessage::parse_with_param(&raw, header).unwrap(),
+    );
+
+    let mut emitted = vec![0; expected.buffer_len()];
+    expected.emit(&mut emitted);
+    assert_eq!(
+        expected,
+        EthtoolMessage::parse_with_param(&emitted, header).unwrap(),
+    );
+}
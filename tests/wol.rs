@@ -0,0 +1,79 @@
+// SPDX-License-Identifier: MIT
+
+use ethtool::{
+    EthtoolAttr, EthtoolCmd, EthtoolHeader, EthtoolMessage, EthtoolWolAttr,
+    EthtoolWolMode,
+};
+use netlink_packet_core::{Emitable, ParseableParametrized};
+use netlink_packet_generic::GenlHeader;
+
+#[test]
+fn test_wol_set() {
+    let expected: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x24, 0x00, 0x02, 0x80, 0x20, 0x00, 0x03, 0x80,
+        0x10, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00, 0x05, 0x00, 0x00, 0x00,
+        0x04, 0x00, 0x03, 0x00, 0x0c, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x03, 0x00, 0x01, 0x02, 0x03, 0x04,
+        0x05, 0x06, 0x00, 0x00,
+    ];
+
+    let msg = EthtoolMessage {
+        cmd: EthtoolCmd::WolSet,
+        nlas: vec![
+            EthtoolAttr::Wol(EthtoolWolAttr::Header(vec![
+                EthtoolHeader::DevName("eth0".to_string()),
+            ])),
+            EthtoolAttr::Wol(EthtoolWolAttr::Modes(vec![
+                (EthtoolWolMode::Magic, true),
+                (EthtoolWolMode::Phy, false),
+            ])),
+            EthtoolAttr::Wol(EthtoolWolAttr::Sopass(vec![1, 2, 3, 4, 5, 6])),
+        ],
+    };
+
+    let mut raw = vec![0; msg.buffer_len()];
+    msg.emit(&mut raw);
+
+    assert_eq!(expected, raw);
+}
+
+#[test]
+fn test_wol_get_reply() {
+    let raw: Vec<u8> = vec![
+        0x10, 0x00, 0x01, 0x80, 0x09, 0x00, 0x02, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x60, 0x00, 0x02, 0x80, 0x08, 0x00, 0x02, 0x00,
+        0x08, 0x00, 0x00, 0x00, 0x54, 0x00, 0x03, 0x80, 0x14, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00,
+        0x70, 0x68, 0x79, 0x00, 0x1c, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x05, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x02, 0x00, 0x6d, 0x61, 0x67, 0x69,
+        0x63, 0x00, 0x00, 0x00, 0x04, 0x00, 0x03, 0x00, 0x20, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x06, 0x00, 0x00, 0x00, 0x10, 0x00, 0x02, 0x00,
+        0x6d, 0x61, 0x67, 0x69, 0x63, 0x73, 0x65, 0x63, 0x75, 0x72, 0x65, 0x00,
+        0x04, 0x00, 0x03, 0x00, 0x0a, 0x00, 0x03, 0x00, 0x01, 0x02, 0x03, 0x04,
+        0x05, 0x06, 0x00, 0x00,
+    ];
+
+    // Supported modes are listed, the enabled ones flagged with VALUE
+    let expected = EthtoolMessage {
+        cmd: EthtoolCmd::WolGetReply,
+        nlas: vec![
+            EthtoolAttr::Wol(EthtoolWolAttr::Header(vec![
+                EthtoolHeader::DevName("eth0".to_string()),
+            ])),
+            EthtoolAttr::Wol(EthtoolWolAttr::Modes(vec![
+                (EthtoolWolMode::Phy, false),
+                (EthtoolWolMode::Magic, true),
+                (EthtoolWolMode::MagicSecure, true),
+            ])),
+            EthtoolAttr::Wol(EthtoolWolAttr::Sopass(vec![1, 2, 3, 4, 5, 6])),
+        ],
+    };
+
+    let header = GenlHeader { cmd: 9, version: 1 };
+
+    assert_eq!(
+        expected,
+        EthtoolMessage::parse_with_param(&raw, header).unwrap(),
+    );
+}